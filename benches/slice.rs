@@ -137,3 +137,138 @@ fn get_mut(b: &mut Bencher) {
 		b.iter(|| *bsl64.get_mut(69).unwrap() = true);
 	}
 }
+
+/* Adds two 1M-bit numbers. When both operands lay out their bits the same way,
+each element is added with one `overflowing_add`; the bitwise benchmark adds an
+addend of a different store type, which ripples the carry one bit at a time.
+*/
+const ADD_BITS: usize = 1 << 20;
+
+#[bench]
+fn add_reverse_elementwise(b: &mut Bencher) {
+	let addend = vec![0x0123_4567_89AB_CDEFu64; ADD_BITS / 64];
+	let mut data = vec![0xFEDC_BA98_7654_3210u64; ADD_BITS / 64];
+	let addend = &addend.bits::<Lsb0>()[3 .. ADD_BITS - 10];
+	let bits = &mut data.bits_mut::<Lsb0>()[3 .. ADD_BITS - 10];
+	b.iter(|| black_box(&mut *bits).add_assign_reverse_slice(addend));
+}
+
+#[bench]
+fn add_reverse_bitwise(b: &mut Bencher) {
+	let addend = vec![0x0123_4567u32; ADD_BITS / 32];
+	let mut data = vec![0xFEDC_BA98_7654_3210u64; ADD_BITS / 64];
+	let addend = &addend.bits::<Lsb0>()[3 .. ADD_BITS - 10];
+	let bits = &mut data.bits_mut::<Lsb0>()[3 .. ADD_BITS - 10];
+	b.iter(|| black_box(&mut *bits).add_assign_reverse_slice(addend));
+}
+
+/* A bitmap with one set bit in every thousand. `iter_ones` skips the empty
+elements entirely, while the enumeration reads every bit.
+*/
+fn sparse() -> [usize; 1024] {
+	let mut src = [0usize; 1024];
+	let bits = src.bits_mut::<Lsb0>();
+	let len = bits.len();
+	for idx in (0 .. len).step_by(1000) {
+		bits.set(idx, true);
+	}
+	src
+}
+
+#[bench]
+fn iter_ones(b: &mut Bencher) {
+	let src = sparse();
+	let bits = src.bits::<Lsb0>();
+	b.iter(|| black_box(bits).iter_ones().sum::<usize>());
+}
+
+#[bench]
+fn iter_ones_naive(b: &mut Bencher) {
+	let src = sparse();
+	let bits = src.bits::<Lsb0>();
+	b.iter(|| {
+		black_box(bits)
+			.iter()
+			.enumerate()
+			.filter_map(|(idx, &bit)| if bit { Some(idx) } else { None })
+			.sum::<usize>()
+	});
+}
+
+/* Copies between two 8 MiB buffers. When the source and destination begin at
+the same position in an element, the body is copied as a block; when they do
+not, each element is assembled from two shifted loads. The bitwise benchmark
+copies between different store types, which has no faster path.
+*/
+const COPY_ELTS: usize = 1 << 20;
+
+#[bench]
+fn copy_aligned(b: &mut Bencher) {
+	let src = vec![0x5A5A_5A5A_5A5A_5A5Au64; COPY_ELTS];
+	let mut dst = vec![0u64; COPY_ELTS];
+	let src = src.bits::<Lsb0>();
+	let dst = dst.bits_mut::<Lsb0>();
+	b.iter(|| dst.copy_from_bitslice(black_box(src)));
+}
+
+#[bench]
+fn copy_same_phase(b: &mut Bencher) {
+	let src = vec![0x5A5A_5A5A_5A5A_5A5Au64; COPY_ELTS];
+	let mut dst = vec![0u64; COPY_ELTS];
+	let len = src.len() * 64;
+	let src = &src.bits::<Lsb0>()[3 .. len - 5];
+	let dst = &mut dst.bits_mut::<Lsb0>()[3 .. len - 5];
+	b.iter(|| dst.copy_from_bitslice(black_box(src)));
+}
+
+#[bench]
+fn copy_diff_phase(b: &mut Bencher) {
+	let src = vec![0x5A5A_5A5A_5A5A_5A5Au64; COPY_ELTS];
+	let mut dst = vec![0u64; COPY_ELTS];
+	let len = src.len() * 64;
+	let src = &src.bits::<Lsb0>()[5 .. len - 3];
+	let dst = &mut dst.bits_mut::<Lsb0>()[3 .. len - 5];
+	b.iter(|| dst.copy_from_bitslice(black_box(src)));
+}
+
+#[bench]
+fn copy_bitwise(b: &mut Bencher) {
+	let src = vec![0x5A5A_5A5Au32; COPY_ELTS * 2];
+	let mut dst = vec![0u64; COPY_ELTS];
+	let src = src.bits::<Lsb0>();
+	let dst = dst.bits_mut::<Lsb0>();
+	b.iter(|| dst.clone_from_slice(black_box(src)));
+}
+
+/* `BitSlice::reverse` exchanges element-width chunks from each end of the slice
+under `Lsb0` and `Msb0`. The bitwise benchmark performs the same reversal one
+pair of bits at a time, which is what other orderings use.
+*/
+const REVERSE_BITS: usize = 1 << 20;
+
+#[bench]
+fn reverse_aligned(b: &mut Bencher) {
+	let mut data = vec![0x0123_4567_89AB_CDEFu64; REVERSE_BITS / 64];
+	let bits = data.bits_mut::<Lsb0>();
+	b.iter(|| black_box(&mut *bits).reverse());
+}
+
+#[bench]
+fn reverse_unaligned(b: &mut Bencher) {
+	let mut data = vec![0x0123_4567_89AB_CDEFu64; REVERSE_BITS / 64];
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. REVERSE_BITS - 10];
+	b.iter(|| black_box(&mut *bits).reverse());
+}
+
+#[bench]
+fn reverse_bitwise(b: &mut Bencher) {
+	let mut data = vec![0x0123_4567_89AB_CDEFu64; REVERSE_BITS / 64];
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. REVERSE_BITS - 10];
+	let len = bits.len();
+	b.iter(|| {
+		let bits = black_box(&mut *bits);
+		for idx in 0 .. len / 2 {
+			bits.swap(idx, len - 1 - idx);
+		}
+	});
+}
@@ -0,0 +1,153 @@
+#![feature(test)]
+
+extern crate test;
+
+use bitvec::prelude::*;
+use test::Bencher;
+
+/* These benchmarks compare bulk appends against the bit-by-bit `Extend`
+implementation. The aligned case should run at `memcpy` speed.
+*/
+
+#[bench]
+fn extend_from_bitslice_aligned(b: &mut Bencher) {
+	let src = bitvec![Local, usize; 1; 1 << 20];
+	b.iter(|| {
+		let mut bv = BitVec::<Local, usize>::new();
+		bv.extend_from_bitslice(&src);
+		bv
+	});
+}
+
+#[bench]
+fn extend_from_bitslice_unaligned(b: &mut Bencher) {
+	let src = bitvec![Local, usize; 1; 1 << 20];
+	b.iter(|| {
+		let mut bv = BitVec::<Local, usize>::new();
+		bv.extend_from_bitslice(&src[3 ..]);
+		bv
+	});
+}
+
+#[bench]
+fn extend_iter(b: &mut Bencher) {
+	let src = bitvec![Local, usize; 1; 1 << 20];
+	b.iter(|| {
+		let mut bv = BitVec::<Local, usize>::new();
+		bv.extend(src.iter().copied());
+		bv
+	});
+}
+
+/* These benchmarks compare a bulk insertion into the middle of a vector against
+inserting the same bits one at a time.
+*/
+
+#[bench]
+fn insert_bitslice(b: &mut Bencher) {
+	let base = bitvec![Local, usize; 0; 100_000];
+	let src = bitvec![Local, usize; 1; 1_000];
+	b.iter(|| {
+		let mut bv = base.clone();
+		bv.insert_bitslice(50_000, &src);
+		bv
+	});
+}
+
+#[bench]
+fn insert_loop(b: &mut Bencher) {
+	let base = bitvec![Local, usize; 0; 100_000];
+	let src = bitvec![Local, usize; 1; 1_000];
+	b.iter(|| {
+		let mut bv = base.clone();
+		for (n, &bit) in src.iter().enumerate() {
+			bv.insert(50_000 + n, bit);
+		}
+		bv
+	});
+}
+
+#[bench]
+fn retain(b: &mut Bencher) {
+	let base = (0 .. 1 << 20).map(|n| n % 3 == 0).collect::<BitVec>();
+	b.iter(|| {
+		let mut bv = base.clone();
+		bv.retain(|_, bit| bit);
+		bv
+	});
+}
+
+#[bench]
+fn append(b: &mut Bencher) {
+	let base = bitvec![Local, usize; 0; 1 << 20];
+	let src = bitvec![Local, usize; 1; 1 << 20];
+	b.iter(|| {
+		let mut bv = base.clone();
+		bv.append(&mut src.clone());
+		bv
+	});
+}
+
+/* These benchmarks compare cloning a 10 MB `BitSlice` into a `BitVec` against
+copying the same bytes into a `Vec`.
+*/
+
+#[bench]
+fn from_bitslice(b: &mut Bencher) {
+	let src = vec![0x5Au8; 10 << 20];
+	b.iter(|| BitVec::from_bitslice(&src.bits::<Local>()[3 ..]));
+}
+
+#[bench]
+fn from_bitslice_vec(b: &mut Bencher) {
+	let src = vec![0x5Au8; 10 << 20];
+	b.iter(|| src.to_vec());
+}
+
+#[bench]
+fn extend_map(b: &mut Bencher) {
+	b.iter(|| {
+		let mut bv = BitVec::<Local, usize>::new();
+		bv.extend((0 .. 1 << 20).map(|n| n % 3 == 0));
+		bv
+	});
+}
+
+#[bench]
+fn resize(b: &mut Bencher) {
+	b.iter(|| {
+		let mut bv = bitvec![Local, usize; 0; 3];
+		bv.resize(1 << 24, true);
+		bv
+	});
+}
+
+/* These benchmarks split a 4 MB vector at element-aligned and unaligned
+positions.
+*/
+
+#[bench]
+fn split_off_aligned(b: &mut Bencher) {
+	let base = bitvec![Local, usize; 1; 32 << 20];
+	b.iter(|| base.clone().split_off(16 << 20));
+}
+
+#[bench]
+fn split_off_unaligned(b: &mut Bencher) {
+	let base = bitvec![Local, usize; 1; 32 << 20];
+	b.iter(|| base.clone().split_off((16 << 20) + 3));
+}
+
+/* This benchmark aligns a 10M-bit vector copied from an unaligned slice. The
+copy itself runs at `memcpy` speed.
+*/
+
+#[bench]
+fn force_align(b: &mut Bencher) {
+	let src = bitvec![Local, usize; 1; 10_000_003];
+	b.iter(|| {
+		let mut bv = BitVec::from_bitslice(&src[3 ..]);
+		bv.force_align();
+		bv
+	});
+}
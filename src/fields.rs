@@ -958,7 +958,7 @@ essentially a generic-aware version of the `as` operator.
 The result of transforming `value as U`. Where `U` is wider than `T`, this
 zero-extends; where `U` is narrower, it truncates.
 **/
-fn resize<T, U>(value: T) -> U
+pub(crate) fn resize<T, U>(value: T) -> U
 where
 	T: BitMemory,
 	U: BitMemory,
@@ -45,6 +45,9 @@ extern crate serde;
 #[cfg(all(test, feature = "serde"))]
 extern crate serde_test;
 
+#[cfg(feature = "zeroize")]
+extern crate zeroize;
+
 #[macro_use]
 pub mod macros;
 
@@ -62,8 +65,14 @@ pub mod store;
 #[cfg(feature = "alloc")]
 pub mod boxed;
 
+#[cfg(feature = "alloc")]
+pub mod rank;
+
 #[cfg(feature = "alloc")]
 pub mod vec;
 
 #[cfg(feature = "serde")]
 mod serdes;
+
+#[cfg(test)]
+mod testing;
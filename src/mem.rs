@@ -11,6 +11,12 @@ use crate::{
 	store::BitStore,
 };
 
+#[cfg(feature = "alloc")]
+use crate::{
+	fields::resize,
+	index::Indexable,
+};
+
 use core::mem;
 
 use funty::IsUnsigned;
@@ -132,6 +138,219 @@ pub const fn elts<T>(bits: usize) -> usize {
 	bits / width + (bits % width != 0) as usize
 }
 
+/* The element arithmetic below is written as free functions on `M: BitMemory`,
+rather than inline on `T::Mem` at each use site. `T::Mem` does not correctly
+project its trait implementations, so its operators and inherent-style methods
+cannot be used through that path on the minimum supported compiler. Calling a
+function generic over `M: BitMemory` with a `T::Mem` value resolves them. See
+Rust issue #69441 for more information.
+*/
+
+/** Reverses the order of the bits in an element.
+
+`IsInteger::reverse_bits` forwards to the inherent method of the same name,
+which the minimum supported compiler does not have. This reverses the bytes,
+then exchanges adjacent nibbles, bit-pairs, and bits within each byte.
+
+# Parameters
+
+- `val`: A memory element.
+
+# Returns
+
+`val`, with its bit at position `n` moved to position `M::BITS - 1 - n`.
+**/
+pub(crate) fn reverse_bits<M>(val: M) -> M
+where M: BitMemory {
+	let mut val = val.swap_bytes();
+	let mut shamt = 4;
+	while shamt > 0 {
+		//  `!0 / 17` is `0x0F…`, `!0 / 5` is `0x33…`, and `!0 / 3` is `0x55…`.
+		let mask = M::ALL / (M::ONE << shamt | M::ONE);
+		val = (val >> shamt) & mask | (val & mask) << shamt;
+		shamt >>= 1;
+	}
+	val
+}
+
+/** Joins the back of one element to the front of its successor.
+
+This is the shifted load used to read an element’s worth of bits that begins
+partway through a memory element, under the [`Lsb0`] or [`Msb0`] orderings.
+
+# Parameters
+
+- `lo`: The element holding the first bits to keep.
+- `hi`: The element after `lo`.
+- `off`: The index in `lo` of the first bit to keep. It must be less than
+  `M::BITS`.
+- `lsb0`: `true` for the [`Lsb0`] ordering, and `false` for [`Msb0`].
+
+# Returns
+
+An element whose front indices hold the bits of `lo` from `off` onwards,
+followed by the front `off` bits of `hi`.
+
+[`Lsb0`]: ../order/struct.Lsb0.html
+[`Msb0`]: ../order/struct.Msb0.html
+**/
+pub(crate) fn funnel<M>(lo: M, hi: M, off: u8, lsb0: bool) -> M
+where M: BitMemory {
+	if off == 0 {
+		return lo;
+	}
+	let back = M::BITS - off;
+	if lsb0 {
+		lo >> off | hi << back
+	}
+	else {
+		lo << off | hi >> back
+	}
+}
+
+/** Moves the front bits of an element back to begin at an index.
+
+This is the inverse of [`funnel`] for a single element, under the [`Lsb0`] or
+[`Msb0`] orderings. The bits moved past the back of the element are lost.
+
+# Parameters
+
+- `bits`: An element whose front indices hold some bits.
+- `at`: The index at which the bits are to begin. It must be less than
+  `M::BITS`.
+- `lsb0`: `true` for the [`Lsb0`] ordering, and `false` for [`Msb0`].
+
+[`funnel`]: fn.funnel.html
+[`Lsb0`]: ../order/struct.Lsb0.html
+[`Msb0`]: ../order/struct.Msb0.html
+**/
+pub(crate) fn shift_back<M>(bits: M, at: u8, lsb0: bool) -> M
+where M: BitMemory {
+	if lsb0 { bits << at } else { bits >> at }
+}
+
+/** Clears the bits of `a` that are set in `b`.
+
+Set-difference callers pass this as a function item, rather than a closure over
+`T::Mem`.
+**/
+pub(crate) fn and_not<M>(a: M, b: M) -> M
+where M: BitMemory {
+	a & !b
+}
+
+/** Adds two elements and an incoming carry.
+
+# Parameters
+
+- `a`, `b`: The elements to add.
+- `carry`: The carry into the units place.
+
+# Returns
+
+The wrapped sum, and whether the addition carried out of the element.
+**/
+pub(crate) fn add_carry<M>(a: M, b: M, carry: bool) -> (M, bool)
+where M: BitMemory {
+	let (sum, over) = a.overflowing_add(b);
+	let (sum, out) = sum.overflowing_add(if carry { M::ONE } else { M::ZERO });
+	(sum, over | out)
+}
+
+/** Moves the low bits of an integer into the positions of an element selected
+by a mask.
+
+# Parameters
+
+- `value`: The integer whose low bits are placed, least significant first.
+- `from`: The index in the element of the first placed bit.
+- `mask`: The positions that `O` assigns to the indices `from ..` that are to
+  be written.
+- `lsb0`: `Some(true)` for the [`Lsb0`] ordering, `Some(false)` for [`Msb0`],
+  and `None` for any other ordering.
+
+# Returns
+
+An element holding the bits of `value` at the masked positions, and zero
+elsewhere.
+
+[`Lsb0`]: ../order/struct.Lsb0.html
+[`Msb0`]: ../order/struct.Msb0.html
+**/
+#[cfg(feature = "alloc")]
+pub(crate) fn place<O, U, M>(
+	value: U,
+	from: u8,
+	mask: M,
+	lsb0: Option<bool>,
+) -> M
+where
+	O: BitOrder,
+	U: BitMemory,
+	M: BitMemory,
+{
+	match lsb0 {
+		Some(true) => resize::<U, M>(value) << from & mask,
+		Some(false) => reverse_bits(resize::<U, M>(value)) >> from & mask,
+		None => {
+			let mut out = M::ZERO;
+			for idx in from .. M::BITS {
+				let shamt = idx - from;
+				if shamt >= U::BITS {
+					break;
+				}
+				if value >> shamt & U::ONE != U::ZERO {
+					out |= *O::select(idx.idx::<M>());
+				}
+			}
+			out & mask
+		},
+	}
+}
+
+/** Collects the bits of an element selected by a mask into the low bits of an
+integer.
+
+This is the inverse of [`place`].
+
+# Parameters
+
+- `elem`: The element to read.
+- `mask`: The positions that `O` assigns to the indices `from .. to`.
+- `from`, `to`: The range of indices being read.
+- `lsb0`: `Some(true)` for the [`Lsb0`] ordering, `Some(false)` for [`Msb0`],
+  and `None` for any other ordering.
+
+# Returns
+
+The bits at indices `from .. to`, with the bit at `from` least significant.
+
+[`Lsb0`]: ../order/struct.Lsb0.html
+[`Msb0`]: ../order/struct.Msb0.html
+[`place`]: fn.place.html
+**/
+#[cfg(feature = "alloc")]
+pub(crate) fn gather<O, M>(
+	elem: M,
+	mask: M,
+	from: u8,
+	to: u8,
+	lsb0: Option<bool>,
+) -> usize
+where
+	O: BitOrder,
+	M: BitMemory,
+{
+	let elem = elem & mask;
+	match lsb0 {
+		Some(true) => resize::<M, usize>(elem >> from),
+		Some(false) => resize::<M, usize>(reverse_bits(elem) >> from),
+		None => (from .. to)
+			.filter(|&idx| elem & *O::select(idx.idx::<M>()) != M::ZERO)
+			.fold(0, |out, idx| out | 1 << (idx - from)),
+	}
+}
+
 macro_rules! memory {
 	($($t:ty),* $(,)?) => { $(
 		impl BitMemory for $t {
@@ -147,3 +366,18 @@ memory!(u8, u16, u32, usize);
 
 #[cfg(target_pointer_width = "64")]
 memory!(u64);
+
+#[cfg(test)]
+mod tests {
+	#[test]
+	fn reverse_bits() {
+		assert_eq!(super::reverse_bits(0b1100_1010u8), 0b0101_0011);
+		assert_eq!(super::reverse_bits(0x0001u16), 0x8000);
+		assert_eq!(super::reverse_bits(0x1234_5678u32), 0x1E6A_2C48);
+		#[cfg(target_pointer_width = "64")]
+		assert_eq!(
+			super::reverse_bits(0x0123_4567_89AB_CDEFu64),
+			0xF7B3_D591_E6A2_C480,
+		);
+	}
+}
@@ -178,6 +178,50 @@ pub trait BitOrder {
 		};
 		(from .. to).map(Indexable::idx).map(Self::select).sum()
 	}
+
+	/// Finds the lowest index whose bit is set in an element.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// The default implementation tests each index in turn. `BitOrder`
+	/// implementations may choose to count leading or trailing zeros instead.
+	///
+	/// # Parameters
+	///
+	/// - `elem`: An element of memory.
+	///
+	/// # Returns
+	///
+	/// The lowest index whose *position* is set in `elem`, or `None` if `elem`
+	/// is zero.
+	fn first_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		(0 .. M::BITS)
+			.map(Indexable::idx)
+			.find(|&idx| elem & *Self::select::<M>(idx) != M::ZERO)
+	}
+
+	/// Finds the highest index whose bit is set in an element.
+	///
+	/// This is an optional function; a default implementation is provided for
+	/// you.
+	///
+	/// # Parameters
+	///
+	/// - `elem`: An element of memory.
+	///
+	/// # Returns
+	///
+	/// The highest index whose *position* is set in `elem`, or `None` if
+	/// `elem` is zero.
+	fn last_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		(0 .. M::BITS)
+			.rev()
+			.map(Indexable::idx)
+			.find(|&idx| elem & *Self::select::<M>(idx) != M::ZERO)
+	}
 }
 
 impl BitOrder for Msb0 {
@@ -221,6 +265,23 @@ impl BitOrder for Msb0 {
 		//  4. Shift right by the distance from MSedge.
 		BitMask::new(!(M::ALL >> ct) >> from)
 	}
+
+	fn first_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		if elem == M::ZERO {
+			return None;
+		}
+		Some(unsafe { BitIdx::new_unchecked(elem.leading_zeros() as u8) })
+	}
+
+	fn last_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		if elem == M::ZERO {
+			return None;
+		}
+		let idx = M::MASK - elem.trailing_zeros() as u8;
+		Some(unsafe { BitIdx::new_unchecked(idx) })
+	}
 }
 
 impl BitOrder for Lsb0 {
@@ -260,6 +321,23 @@ impl BitOrder for Lsb0 {
 		//  4. Shift right by the distance from LSedge.
 		BitMask::new(!(M::ALL << ct) << from)
 	}
+
+	fn first_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		if elem == M::ZERO {
+			return None;
+		}
+		Some(unsafe { BitIdx::new_unchecked(elem.trailing_zeros() as u8) })
+	}
+
+	fn last_one<M>(elem: M) -> Option<BitIdx<M>>
+	where M: BitMemory {
+		if elem == M::ZERO {
+			return None;
+		}
+		let idx = M::MASK - elem.leading_zeros() as u8;
+		Some(unsafe { BitIdx::new_unchecked(idx) })
+	}
 }
 
 /** A default bit ordering.
@@ -618,4 +696,40 @@ mod tests {
 		assert_eq!(Msb0::mask(0.idx(), 6.tail()), BitMask::new(0b1111_1100u8));
 		assert_eq!(Msb0::mask(0.idx(), 7.tail()), BitMask::new(0b1111_1110u8));
 	}
+
+	#[test]
+	fn first_last_one() {
+		/// Uses only the provided methods of `BitOrder`.
+		struct Slow;
+		impl BitOrder for Slow {
+			const TYPENAME: &'static str = "Slow";
+
+			fn at<M>(place: BitIdx<M>) -> BitPos<M>
+			where M: BitMemory {
+				Msb0::at(place)
+			}
+		}
+
+		for elem in 0 ..= 255u8 {
+			assert_eq!(Msb0::first_one(elem), Slow::first_one(elem));
+			assert_eq!(Msb0::last_one(elem), Slow::last_one(elem));
+			assert_eq!(
+				Lsb0::first_one(elem),
+				(0 .. 8u8)
+					.find(|&idx| elem & (1 << idx) != 0)
+					.map(Indexable::idx),
+			);
+			assert_eq!(
+				Lsb0::last_one(elem),
+				(0 .. 8u8)
+					.rev()
+					.find(|&idx| elem & (1 << idx) != 0)
+					.map(Indexable::idx),
+			);
+		}
+		assert_eq!(Msb0::first_one(0x0100u16), Some(7u8.idx()));
+		assert_eq!(Msb0::last_one(0x0101u16), Some(15u8.idx()));
+		assert_eq!(Lsb0::first_one(0x0100u16), Some(8u8.idx()));
+		assert_eq!(Lsb0::last_one(!0u32), Some(31u8.idx()));
+	}
 }
@@ -742,25 +742,6 @@ where T: BitStore
 		unsafe { slice::from_raw_parts_mut(self.pointer().w, self.elements()) }
 	}
 
-	/// Accesses the element slice behind the pointer as a shared-mutable slice.
-	///
-	/// # Parameters
-	///
-	/// - `&self`
-	///
-	/// # Returns
-	///
-	/// Standard Rust slice handle over the data governed by this pointer.
-	///
-	/// # Lifetimes
-	///
-	/// - `'a`: Lifetime for which the data behind the pointer is live.
-	#[inline]
-	#[cfg(feature = "alloc")]
-	pub fn as_access_slice<'a>(&self) -> &'a [T::Access] {
-		unsafe { slice::from_raw_parts(self.pointer().a, self.elements()) }
-	}
-
 	/// Converts a `BitSlice` handle into its `BitPtr` representation.
 	///
 	/// # Parameters
@@ -30,5 +30,8 @@ pub use crate::{
 	bitbox,
 	bitvec,
 	boxed::BitBox,
-	vec::BitVec,
+	vec::{
+		BitConcat,
+		BitVec,
+	},
 };
@@ -0,0 +1,289 @@
+/*! Rank and Select Index
+
+This module holds an auxiliary index over a `BitSlice` that does not change,
+which answers rank (“how many set bits are before this index?”) and select
+(“where is the `n`th set bit?”) queries without rescanning the slice.
+!*/
+
+#![cfg(feature = "alloc")]
+
+use crate::{
+	order::{
+		BitOrder,
+		Local,
+	},
+	slice::BitSlice,
+	store::BitStore,
+};
+
+use alloc::vec::Vec;
+
+use core::mem;
+
+/// The number of bits counted by each block entry.
+const BLOCK: usize = 512;
+
+/// The number of blocks counted by each superblock entry.
+///
+/// A superblock spans 65,536 bits, so a block’s count relative to its
+/// superblock always fits in a `u16`.
+const SUPER: usize = 128;
+
+/** A precomputed rank and select index over a borrowed `BitSlice`.
+
+The index stores the number of set bits before every 512-bit block, split into
+two levels: a `usize` count before each superblock of 65,536 bits, and a `u16`
+count from the start of the superblock before each block. This costs about
+3.2% of the slice’s size: 16 bits per 512-bit block, plus 64 bits per
+superblock on 64-bit targets.
+
+Rank queries look up the counts of their block, and count at most one block’s
+worth of bits directly. Select queries binary-search the blocks, and then scan
+one block.
+
+The slice may have any length. Its final block may be partial.
+
+# Type Parameters
+
+- `O`: The ordering of the indexed slice.
+- `T`: The storage type of the indexed slice.
+
+# Examples
+
+```rust
+use bitvec::prelude::*;
+use bitvec::rank::RankSelect;
+
+let data = [0x8001u16; 100];
+let rs = RankSelect::new(data.bits::<Msb0>());
+assert_eq!(rs.rank1(16), 2);
+assert_eq!(rs.rank0(16), 14);
+assert_eq!(rs.select1(3), Some(31));
+assert_eq!(rs.select0(0), Some(1));
+```
+**/
+pub struct RankSelect<'a, O = Local, T = usize>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The indexed slice.
+	bits: &'a BitSlice<O, T>,
+	/// The number of set bits before each superblock.
+	supers: Vec<usize>,
+	/// The number of set bits before each block, from the start of its
+	/// superblock.
+	blocks: Vec<u16>,
+	/// The number of set bits in the whole slice.
+	ones: usize,
+}
+
+impl<'a, O, T> RankSelect<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// Builds an index over a slice.
+	///
+	/// This counts every element of the slice once.
+	///
+	/// # Parameters
+	///
+	/// - `bits`: The slice to index. It is borrowed for the life of the index,
+	///   so it cannot change while the index exists.
+	///
+	/// # Returns
+	///
+	/// An index over `bits`.
+	pub fn new(bits: &'a BitSlice<O, T>) -> Self {
+		let len = bits.len();
+		let count = (len + BLOCK - 1) / BLOCK;
+		let mut supers = Vec::with_capacity((count + SUPER - 1) / SUPER);
+		let mut blocks = Vec::with_capacity(count);
+		let mut ones = 0;
+		for (idx, block) in bits.chunks(BLOCK).enumerate() {
+			if idx % SUPER == 0 {
+				supers.push(ones);
+			}
+			blocks.push((ones - supers[idx / SUPER]) as u16);
+			ones += block.count_ones();
+		}
+		Self {
+			bits,
+			supers,
+			blocks,
+			ones,
+		}
+	}
+
+	/// Views the indexed slice.
+	pub fn as_bitslice(&self) -> &'a BitSlice<O, T> {
+		self.bits
+	}
+
+	/// Counts the set bits in `.. index`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `index`: The end of the counted range. It may equal the slice’s
+	///   length.
+	///
+	/// # Returns
+	///
+	/// The number of set bits before `index`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the slice’s length.
+	pub fn rank1(&self, index: usize) -> usize {
+		let len = self.bits.len();
+		assert!(index <= len, "Index {} out of bounds: {}", index, len);
+		if index == len {
+			return self.ones;
+		}
+		let block = index / BLOCK;
+		self.ones_before(block) + self.bits.count_ones_in(block * BLOCK .. index)
+	}
+
+	/// Counts the unset bits in `.. index`.
+	///
+	/// # Panics
+	///
+	/// This panics if `index` is greater than the slice’s length.
+	pub fn rank0(&self, index: usize) -> usize {
+		index - self.rank1(index)
+	}
+
+	/// Finds the index of the `n`th set bit, counting from zero.
+	///
+	/// # Returns
+	///
+	/// The index of the `n`th set bit, or `None` if the slice has no more than
+	/// `n` set bits.
+	pub fn select1(&self, n: usize) -> Option<usize> {
+		if n >= self.ones {
+			return None;
+		}
+		self.select(n, |block| self.ones_before(block), BitSlice::select_one)
+	}
+
+	/// Finds the index of the `n`th unset bit, counting from zero.
+	///
+	/// # Returns
+	///
+	/// The index of the `n`th unset bit, or `None` if the slice has no more
+	/// than `n` unset bits.
+	pub fn select0(&self, n: usize) -> Option<usize> {
+		if n >= self.bits.len() - self.ones {
+			return None;
+		}
+		self.select(
+			n,
+			|block| block * BLOCK - self.ones_before(block),
+			BitSlice::select_zero,
+		)
+	}
+
+	/// Counts the bytes used by the index, not including the slice.
+	pub fn space_overhead_bytes(&self) -> usize {
+		self.supers.len() * mem::size_of::<usize>()
+			+ self.blocks.len() * mem::size_of::<u16>()
+	}
+
+	/// Counts the set bits before a block.
+	fn ones_before(&self, block: usize) -> usize {
+		self.supers[block / SUPER] + self.blocks[block] as usize
+	}
+
+	/// Finds the `n`th matching bit, which must exist.
+	///
+	/// `before` counts the matching bits before a block, and `scan` finds a
+	/// matching bit within one block.
+	fn select<F, S>(&self, n: usize, before: F, scan: S) -> Option<usize>
+	where
+		F: Fn(usize) -> usize,
+		S: Fn(&BitSlice<O, T>, usize) -> Option<usize>,
+	{
+		//  Find the last block with no more than `n` matching bits before it.
+		//  The first block has none, so it always qualifies.
+		let (mut lo, mut hi) = (0, self.blocks.len());
+		while hi - lo > 1 {
+			let mid = lo + (hi - lo) / 2;
+			if before(mid) <= n {
+				lo = mid;
+			}
+			else {
+				hi = mid;
+			}
+		}
+		let start = lo * BLOCK;
+		let end = core::cmp::min(start + BLOCK, self.bits.len());
+		scan(&self.bits[start .. end], n - before(lo)).map(|idx| start + idx)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		order::Msb0,
+		slice::AsBits,
+	};
+
+	#[test]
+	#[cfg(target_pointer_width = "64")]
+	fn rank_select() {
+		use crate::testing::xorshift;
+
+		let mut next = xorshift();
+
+		let mut data = [0u64; 2100];
+		for (idx, elem) in data.iter_mut().enumerate() {
+			//  Vary the density, so that some blocks are empty or full.
+			*elem = match idx / 100 % 4 {
+				0 => next() & next() & next(),
+				1 => next(),
+				2 => 0,
+				_ => !0,
+			};
+		}
+		let all = data.bits::<Msb0>();
+		for &len in &[0, 1, 511, 512, 513, 1000, 65_536, 65_537, 134_000] {
+			let bits = &all[7 .. 7 + len];
+			let rs = RankSelect::new(bits);
+			let ones = bits.iter().filter(|&&bit| bit).count();
+
+			let mut rank = 0;
+			for idx in 0 .. len {
+				assert_eq!(rs.rank1(idx), rank);
+				assert_eq!(rs.rank0(idx), idx - rank);
+				if bits[idx] {
+					assert_eq!(rs.select1(rank), Some(idx));
+					rank += 1;
+				}
+				else {
+					assert_eq!(rs.select0(idx - rank), Some(idx));
+				}
+			}
+			assert_eq!(rank, ones);
+			assert_eq!(rs.rank1(len), ones);
+			assert_eq!(rs.select1(ones), None);
+			assert_eq!(rs.select0(len - ones), None);
+
+			let blocks = (len + BLOCK - 1) / BLOCK;
+			assert_eq!(
+				rs.space_overhead_bytes(),
+				blocks * 2
+					+ (blocks + SUPER - 1) / SUPER * mem::size_of::<usize>(),
+			);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "Index 11 out of bounds: 10")]
+	fn rank_past_len() {
+		let data = [0u8; 2];
+		RankSelect::new(&data.bits::<Msb0>()[.. 10]).rank1(11);
+	}
+}
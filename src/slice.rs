@@ -17,10 +17,18 @@ use crate::{
 		DomainMut,
 	},
 	index::{
+		BitIdx,
 		BitMask,
 		Indexable,
 	},
-	mem::BitMemory,
+	mem::{
+		add_carry,
+		and_not,
+		funnel,
+		reverse_bits,
+		shift_back,
+		BitMemory,
+	},
 	order::{
 		BitOrder,
 		Local,
@@ -29,7 +37,30 @@ use crate::{
 	store::BitStore,
 };
 
-use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use crate::{
+	order::Msb0,
+	vec::BitVec,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::{
+	cmp,
+	hint,
+	marker::PhantomData,
+	mem,
+	ops::{
+		BitAnd,
+		BitXor,
+		Bound,
+		Range,
+		RangeBounds,
+	},
+	ptr,
+	slice,
+};
 
 use funty::IsInteger;
 
@@ -295,6 +326,114 @@ where
 		Self::from_slice(slice).bitptr().into_bitslice_mut()
 	}
 
+	/// Forms a `BitSlice` over foreign memory, from a pointer to its first
+	/// element, the index of its first bit in that element, and its length in
+	/// bits.
+	///
+	/// This is the associated-function form of [`bits_from_raw_parts`], taking
+	/// the head index as a plain integer, as it arrives from other languages.
+	///
+	/// # Parameters
+	///
+	/// - `data`: A pointer to the element holding the first bit of the slice.
+	/// - `head`: The index of the first bit within `*data`. It must be less
+	///   than `T::Mem::BITS`.
+	/// - `bits`: The number of bits in the slice.
+	///
+	/// # Returns
+	///
+	/// A slice of `bits` bits, beginning at bit `head` of `*data`.
+	///
+	/// # Panics
+	///
+	/// This panics if `head` is not a valid index in `T`, if `bits` exceeds
+	/// `usize::MAX >> 3` (the longest length that a `BitSlice` handle can
+	/// encode), or if `data` is not aligned for `T`.
+	///
+	/// # Safety
+	///
+	/// `data` must be non-null, and valid for reads of every element that
+	/// holds the bits `head .. head + bits`. The returned lifetime `'a` is not
+	/// tied to any source; the caller must ensure that the memory stays valid,
+	/// and is not written through any other path, for all of `'a`. Bits of the
+	/// first and last elements outside the slice may be written by others, if
+	/// `T` is one of the aliased store types.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x0Fu8, 0xF0];
+	/// let bits = unsafe {
+	///     BitSlice::<Msb0, u8>::from_raw_parts(data.as_ptr(), 4, 8)
+	/// };
+	/// assert!(bits.all());
+	/// ```
+	///
+	/// [`bits_from_raw_parts`]: fn.bits_from_raw_parts.html
+	pub unsafe fn from_raw_parts<'a>(
+		data: *const T,
+		head: u8,
+		bits: usize,
+	) -> &'a Self
+	where
+		T: 'a,
+	{
+		assert!(
+			head < T::Mem::BITS,
+			"Head index {} cannot exceed type width {}",
+			head,
+			T::Mem::BITS,
+		);
+		BitPtr::new(data, head.idx(), bits).into_bitslice()
+	}
+
+	/// Forms a mutable `BitSlice` over foreign memory, from a pointer to its
+	/// first element, the index of its first bit in that element, and its
+	/// length in bits.
+	///
+	/// This is the mutable form of [`from_raw_parts`].
+	///
+	/// # Panics
+	///
+	/// This panics under the same conditions as [`from_raw_parts`].
+	///
+	/// # Safety
+	///
+	/// `data` must be non-null, and valid for reads and writes of every
+	/// element that holds the bits `head .. head + bits`. For all of `'a`, no
+	/// other reference may read or write those bits. Other slices may use the
+	/// bits of the first and last elements outside the returned slice only if
+	/// `T` is one of the aliased store types.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// let bits = unsafe {
+	///     BitSlice::<Lsb0, u8>::from_raw_parts_mut(data.as_mut_ptr(), 6, 4)
+	/// };
+	/// bits.set_all(true);
+	/// assert_eq!(data, [0xC0, 0x03]);
+	/// ```
+	///
+	/// [`from_raw_parts`]: #method.from_raw_parts
+	pub unsafe fn from_raw_parts_mut<'a>(
+		data: *mut T,
+		head: u8,
+		bits: usize,
+	) -> &'a mut Self
+	where
+		T: 'a,
+	{
+		Self::from_raw_parts(data, head, bits)
+			.bitptr()
+			.into_bitslice_mut()
+	}
+
 	/// Sets the bit value at the given position.
 	///
 	/// # Parameters
@@ -349,13 +488,11 @@ where
 	///
 	/// Use this method **only** when you have already performed the bounds
 	/// check, and can guarantee that the call occurs with a safely in-bounds
-	/// index.
+	/// index. Builds with `debug_assertions` enabled check the index anyway,
+	/// and panic if it is out of bounds.
 	///
 	/// # Examples
 	///
-	/// This example uses a bit slice of length 2, and demonstrates
-	/// out-of-bounds access to the last bit in the element.
-	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
@@ -363,13 +500,19 @@ where
 	/// let bits = &mut src.bits_mut::<Msb0>()[2 .. 4];
 	/// assert_eq!(bits.len(), 2);
 	/// unsafe {
-	///     bits.set_unchecked(5, true);
+	///     bits.set_unchecked(1, true);
 	/// }
-	/// assert_eq!(src, 1);
+	/// assert_eq!(src, 0x10);
 	/// ```
 	///
 	/// [`set`]: #method.set
 	pub unsafe fn set_unchecked(&mut self, index: usize, value: bool) {
+		debug_assert!(
+			index < self.len(),
+			"Index out of range: {} >= {}",
+			index,
+			self.len(),
+		);
 		let bitptr = self.bitptr();
 		let (elt, bit) = bitptr.head().offset(index as isize);
 		let data_ptr = bitptr.pointer().a();
@@ -801,263 +944,3329 @@ where
 		}
 	}
 
-	/// Set all bits in the slice to a value.
+	/// Computes the parity of the slice: the XOR of all its bits.
+	///
+	/// The live bits of each memory element are folded together with XOR, and
+	/// only the final element is counted.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `value`: The bit value to which all bits in the slice will be set.
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// `true` when an odd number of bits are set.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.bits_mut::<Msb0>();
-	/// bits[2 .. 6].set_all(true);
-	/// assert_eq!(bits.as_slice(), &[0b0011_1100]);
-	/// bits[3 .. 5].set_all(false);
-	/// assert_eq!(bits.as_slice(), &[0b0010_0100]);
-	/// bits[.. 1].set_all(true);
-	/// assert_eq!(bits.as_slice(), &[0b1010_0100]);
+	/// let bits = [0x31u8, 0x80].bits::<Msb0>();
+	/// assert!(bits[.. 8].parity());
+	/// assert!(!bits.parity());
+	/// assert!(!BitSlice::<Msb0, u8>::empty().parity());
 	/// ```
-	pub fn set_all(&mut self, value: bool) {
-		match self.domain_mut() {
-			DomainMut::Enclave { head, elem, tail } => {
-				let val = elem.load();
-				let mask = O::mask(head, tail);
-				elem.store(*if value { mask | val } else { !mask & val });
+	pub fn parity(&self) -> bool {
+		let acc = match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				*(O::mask(head, tail) & elem.load())
 			},
-			DomainMut::Region { head, body, tail } => {
-				if let Some((h, head)) = head {
-					let val = head.load();
-					let mask = O::mask(h, None);
-					head.store(*if value { mask | val } else { !mask & val });
-				}
-				for elem in body {
-					elem.set_elem(
-						if value { T::Mem::ALL } else { T::Mem::ZERO }
-							.retype::<T::NoAlias>(),
-					);
-				}
-				if let Some((tail, t)) = tail {
-					let val = tail.load();
-					let mask = O::mask(None, t);
-					tail.store(*if value { mask | val } else { !mask & val });
-				}
+			Domain::Region { head, body, tail } => {
+				let head = head.map_or(T::Mem::ZERO, |(h, head)| {
+					*(O::mask(h, None) & head.load())
+				});
+				let tail = tail.map_or(T::Mem::ZERO, |(tail, t)| {
+					*(O::mask(None, t) & tail.load())
+				});
+				body.iter()
+					.map(|e| e.get_elem().retype::<T>())
+					.fold(<T::Mem as BitXor>::bitxor(head, tail), BitXor::bitxor)
 			},
-		}
+		};
+		acc.count_ones() & 1 == 1
 	}
 
-	/// Provides mutable traversal of the collection.
+	/// Counts how many bits are set high in a range of the slice.
 	///
-	/// It is impossible to implement `IndexMut` on `BitSlice`, because bits do
-	/// not have addresses, so there can be no `&mut u1`. This method allows the
-	/// client to receive an enumerated bit, and provide a new bit to set at
-	/// each index.
+	/// This is equivalent to `self[range].count_ones()`, but works from the
+	/// range bounds directly rather than building a sub-slice. The partial
+	/// elements at the edges of the range are masked, and the elements between
+	/// them are counted whole.
 	///
 	/// # Parameters
 	///
-	/// - `&mut self`
-	/// - `func`: A function which receives a `(usize, bool)` pair of index and
-	///   value, and returns a bool. It receives the bit at each position, and
-	///   the return value is written back at that position.
+	/// - `&self`
+	/// - `range`: The range of indices to count. It may be empty, and may end
+	///   at `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The number of high bits in `range`.
+	///
+	/// # Panics
+	///
+	/// This panics if `range` is out of bounds, in the same way as indexing.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = 0u8;
-	/// let bits = src.bits_mut::<Msb0>();
-	/// bits.for_each(|idx, _bit| idx % 3 == 0);
-	/// assert_eq!(src, 0b1001_0010);
+	/// let bits = [0xFDu8, 0x25].bits::<Msb0>();
+	/// assert_eq!(bits.count_ones_in(4 .. 12), 4);
+	/// assert_eq!(bits.count_ones_in(.. 8), 7);
+	/// assert_eq!(bits.count_ones_in(16 ..), 0);
 	/// ```
-	pub fn for_each<F>(&mut self, func: F)
-	where F: Fn(usize, bool) -> bool {
-		for idx in 0 .. self.len() {
-			unsafe {
-				let tmp = *self.get_unchecked(idx);
-				let new = func(idx, tmp);
-				self.set_unchecked(idx, new);
-			}
+	pub fn count_ones_in<R>(&self, range: R) -> usize
+	where R: RangeBounds<usize> {
+		let Range { start, end } = self.bounds(range);
+		let len = self.len();
+		assert!(
+			start <= end && end <= len,
+			"Range {:?} out of bounds: {}",
+			start .. end,
+			len,
+		);
+		if start == end {
+			return 0;
+		}
+		let bitptr = self.bitptr();
+		let elts = bitptr.aliased_slice();
+		let width = T::Mem::BITS as usize;
+		//  Translate the range into indices from the front of the first element.
+		let offset = *bitptr.head() as usize;
+		let (start, end) = (start + offset, end + offset);
+		let (first, last) = (start / width, (end - 1) / width);
+		let head = ((start % width) as u8).idx::<T::Mem>();
+		let tail = ((end - 1) % width + 1) as u8;
+		let tail = tail.tail::<T::Mem>();
+		let count = |elem: &T::Alias, mask: BitMask<T::Mem>| {
+			(mask & elem.load()).count_ones() as usize
+		};
+		if first == last {
+			return count(&elts[first], O::mask(head, tail));
 		}
+		count(&elts[first], O::mask(head, None))
+			+ elts[first + 1 .. last]
+				.iter()
+				.map(|elem| elem.load().count_ones() as usize)
+				.sum::<usize>()
+			+ count(&elts[last], O::mask(None, tail))
 	}
 
-	/// Accesses the total backing stoarge of the `BitSlice`, as a slice of its
-	/// aliased elements.
-	///
-	/// Because `BitSlice` is permitted to create aliasing views to memory at
-	/// runtime, this method is required to mark the entire slice as aliased in
-	/// order to include the maybe-aliased edge elements.
-	///
-	/// You should prefer using [`.domain()`] to produce a fine-grained
-	/// view that only aliases when necessary. This method is only appropriate
-	/// when you require a single, contiguous, slice for some API.
+	/// Counts how many bits are set low in a range of the slice.
 	///
-	/// # Parameters
+	/// This is the counterpart of [`count_ones_in`].
 	///
-	/// - `&self`
+	/// # Examples
 	///
-	/// # Returns
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// An aliased view of the entire memory region this slice covers, including
-	/// contended edge elements.
+	/// let bits = [0xFDu8, 0x25].bits::<Msb0>();
+	/// assert_eq!(bits.count_zeros_in(4 .. 12), 4);
+	/// assert_eq!(bits.count_zeros_in(..= 8), 2);
+	/// ```
 	///
-	/// [`.domain()`]: #method.domain
-	pub fn as_aliased_slice(&self) -> &[T::Alias] {
-		self.bitptr().aliased_slice()
+	/// [`count_ones_in`]: #method.count_ones_in
+	pub fn count_zeros_in<R>(&self, range: R) -> usize
+	where R: RangeBounds<usize> {
+		let Range { start, end } = self.bounds(range);
+		let ones = self.count_ones_in(start .. end);
+		end - start - ones
 	}
 
-	/// Accesses the backing storage of the `BitSlice` as a slice of its
-	/// elements.
+	/// Finds the index of the `n`th set bit, counting from zero.
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// Whole elements are skipped by their popcount, and only the element that
+	/// holds the bit is scanned.
 	///
 	/// # Parameters
 	///
 	/// - `&self`
+	/// - `n`: The rank of the set bit to find. `0` finds the first set bit.
 	///
 	/// # Returns
 	///
-	/// A slice of all the elements that the `BitSlice` uses for storage.
+	/// The index of the `n`th set bit, or `None` if the slice has no more than
+	/// `n` set bits.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let src = [1u8, 66];
-	/// let bits = src.bits::<Msb0>();
-	///
-	/// let accum = bits
-	///     .as_slice()
-	///     .iter()
-	///     .map(|elt| elt.count_ones())
-	///     .sum::<u32>();
-	/// assert_eq!(accum, 3);
+	/// let bits = [0x81u8, 0x40].bits::<Msb0>();
+	/// assert_eq!(bits.select_one(0), Some(0));
+	/// assert_eq!(bits.select_one(1), Some(7));
+	/// assert_eq!(bits.select_one(2), Some(9));
+	/// assert_eq!(bits.select_one(3), None);
 	/// ```
-	pub fn as_slice(&self) -> &[T] {
-		unsafe {
-			&*(match self.domain() {
-				Domain::Enclave { .. } => &[],
-				Domain::Region { body, .. } => body,
-			} as *const [T::NoAlias] as *const [T])
-		}
+	pub fn select_one(&self, n: usize) -> Option<usize> {
+		self.select(true, n)
 	}
 
-	/// Accesses the underlying store.
+	/// Finds the index of the `n`th unset bit, counting from zero.
 	///
-	/// This will not include partially-owned edge elements, as they may be
-	/// contended by other slice handles.
+	/// This is the counterpart of [`select_one`].
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	///
-	/// let mut src = [1u8, 64];
-	/// let bits = src.bits_mut::<Msb0>();
-	/// for elt in bits.as_mut_slice() {
-	///     *elt |= 2;
-	/// }
-	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// let bits = [0x7Fu8, 0xFF].bits::<Msb0>();
+	/// assert_eq!(bits.select_zero(0), Some(0));
+	/// assert_eq!(bits.select_zero(1), None);
 	/// ```
-	pub fn as_mut_slice(&mut self) -> &mut [T] {
-		unsafe {
-			&mut *(match self.domain() {
-				Domain::Enclave { .. } => &[],
-				Domain::Region { body, .. } => body,
-			} as *const [T::NoAlias] as *const [T] as *mut [T])
-		}
-	}
-
-	/// Splits the slice into the components of its memory domain.
-	///
-	/// This produces a set of read-only aliased and unaliased subslices,
-	/// according to its pointer information. See the `BitDomain` documentation
-	/// for more information about the returned descriptor.
-	pub fn bit_domain(&self) -> BitDomain<O, T> {
-		self.into()
-	}
-
-	/// Splits the slice into the components of its memory domain.
 	///
-	/// This produces a set of writable aliased and unaliased subslices,
-	/// according to its pointer information. See the `BitDomainMut`
-	/// documentation for more information about the returned descriptor.
-	pub fn bit_domain_mut(&mut self) -> BitDomainMut<O, T> {
-		self.into()
+	/// [`select_one`]: #method.select_one
+	pub fn select_zero(&self, n: usize) -> Option<usize> {
+		self.select(false, n)
 	}
 
-	/// Splits the slice into references to its underlying memory elements.
+	/// Iterates over the maximal runs of identical bits in the slice.
 	///
-	/// Unlike `.bit_domain()` and `.bit_domain_mut()`, this does not return
-	/// smaller, specialized, `BitSlice` handles, but rather appropriately
-	/// un/aliased references to memory elements.
+	/// Each run is measured with the same element-wise search as
+	/// [`first_one`] and [`first_zero`], so an element that continues a run
+	/// extends it by a whole element at once.
 	///
-	/// The aliased references allow mutation of these elements. You are
-	/// required to not mutate through these references. This function is not
-	/// marked `unsafe`, but this is a contract you must uphold. Use
-	/// [`.domain_mut()`] for mutation.
+	/// # Returns
 	///
-	/// # Parameters
+	/// An iterator over `(value, length)` pairs, in order. Adjacent pairs have
+	/// different values, and the lengths sum to `self.len()`.
 	///
-	/// - `&self`
+	/// # Examples
 	///
-	/// # Returns
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// A read-only descriptor of the memory elements underneath `*self`.
+	/// let bits = bits![1, 1, 0, 0, 0, 1];
+	/// let mut runs = bits.runs();
+	/// assert_eq!(runs.next(), Some((true, 2)));
+	/// assert_eq!(runs.next(), Some((false, 3)));
+	/// assert_eq!(runs.next(), Some((true, 1)));
+	/// assert!(runs.next().is_none());
+	/// ```
 	///
-	/// [`.domain_mut()`]: #method.domain_mut
-	pub fn domain(&self) -> Domain<T> {
-		self.into()
+	/// [`first_one`]: #method.first_one
+	/// [`first_zero`]: #method.first_zero
+	pub fn runs(&self) -> Runs<O, T> {
+		Runs { inner: self }
 	}
 
-	/// Splits the slice into mutable references to its underlying memory
-	/// elements.
+	/// Finds the length of the longest run of `value` in the slice.
 	///
-	/// Like `.domain()`, this returns appropriately un/aliased references to
-	/// memory elements. These references are all writable.
-	///
-	/// The aliased edge references permit modifying memory beyond their bit
-	/// marker. You are required to only mutate the region of these edge
-	/// elements that you currently govern. This function is not marked
-	/// `unsafe`, but this is a contract you must uphold.
+	/// # Returns
 	///
-	/// # Parameters
+	/// The length of the longest run of `value`, or 0 if the slice does not
+	/// contain `value`.
 	///
-	/// - `&mut self`
+	/// # Examples
 	///
-	/// # Returns
+	/// ```rust
+	/// use bitvec::prelude::*;
 	///
-	/// A descriptor of the memory elements underneath `*self`, permitting
-	/// mutation.
-	pub fn domain_mut(&mut self) -> DomainMut<T> {
-		self.into()
+	/// let bits = bits![1, 1, 0, 1, 1, 1, 0];
+	/// assert_eq!(bits.max_run(true), 3);
+	/// assert_eq!(bits.max_run(false), 1);
+	/// ```
+	pub fn max_run(&self, value: bool) -> usize {
+		self.runs()
+			.filter(|&(bit, _)| bit == value)
+			.map(|(_, len)| len)
+			.max()
+			.unwrap_or(0)
 	}
 
-	/// Accesses the underlying pointer structure.
+	/// Finds the index of the first set bit in the slice.
 	///
-	/// # Parameters
-	///
-	/// - `&self`
+	/// Each memory element is loaded once, and the first element that has a
+	/// set bit in the slice is searched with `O::first_one`.
 	///
 	/// # Returns
 	///
-	/// The [`BitPtr`] structure of the slice handle.
+	/// The lowest index of a set bit, or `None` if no bit is set.
 	///
-	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
-	#[inline]
-	pub(crate) fn bitptr(&self) -> BitPtr<T> {
-		BitPtr::from_bitslice(self)
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x12].bits::<Msb0>();
+	/// assert_eq!(bits.first_one(), Some(11));
+	/// assert_eq!(bits[.. 11].first_one(), None);
+	/// ```
+	pub fn first_one(&self) -> Option<usize> {
+		self.first_unlike(false)
 	}
 
-	/// Copy a bit from one location in a slice to another.
+	/// Finds the index of the last set bit in the slice.
 	///
-	/// # Parameters
+	/// # Returns
+	///
+	/// The highest index of a set bit, or `None` if no bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x12].bits::<Msb0>();
+	/// assert_eq!(bits.last_one(), Some(14));
+	/// ```
+	pub fn last_one(&self) -> Option<usize> {
+		self.last_unlike(false)
+	}
+
+	/// Finds the index of the first unset bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The lowest index of an unset bit, or `None` if every bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0u8, 0xEF].bits::<Msb0>();
+	/// assert_eq!(bits.first_zero(), Some(11));
+	/// ```
+	pub fn first_zero(&self) -> Option<usize> {
+		self.first_unlike(true)
+	}
+
+	/// Finds the index of the last unset bit in the slice.
+	///
+	/// # Returns
+	///
+	/// The highest index of an unset bit, or `None` if every bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x7Fu8, !0].bits::<Msb0>();
+	/// assert_eq!(bits.last_zero(), Some(0));
+	/// assert_eq!(bits[1 ..].last_zero(), None);
+	/// ```
+	pub fn last_zero(&self) -> Option<usize> {
+		self.last_unlike(true)
+	}
+
+	/// Counts the unset bits at the front of the slice, starting at index 0.
+	///
+	/// This stops at the first memory element that holds a set bit.
+	///
+	/// # Returns
+	///
+	/// The number of unset bits before the first set bit. This is the length
+	/// of the slice if no bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0u8, 0x10].bits::<Msb0>();
+	/// assert_eq!(bits.leading_zeros(), 11);
+	/// assert_eq!(bits[.. 8].leading_zeros(), 8);
+	/// ```
+	pub fn leading_zeros(&self) -> usize {
+		self.first_unlike(false).unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the unset bits at the back of the slice.
+	///
+	/// # Returns
+	///
+	/// The number of unset bits after the last set bit. This is the length of
+	/// the slice if no bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x10u8, 0].bits::<Msb0>();
+	/// assert_eq!(bits.trailing_zeros(), 12);
+	/// ```
+	pub fn trailing_zeros(&self) -> usize {
+		let len = self.len();
+		self.last_unlike(false).map_or(len, |idx| len - 1 - idx)
+	}
+
+	/// Counts the set bits at the front of the slice, starting at index 0.
+	///
+	/// # Returns
+	///
+	/// The number of set bits before the first unset bit. This is the length
+	/// of the slice if every bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [!0u8, 0xEF].bits::<Msb0>();
+	/// assert_eq!(bits.leading_ones(), 11);
+	/// ```
+	pub fn leading_ones(&self) -> usize {
+		self.first_unlike(true).unwrap_or_else(|| self.len())
+	}
+
+	/// Counts the set bits at the back of the slice.
+	///
+	/// # Returns
+	///
+	/// The number of set bits after the last unset bit. This is the length of
+	/// the slice if every bit is set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0xEFu8, !0].bits::<Msb0>();
+	/// assert_eq!(bits.trailing_ones(), 12);
+	/// ```
+	pub fn trailing_ones(&self) -> usize {
+		let len = self.len();
+		self.last_unlike(true).map_or(len, |idx| len - 1 - idx)
+	}
+
+	/// Iterates over the indices of the set bits in the slice.
+	///
+	/// The iterator loads each memory element once, skips elements with no set
+	/// bits, and moves between set bits with the ordering’s
+	/// [`BitOrder::first_one`] and [`BitOrder::last_one`] searches.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An iterator over the indices of the set bits, in ascending order.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x81u8, 0x40].bits::<Msb0>();
+	/// let mut ones = bits.iter_ones();
+	/// assert_eq!(ones.next(), Some(0));
+	/// assert_eq!(ones.next_back(), Some(9));
+	/// assert_eq!(ones.next(), Some(7));
+	/// assert!(ones.next().is_none());
+	/// ```
+	///
+	/// [`BitOrder::first_one`]: ../order/trait.BitOrder.html#method.first_one
+	/// [`BitOrder::last_one`]: ../order/trait.BitOrder.html#method.last_one
+	pub fn iter_ones(&self) -> IterOnes<O, T> {
+		IterOnes {
+			inner: self,
+			front: 0,
+		}
+	}
+
+	/// Iterates over the indices of the unset bits in the slice.
+	///
+	/// This is the counterpart of [`iter_ones`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = [0x7Eu8, 0xFF].bits::<Msb0>();
+	/// assert!(bits.iter_zeros().eq(vec![0, 7]));
+	/// ```
+	///
+	/// [`iter_ones`]: #method.iter_ones
+	pub fn iter_zeros(&self) -> IterZeros<O, T> {
+		IterZeros {
+			inner: self,
+			front: 0,
+		}
+	}
+
+	/// Finds the index of the `n`th bit equal to `value`.
+	fn select(&self, value: bool, mut n: usize) -> Option<usize> {
+		let mut offset = 0;
+		//  Searches the live bits `from .. to` of an element, or counts them
+		//  against `n` and moves past the element.
+		let mut find = |elem: T::Mem, from: u8, to: u8| {
+			let elem = if value { elem } else { !elem };
+			let live = *(O::mask(from.idx(), to.tail()) & elem);
+			let count = live.count_ones() as usize;
+			if n >= count {
+				n -= count;
+				offset += (to - from) as usize;
+				return None;
+			}
+			(from .. to)
+				.filter(|&idx| live.get::<O>(idx.idx()))
+				.nth(n)
+				.map(|idx| offset + (idx - from) as usize)
+		};
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				find(elem.load(), *head, *tail)
+			},
+			Domain::Region { head, body, tail } => head
+				.and_then(|(h, elem)| find(elem.load(), *h, T::Mem::BITS))
+				.or_else(|| {
+					body.iter().find_map(|elem| {
+						find(elem.get_elem().retype::<T>(), 0, T::Mem::BITS)
+					})
+				})
+				.or_else(|| tail.and_then(|(elem, t)| find(elem.load(), 0, *t))),
+		}
+	}
+
+	/// Set all bits in the slice to a value.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<Msb0>();
+	/// bits[2 .. 6].set_all(true);
+	/// assert_eq!(bits.as_slice(), &[0b0011_1100]);
+	/// bits[3 .. 5].set_all(false);
+	/// assert_eq!(bits.as_slice(), &[0b0010_0100]);
+	/// bits[.. 1].set_all(true);
+	/// assert_eq!(bits.as_slice(), &[0b1010_0100]);
+	/// ```
+	pub fn set_all(&mut self, value: bool) {
+		//  The edge elements may be shared with other slices, so they are
+		//  written through the aliased accessors. Only the body is written
+		//  whole, which lets the loop compile down to a memset.
+		let edge = |elem: &T::Alias, mask: BitMask<T::Mem>| {
+			if value {
+				elem.set_bits(mask);
+			}
+			else {
+				elem.clear_bits(mask);
+			}
+		};
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				edge(elem, O::mask(head, tail));
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((h, head)) = head {
+					edge(head, O::mask(h, None));
+				}
+				let fill = if value { T::Mem::ALL } else { T::Mem::ZERO };
+				for elem in body {
+					elem.set_elem(fill.retype::<T::NoAlias>());
+				}
+				if let Some((tail, t)) = tail {
+					edge(tail, O::mask(None, t));
+				}
+			},
+		}
+	}
+
+	/// Sets every bit in the slice to a value.
+	///
+	/// This is an alias of [`set_all`], named after [`slice::fill`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which all bits in the slice will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 3];
+	/// data.bits_mut::<Lsb0>()[4 .. 20].fill(true);
+	/// assert_eq!(data, [0xF0, 0xFF, 0x0F]);
+	/// ```
+	///
+	/// [`set_all`]: #method.set_all
+	/// [`slice::fill`]: https://doc.rust-lang.org/std/primitive.slice.html#method.fill
+	#[inline]
+	pub fn fill(&mut self, value: bool) {
+		self.set_all(value);
+	}
+
+	/// Inverts all bits in the slice, in place.
+	///
+	/// Whole elements are inverted directly, and the partial edge elements
+	/// are inverted through masks, so bits outside the slice are unaffected.
+	/// This is the same operation as `!` on a `&mut BitSlice`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// data.bits_mut::<Msb0>()[2 .. 14].invert();
+	/// assert_eq!(data, [0x3F, 0xFC]);
+	/// ```
+	pub fn invert(&mut self) {
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				elem.invert_bits(O::mask(head, tail));
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((h, head)) = head {
+					head.invert_bits(O::mask(h, None));
+				}
+				for elem in body {
+					elem.set_elem(!elem.get_elem());
+				}
+				if let Some((tail, t)) = tail {
+					tail.invert_bits(O::mask(None, t));
+				}
+			},
+		}
+	}
+
+	/// Sets each bit in the slice to the value a function produces for its
+	/// index.
+	///
+	/// The function is called once for each index, in ascending order. Its
+	/// results are collected for a whole memory element at a time, and each
+	/// element is written once.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives an index in `0 .. self.len()`, and
+	///   produces the bit to write at that index. Indices are counted from the
+	///   start of `self`, not from the start of any slice it was taken from.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0u8; 2];
+	/// data.bits_mut::<Msb0>()[3 ..].fill_with(|idx| idx % 4 == 0);
+	/// assert_eq!(data, [0b0001_0001, 0b0001_0001]);
+	/// ```
+	pub fn fill_with<F>(&mut self, mut func: F)
+	where F: FnMut(usize) -> bool {
+		/// Calls `func` for the positions `from .. to` in one element, and
+		/// collects the results into a mask of that element.
+		fn gather<O, M, F>(from: u8, to: u8, start: usize, func: &mut F) -> M
+		where
+			O: BitOrder,
+			M: BitMemory,
+			F: FnMut(usize) -> bool,
+		{
+			let out: BitMask<M> = (from .. to)
+				.zip(start ..)
+				.filter(|&(_, idx)| func(idx))
+				.map(|(pos, _)| O::select(unsafe { BitIdx::new_unchecked(pos) }))
+				.sum();
+			*out
+		}
+
+		let edge = |elem: &T::Alias, mask: BitMask<T::Mem>, bits: T::Mem| {
+			elem.clear_bits(mask);
+			elem.set_bits(mask & bits);
+		};
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let bits = gather::<O, _, _>(*head, *tail, 0, &mut func);
+				edge(elem, O::mask(head, tail), bits);
+			},
+			DomainMut::Region { head, body, tail } => {
+				let mut start = 0;
+				if let Some((h, head)) = head {
+					let bits =
+						gather::<O, _, _>(*h, T::Mem::BITS, start, &mut func);
+					edge(head, O::mask(h, None), bits);
+					start += (T::Mem::BITS - *h) as usize;
+				}
+				for elem in body {
+					let bits: T::Mem =
+						gather::<O, _, _>(0, T::Mem::BITS, start, &mut func);
+					elem.set_elem(bits.retype::<T::NoAlias>());
+					start += T::Mem::BITS as usize;
+				}
+				if let Some((tail, t)) = tail {
+					let bits = gather::<O, _, _>(0, *t, start, &mut func);
+					edge(tail, O::mask(None, t), bits);
+				}
+			},
+		}
+	}
+
+	/// Rewrites each memory element of the slice with a function.
+	///
+	/// The function receives the index of each element within the slice’s
+	/// storage, counting from zero, and the element’s current value. Bits
+	/// outside the slice are cleared to zero in that value. The function’s
+	/// result is written back through the same mask, so its bits outside the
+	/// slice are discarded and the memory there is never altered.
+	///
+	/// An empty slice does not call the function.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function from an element index and value to the element’s
+	///   new value.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0x0Fu8, 0xF0];
+	/// data.bits_mut::<Msb0>()[2 .. 10].for_each_element(|idx, elem| {
+	///     assert_eq!(elem, [0x0F, 0xC0][idx]);
+	///     !elem
+	/// });
+	/// assert_eq!(data, [0x30, 0x30]);
+	/// ```
+	pub fn for_each_element<F>(&mut self, mut func: F)
+	where F: FnMut(usize, T::Mem) -> T::Mem {
+		/// Rewrites the masked bits of an edge element.
+		fn edge<T, F>(
+			func: &mut F,
+			idx: usize,
+			elem: &T::Alias,
+			mask: BitMask<T::Mem>,
+		) where
+			T: BitStore,
+			F: FnMut(usize, T::Mem) -> T::Mem,
+		{
+			let out = func(idx, *(mask & elem.load()));
+			elem.clear_bits(mask);
+			elem.set_bits(mask & out);
+		}
+
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				edge::<T, _>(&mut func, 0, elem, O::mask(head, tail));
+			},
+			DomainMut::Region { head, body, tail } => {
+				let mut idx = 0;
+				if let Some((h, elem)) = head {
+					edge::<T, _>(&mut func, idx, elem, O::mask(h, None));
+					idx += 1;
+				}
+				for elem in body {
+					let out = func(idx, elem.get_elem().retype::<T>());
+					elem.set_elem(out.retype::<T::NoAlias>());
+					idx += 1;
+				}
+				if let Some((elem, t)) = tail {
+					edge::<T, _>(&mut func, idx, elem, O::mask(None, t));
+				}
+			},
+		}
+	}
+
+	/// Folds the memory elements of the slice into an accumulator.
+	///
+	/// Each element is passed in order, with the bits outside the slice
+	/// cleared to zero, as in [`for_each_element`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `init`: The initial value of the accumulator.
+	/// - `func`: A function from the accumulator and an element’s value to the
+	///   next accumulator.
+	///
+	/// # Returns
+	///
+	/// The accumulator after the last element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0xFFu8, 0x0F, 0xF0];
+	/// let bits = &data.bits::<Msb0>()[4 .. 20];
+	/// let ones = bits.fold_elements(0, |acc, elem| acc + elem.count_ones());
+	/// assert_eq!(ones, 12);
+	/// ```
+	///
+	/// [`for_each_element`]: #method.for_each_element
+	pub fn fold_elements<A, F>(&self, init: A, mut func: F) -> A
+	where F: FnMut(A, T::Mem) -> A {
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				func(init, *(O::mask(head, tail) & elem.load()))
+			},
+			Domain::Region { head, body, tail } => {
+				let mut acc = init;
+				if let Some((h, elem)) = head {
+					acc = func(acc, *(O::mask(h, None) & elem.load()));
+				}
+				for elem in body {
+					acc = func(acc, elem.get_elem().retype::<T>());
+				}
+				if let Some((elem, t)) = tail {
+					acc = func(acc, *(O::mask(None, t) & elem.load()));
+				}
+				acc
+			},
+		}
+	}
+
+	/// Shifts the slice left, in place, filling the vacated bits with `fill`
+	/// and returning the bits that were shifted out.
+	///
+	/// As with `<<=`, “left” is towards index `0`: the bits in `.. shamt` are
+	/// ejected, every other bit moves down by `shamt`, and the bits in
+	/// `len - shamt ..` are set to `fill`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shamt`: The shift amount. If this is not less than the length, then
+	///   every bit is ejected and the whole slice is set to `fill`.
+	/// - `fill`: The value written into the vacated bits.
+	///
+	/// # Returns
+	///
+	/// The ejected bits, in their original order. This has
+	/// `min(shamt, self.len())` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.bits_mut::<Msb0>()[2 .. 14];
+	/// let out = bits.shift_left_in(3, true);
+	/// assert_eq!(out, bits![0, 0, 1]);
+	/// assert_eq!(src, [0b01_011_101, 0b001_111_01]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn shift_left_in(&mut self, shamt: usize, fill: bool) -> BitVec<O, T> {
+		let out = BitVec::from_bitslice(&self[.. cmp::min(shamt, self.len())]);
+		self.shift_left_in_discard(shamt, fill);
+		out
+	}
+
+	/// Shifts the slice left, in place, filling the vacated bits with `fill`
+	/// and discarding the bits that were shifted out.
+	///
+	/// This is [`shift_left_in`] without the allocation for the ejected bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<Lsb0>();
+	/// bits.shift_left_in_discard(3, true);
+	/// assert_eq!(bits.as_slice(), &[0b1110_0000]);
+	/// bits.shift_left_in_discard(9, false);
+	/// assert_eq!(bits.as_slice(), &[0]);
+	/// ```
+	///
+	/// [`shift_left_in`]: #method.shift_left_in
+	pub fn shift_left_in_discard(&mut self, shamt: usize, fill: bool) {
+		let len = self.len();
+		let shamt = cmp::min(shamt, len);
+		*self <<= shamt;
+		if fill {
+			self[len - shamt ..].set_all(true);
+		}
+	}
+
+	/// Shifts the slice right, in place, filling the vacated bits with `fill`
+	/// and returning the bits that were shifted out.
+	///
+	/// As with `>>=`, “right” is away from index `0`: the bits in
+	/// `len - shamt ..` are ejected, every other bit moves up by `shamt`, and
+	/// the bits in `.. shamt` are set to `fill`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `shamt`: The shift amount. If this is not less than the length, then
+	///   every bit is ejected and the whole slice is set to `fill`.
+	/// - `fill`: The value written into the vacated bits.
+	///
+	/// # Returns
+	///
+	/// The ejected bits, in their original order. This has
+	/// `min(shamt, self.len())` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0x4Bu8, 0xA5];
+	/// let bits = &mut src.bits_mut::<Msb0>()[2 .. 14];
+	/// let out = bits.shift_right_in(3, true);
+	/// assert_eq!(out, bits![0, 0, 1]);
+	/// assert_eq!(src, [0b01_111_00_1, 0b011_101_01]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn shift_right_in(&mut self, shamt: usize, fill: bool) -> BitVec<O, T> {
+		let len = self.len();
+		let out = BitVec::from_bitslice(&self[len - cmp::min(shamt, len) ..]);
+		self.shift_right_in_discard(shamt, fill);
+		out
+	}
+
+	/// Shifts the slice right, in place, filling the vacated bits with `fill`
+	/// and discarding the bits that were shifted out.
+	///
+	/// This is [`shift_right_in`] without the allocation for the ejected bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<Lsb0>();
+	/// bits.shift_right_in_discard(3, true);
+	/// assert_eq!(bits.as_slice(), &[0b0000_0111]);
+	/// ```
+	///
+	/// [`shift_right_in`]: #method.shift_right_in
+	pub fn shift_right_in_discard(&mut self, shamt: usize, fill: bool) {
+		let shamt = cmp::min(shamt, self.len());
+		*self >>= shamt;
+		if fill {
+			self[.. shamt].set_all(true);
+		}
+	}
+
+	/// `XOR`s another slice of the same length into this one.
+	///
+	/// Unlike `^=`, which leaves bits past the end of a shorter stream
+	/// untouched, this requires that the two slices match exactly, so that no
+	/// bit of either is silently ignored.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The slice to `XOR` into `self`. It may have any order and
+	///   storage types.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0b1100u8;
+	/// let bits = &mut data.bits_mut::<Lsb0>()[.. 4];
+	/// bits.xor_assign_strict(bits![Msb0, u16; 1, 0, 1, 0]);
+	/// assert_eq!(data, 0b1001);
+	/// ```
+	pub fn xor_assign_strict<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		assert_eq!(self.len(), other.len(), "Strict XOR requires equal lengths");
+		self.combine_with(other, BitXor::bitxor);
+	}
+
+	/// Tests whether every set bit of the slice is also set in `other`.
+	///
+	/// The slices are treated as sets of the indices of their set bits, and
+	/// the shorter is treated as if it had trailing zeros. The slices are
+	/// compared an element at a time, and the scan stops at the first element
+	/// that has a bit outside `other`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set. It may have any order and storage types.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 0, 1, 0, 1];
+	/// assert!(a.is_subset(bits![Lsb0, u16; 1, 1, 0, 1, 1]));
+	/// assert!(!a.is_subset(bits![0, 1]));
+	/// assert!(bits![0, 1, 0, 0].is_subset(bits![0, 1]));
+	/// ```
+	pub fn is_subset<D, U>(&self, other: &BitSlice<D, U>) -> bool
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		!self.any_with(other, and_not)
+	}
+
+	/// Tests whether every set bit of `other` is also set in the slice.
+	///
+	/// This is [`is_subset`] with the operands exchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 1, 0, 1];
+	/// assert!(a.is_superset(bits![Lsb0, u16; 0, 1, 0, 1, 0]));
+	/// assert!(!a.is_superset(bits![0, 0, 1]));
+	/// ```
+	///
+	/// [`is_subset`]: #method.is_subset
+	pub fn is_superset<D, U>(&self, other: &BitSlice<D, U>) -> bool
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		other.is_subset(self)
+	}
+
+	/// Tests whether the slice and `other` have no set bit in common.
+	///
+	/// Only the indices present in both slices are compared, and the scan
+	/// stops at the first element with a bit in common.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 0, 1];
+	/// assert!(a.is_disjoint(bits![Lsb0, u16; 0, 1, 0, 1, 1]));
+	/// assert!(!a.is_disjoint(bits![0, 0, 1]));
+	/// ```
+	pub fn is_disjoint<D, U>(&self, other: &BitSlice<D, U>) -> bool
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		!self.any_with(other, BitAnd::bitand)
+	}
+
+	/// Finds the first occurrence of a bit pattern in the slice.
+	///
+	/// Each candidate window is compared against `needle` one memory element
+	/// of the slice at a time, and the comparison stops at the first element
+	/// that differs. Under the [`Lsb0`] and [`Msb0`] orderings, the bits of
+	/// `needle` are loaded an element at a time, shifted into the window’s
+	/// position. A `needle` with a different memory layout is first packed
+	/// once into a `BitVec` of the slice’s own type, when the `alloc` feature
+	/// is enabled.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The pattern to find. An empty pattern matches at index 0.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit of the first occurrence of `needle`, or
+	/// `None` if it does not occur.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x0Fu8, 0x5A, 0x0F];
+	/// let bits = data.bits::<Msb0>();
+	/// let sync = bits![1, 1, 1, 1, 0, 1, 0, 1, 1, 0, 1, 0];
+	/// assert_eq!(bits.find(sync), Some(4));
+	/// assert_eq!(bits.find(&sync[.. 4]), Some(4));
+	/// assert_eq!(bits.find(bits![0; 4]), Some(0));
+	/// assert_eq!(bits.find(bits![0; 5]), Some(15));
+	/// assert_eq!(bits.find(bits![1; 5]), None);
+	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	pub fn find<D, U>(&self, needle: &BitSlice<D, U>) -> Option<usize>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		#[cfg(feature = "alloc")]
+		{
+			if Self::linear_order().is_some() && Self::coerce(needle).is_none() {
+				let needle = needle.iter().copied().collect::<BitVec<O, T>>();
+				return self.find_raw(&needle);
+			}
+		}
+		self.find_raw(needle)
+	}
+
+	/// Finds the first occurrence of a bit pattern, without repacking it.
+	///
+	/// Callers that search for the same pattern many times pack it into the
+	/// slice’s own layout once, and then search with this.
+	pub(crate) fn find_raw<D, U>(&self, needle: &BitSlice<D, U>) -> Option<usize>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let width = needle.len();
+		let count = (self.len() + 1).checked_sub(width)?;
+		(0 .. count).find(|&idx| {
+			!self[idx .. idx + width].any_with(needle, BitXor::bitxor)
+		})
+	}
+
+	/// Finds the last occurrence of a bit pattern in the slice.
+	///
+	/// This is the counterpart of [`find`], and searches from the back of the
+	/// slice.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The pattern to find. An empty pattern matches at
+	///   `self.len()`.
+	///
+	/// # Returns
+	///
+	/// The index of the first bit of the last occurrence of `needle`, or
+	/// `None` if it does not occur.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = [0x0Fu8, 0x5A, 0x0F];
+	/// let bits = data.bits::<Msb0>();
+	/// assert_eq!(bits.rfind(bits![1, 1, 1, 1]), Some(20));
+	/// assert_eq!(bits.rfind(bits![0; 0]), Some(24));
+	/// ```
+	///
+	/// [`find`]: #method.find
+	pub fn rfind<D, U>(&self, needle: &BitSlice<D, U>) -> Option<usize>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		#[cfg(feature = "alloc")]
+		{
+			if Self::linear_order().is_some() && Self::coerce(needle).is_none() {
+				let needle = needle.iter().copied().collect::<BitVec<O, T>>();
+				return self.rfind_raw(&needle);
+			}
+		}
+		self.rfind_raw(needle)
+	}
+
+	/// Finds the last occurrence of a bit pattern, without repacking it.
+	///
+	/// This is the counterpart of `find_raw`.
+	pub(crate) fn rfind_raw<D, U>(
+		&self,
+		needle: &BitSlice<D, U>,
+	) -> Option<usize>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let width = needle.len();
+		let count = (self.len() + 1).checked_sub(width)?;
+		(0 .. count).rev().find(|&idx| {
+			!self[idx .. idx + width].any_with(needle, BitXor::bitxor)
+		})
+	}
+
+	/// Iterates over the subslices between occurrences of a bit pattern.
+	///
+	/// This is analogous to `str::split`. Each separator is found with
+	/// [`find`], starting after the end of the previous one, so occurrences
+	/// do not overlap. Adjacent separators, or separators at the ends of the
+	/// slice, produce empty subslices.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `sep`: The separator pattern.
+	///
+	/// # Returns
+	///
+	/// An iterator over the subslices between separators. It always produces
+	/// at least one subslice.
+	///
+	/// # Panics
+	///
+	/// This panics if `sep` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 0, 0, 1, 1, 1, 0, 1, 1];
+	/// let mut iter = bits.split_pattern(bits![1, 1]);
+	/// assert_eq!(iter.next(), Some(&bits[.. 0]));
+	/// assert_eq!(iter.next(), Some(&bits[2 .. 4]));
+	/// assert_eq!(iter.next(), Some(&bits[6 .. 8]));
+	/// assert_eq!(iter.next(), Some(&bits[10 ..]));
+	/// assert!(iter.next().is_none());
+	/// ```
+	///
+	/// [`find`]: #method.find
+	pub fn split_pattern<'a, D, U>(
+		&'a self,
+		sep: &'a BitSlice<D, U>,
+	) -> SplitPattern<'a, O, T, D, U>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		assert!(!sep.is_empty(), "Separator cannot be empty");
+		SplitPattern {
+			inner: self,
+			sep: Needle::new(sep),
+			done: false,
+		}
+	}
+
+	/// Iterates over the subslices between occurrences of a bit pattern,
+	/// producing at most `n` subslices.
+	///
+	/// The last subslice produced holds the rest of the slice, including any
+	/// further separators.
+	///
+	/// # Panics
+	///
+	/// This panics if `sep` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 1, 0, 1, 1];
+	/// let mut iter = bits.splitn_pattern(2, bits![1, 1]);
+	/// assert_eq!(iter.next(), Some(&bits[.. 2]));
+	/// assert_eq!(iter.next(), Some(&bits[4 ..]));
+	/// assert!(iter.next().is_none());
+	/// ```
+	pub fn splitn_pattern<'a, D, U>(
+		&'a self,
+		n: usize,
+		sep: &'a BitSlice<D, U>,
+	) -> SplitNPattern<'a, O, T, D, U>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		SplitNPattern {
+			inner: self.split_pattern(sep),
+			count: n,
+		}
+	}
+
+	/// Iterates over the subslices between occurrences of a bit pattern,
+	/// starting from the end of the slice.
+	///
+	/// Each separator is found with [`rfind`], ending before the start of the
+	/// previous one. When separators can overlap, this may split at different
+	/// places than [`split_pattern`].
+	///
+	/// # Panics
+	///
+	/// This panics if `sep` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 1, 0];
+	/// let mut iter = bits.rsplit_pattern(bits![1, 1]);
+	/// assert_eq!(iter.next(), Some(&bits[4 ..]));
+	/// assert_eq!(iter.next(), Some(&bits[.. 2]));
+	/// assert!(iter.next().is_none());
+	/// ```
+	///
+	/// [`rfind`]: #method.rfind
+	/// [`split_pattern`]: #method.split_pattern
+	pub fn rsplit_pattern<'a, D, U>(
+		&'a self,
+		sep: &'a BitSlice<D, U>,
+	) -> RSplitPattern<'a, O, T, D, U>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		assert!(!sep.is_empty(), "Separator cannot be empty");
+		RSplitPattern {
+			inner: self,
+			sep: Needle::new(sep),
+			done: false,
+		}
+	}
+
+	/// Iterates over the starting indices of the occurrences of a bit pattern
+	/// that do not overlap.
+	///
+	/// Each occurrence is found with [`find`], starting after the end of the
+	/// previous one. The iterator is lazy, and can be cloned to restart the
+	/// search from its current position.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `needle`: The pattern to find.
+	///
+	/// # Panics
+	///
+	/// This panics if `needle` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 1, 1, 0, 1, 1, 1];
+	/// let hits = bits.match_indices(bits![1, 1, 1]);
+	/// assert!(hits.eq([0, 6].iter().copied()));
+	/// ```
+	///
+	/// [`find`]: #method.find
+	pub fn match_indices<'a, D, U>(
+		&'a self,
+		needle: &'a BitSlice<D, U>,
+	) -> MatchIndices<'a, O, T, D, U>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		assert!(!needle.is_empty(), "Pattern cannot be empty");
+		MatchIndices {
+			inner: self,
+			front: 0,
+			needle: Needle::new(needle),
+			overlap: false,
+		}
+	}
+
+	/// Iterates over the starting indices of every occurrence of a bit
+	/// pattern, including occurrences that overlap.
+	///
+	/// After each occurrence, the search resumes one bit after its start.
+	///
+	/// # Panics
+	///
+	/// This panics if `needle` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 1, 1, 1, 1, 0, 1, 1, 1];
+	/// let hits = bits.overlapping_matches(bits![1, 1, 1]);
+	/// assert!(hits.eq([0, 1, 2, 6].iter().copied()));
+	/// ```
+	pub fn overlapping_matches<'a, D, U>(
+		&'a self,
+		needle: &'a BitSlice<D, U>,
+	) -> MatchIndices<'a, O, T, D, U>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		MatchIndices {
+			overlap: true,
+			..self.match_indices(needle)
+		}
+	}
+
+	/// Copies the slice into a new `BitVec`, replacing each occurrence of a
+	/// bit pattern with another pattern.
+	///
+	/// The slice is scanned from the front with [`find`]. Each match is
+	/// replaced, and the scan restarts after the end of the match, so matches
+	/// do not overlap and replacements are never rescanned. The regions
+	/// between matches are copied with [`BitVec::extend_from_bitslice`].
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `from`: The pattern to replace.
+	/// - `to`: The pattern written in place of each match of `from`. It may
+	///   have any length.
+	///
+	/// # Returns
+	///
+	/// A copy of the slice, with each match replaced.
+	///
+	/// # Panics
+	///
+	/// This panics if `from` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![0, 1, 1, 0, 1, 1, 1];
+	/// let out = bits.replace(bits![1, 1], bits![0]);
+	/// assert_eq!(out, bits![0, 0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`BitVec::extend_from_bitslice`]: ../vec/struct.BitVec.html#method.extend_from_bitslice
+	/// [`find`]: #method.find
+	#[cfg(feature = "alloc")]
+	pub fn replace<D, U, E, V>(
+		&self,
+		from: &BitSlice<D, U>,
+		to: &BitSlice<E, V>,
+	) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+		E: BitOrder,
+		V: BitStore,
+	{
+		self.replacen(from, to, usize::max_value())
+	}
+
+	/// Copies the slice into a new `BitVec`, replacing the first `count`
+	/// occurrences of a bit pattern with another pattern.
+	///
+	/// This behaves as [`replace`], but stops replacing after `count` matches.
+	///
+	/// # Panics
+	///
+	/// This panics if `from` is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![1, 0, 1, 0, 1];
+	/// let out = bits.replacen(bits![1], bits![1, 1], 2);
+	/// assert_eq!(out, bits![1, 1, 0, 1, 1, 0, 1]);
+	/// ```
+	///
+	/// [`replace`]: #method.replace
+	#[cfg(feature = "alloc")]
+	pub fn replacen<D, U, E, V>(
+		&self,
+		from: &BitSlice<D, U>,
+		to: &BitSlice<E, V>,
+		count: usize,
+	) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+		E: BitOrder,
+		V: BitStore,
+	{
+		assert!(!from.is_empty(), "Pattern cannot be empty");
+		let mut out = BitVec::with_capacity(self.len());
+		let mut rest = self;
+		for _ in 0 .. count {
+			let idx = match rest.find(from) {
+				Some(idx) => idx,
+				None => break,
+			};
+			out.extend_from_bitslice(&rest[.. idx]);
+			out.extend_from_slice(to);
+			rest = &rest[idx + from.len() ..];
+		}
+		out.extend_from_bitslice(rest);
+		out
+	}
+
+	/// Provides mutable traversal of the collection.
+	///
+	/// It is impossible to implement `IndexMut` on `BitSlice`, because bits do
+	/// not have addresses, so there can be no `&mut u1`. This method allows the
+	/// client to receive an enumerated bit, and provide a new bit to set at
+	/// each index.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `func`: A function which receives a `(usize, bool)` pair of index and
+	///   value, and returns a bool. It receives the bit at each position, and
+	///   the return value is written back at that position.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0u8;
+	/// let bits = src.bits_mut::<Msb0>();
+	/// bits.for_each(|idx, _bit| idx % 3 == 0);
+	/// assert_eq!(src, 0b1001_0010);
+	/// ```
+	pub fn for_each<F>(&mut self, func: F)
+	where F: Fn(usize, bool) -> bool {
+		for idx in 0 .. self.len() {
+			unsafe {
+				let tmp = *self.get_unchecked(idx);
+				let new = func(idx, tmp);
+				self.set_unchecked(idx, new);
+			}
+		}
+	}
+
+	/// Copies all bits from `src` into `self`.
+	///
+	/// This is [`copy_from_slice`] with the copy strategy chosen by the layout
+	/// of the two slices:
+	///
+	/// - When both slices begin at the same position within a memory element,
+	///   the elements between the partial edges are copied as a block, and the
+	///   edge elements are written through masks.
+	/// - Otherwise, under [`Lsb0`] and [`Msb0`], each element of `self` is
+	///   assembled from the two elements of `src` that it overlaps, with a pair
+	///   of shifts, and written once.
+	/// - Other orderings copy one bit at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: The slice to copy from. It must have the same length as `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut dst = [0u8; 3];
+	/// let src = [0xA5u8, 0x0F, 0xF0];
+	/// dst.bits_mut::<Msb0>()[2 .. 18]
+	///     .copy_from_bitslice(&src.bits::<Msb0>()[4 .. 20]);
+	/// assert_eq!(dst, [0x14, 0x3F, 0xC0]);
+	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	/// [`copy_from_slice`]: #method.copy_from_slice
+	pub fn copy_from_bitslice(&mut self, src: &Self) {
+		assert_eq!(
+			self.len(),
+			src.len(),
+			"Copying between slices requires equal lengths",
+		);
+		//  Writes the masked bits of `bits` into an edge element, which may be
+		//  shared with another slice.
+		let edge = |elem: &T::Alias, mask: BitMask<T::Mem>, bits: T::Mem| {
+			elem.clear_bits(mask);
+			elem.set_bits(mask & bits);
+		};
+
+		if self.bitptr().head() == src.bitptr().head() {
+			//  With equal heads and lengths, both domains have the same shape.
+			match (self.domain_mut(), src.domain()) {
+				(
+					DomainMut::Enclave { head, elem, tail },
+					Domain::Enclave { elem: from, .. },
+				) => edge(elem, O::mask(head, tail), from.load()),
+				(
+					DomainMut::Region { head, body, tail },
+					Domain::Region {
+						head: src_head,
+						body: src_body,
+						tail: src_tail,
+					},
+				) => {
+					if let (Some((h, elem)), Some((_, from))) = (head, src_head)
+					{
+						edge(elem, O::mask(h, None), from.load());
+					}
+					unsafe {
+						ptr::copy_nonoverlapping(
+							src_body.as_ptr(),
+							body.as_mut_ptr(),
+							body.len(),
+						);
+					}
+					if let (Some((elem, t)), Some((from, _))) = (tail, src_tail)
+					{
+						edge(elem, O::mask(None, t), from.load());
+					}
+				},
+				_ => unsafe { hint::unreachable_unchecked() },
+			}
+			return;
+		}
+
+		let lsb0 = match Self::linear_order() {
+			Some(lsb0) => lsb0,
+			None => return self.clone_from_slice(src),
+		};
+		let width = T::Mem::BITS;
+		let read = |start, count| src.load_front(start, count, lsb0);
+		let place = |bits, at| shift_back(bits, at, lsb0);
+
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let bits = place(read(0, *tail - *head), *head);
+				edge(elem, O::mask(head, tail), bits);
+			},
+			DomainMut::Region { head, body, tail } => {
+				let mut start = 0;
+				if let Some((h, elem)) = head {
+					let count = width - *h;
+					edge(elem, O::mask(h, None), place(read(0, count), *h));
+					start += count as usize;
+				}
+				for elem in body {
+					elem.set_elem(read(start, width).retype::<T::NoAlias>());
+					start += width as usize;
+				}
+				if let Some((elem, t)) = tail {
+					edge(elem, O::mask(None, t), read(start, *t));
+				}
+			},
+		}
+	}
+
+	/// Swaps all bits in `self` with those in `other`.
+	///
+	/// This is [`swap_with_slice`] with a faster path. When both slices lay
+	/// out bits in their memory elements the same way, and begin at the same
+	/// position within an element, the elements between the partial edges
+	/// are exchanged whole. Otherwise, the bits are exchanged one at a time.
+	///
+	/// The two slices can share an edge element when they were produced by
+	/// [`split_at_mut`]. Edge elements are written only through their masks,
+	/// and each is read immediately before it is written, so the bits of the
+	/// shared element that belong to the other edge are preserved.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The slice to exchange with `self`. It must have the same
+	///   length as `self`.
+	///
+	/// # Panics
+	///
+	/// This panics if the two slices have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [0xFFu8, 0x00, 0x0F, 0xF0];
+	/// let bits = data.bits_mut::<Msb0>();
+	/// let (left, right) = bits.split_at_mut(12);
+	/// left.swap_with_bitslice(&mut right[.. 12]);
+	/// assert_eq!(data, [0x00, 0xFF, 0xF0, 0xF0]);
+	/// ```
+	///
+	/// [`split_at_mut`]: #method.split_at_mut
+	/// [`swap_with_slice`]: #method.swap_with_slice
+	pub fn swap_with_bitslice<P, U>(&mut self, other: &mut BitSlice<P, U>)
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		assert_eq!(
+			self.len(),
+			other.len(),
+			"Swapping between slices requires equal lengths",
+		);
+		let width = T::Mem::BITS;
+		let same_pos =
+			|n: u8| *O::at::<T::Mem>(n.idx()) == *P::at::<U::Mem>(n.idx());
+		let same_layout = width == U::Mem::BITS && (0 .. width).all(same_pos);
+		if !same_layout || *self.bitptr().head() != *other.bitptr().head() {
+			for (mut this, mut that) in self.iter_mut().zip(other.iter_mut()) {
+				*that = this.replace(*that);
+			}
+			return;
+		}
+
+		//  Exchanges the masked bits of two edge elements.
+		let edge = |a: &T::Alias, b: &U::Alias, mask: BitMask<T::Mem>| {
+			let (this, that) = (a.load(), b.load().retype::<T>());
+			a.clear_bits(mask);
+			a.set_bits(mask & that);
+			let mask = BitMask::new((*mask).retype::<U>());
+			b.clear_bits(mask);
+			b.set_bits(mask & this.retype::<U>());
+		};
+		//  With equal layouts, heads, and lengths, both domains have the same
+		//  shape.
+		match (self.domain_mut(), other.domain_mut()) {
+			(
+				DomainMut::Enclave { head, elem, tail },
+				DomainMut::Enclave { elem: that, .. },
+			) => edge(elem, that, O::mask(head, tail)),
+			(
+				DomainMut::Region { head, body, tail },
+				DomainMut::Region {
+					head: that_head,
+					body: that_body,
+					tail: that_tail,
+				},
+			) => {
+				if let (Some((h, elem)), Some((_, that))) = (head, that_head) {
+					edge(elem, that, O::mask(h, None));
+				}
+				unsafe {
+					ptr::swap_nonoverlapping(
+						body.as_mut_ptr() as *mut T::Mem,
+						that_body.as_mut_ptr() as *mut T::Mem,
+						body.len(),
+					);
+				}
+				if let (Some((elem, t)), Some((that, _))) = (tail, that_tail) {
+					edge(elem, that, O::mask(None, t));
+				}
+			},
+			_ => unsafe { hint::unreachable_unchecked() },
+		}
+	}
+
+	/// Exchanges the contents of two equal-length, disjoint ranges of the
+	/// slice in place.
+	///
+	/// When both ranges begin at the same position within their first memory
+	/// element, the interior elements are exchanged whole, and the partial edge
+	/// elements are exchanged through masks. Otherwise, every bit is swapped
+	/// individually. See [`swap_with_bitslice`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `a`: One range of the slice to exchange.
+	/// - `b`: The other range of the slice to exchange.
+	///
+	/// # Panics
+	///
+	/// This panics if either range is out of bounds or has its start after its
+	/// end, if the ranges have different lengths, or if they overlap. Empty
+	/// ranges never overlap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = 0b1100_0000u8;
+	/// let bits = src.bits_mut::<Msb0>();
+	/// bits.swap_ranges(0 .. 2, 5 .. 7);
+	/// assert_eq!(src, 0b0000_0110);
+	/// ```
+	///
+	/// [`swap_with_bitslice`]: #method.swap_with_bitslice
+	pub fn swap_ranges(&mut self, a: Range<usize>, b: Range<usize>) {
+		let len = self.len();
+		for range in &[&a, &b] {
+			assert!(
+				range.start <= range.end,
+				"Range {:?} starts after it ends",
+				range,
+			);
+			assert!(
+				range.end <= len,
+				"Range {:?} out of bounds: {}",
+				range,
+				len,
+			);
+		}
+		let count = a.end - a.start;
+		assert_eq!(
+			count,
+			b.end - b.start,
+			"Swapping between ranges requires equal lengths",
+		);
+		if count == 0 {
+			return;
+		}
+		assert!(
+			a.end <= b.start || b.end <= a.start,
+			"Ranges {:?} and {:?} overlap",
+			a,
+			b,
+		);
+
+		let (lo, hi) = if a.start < b.start { (a, b) } else { (b, a) };
+		let (left, right) = self.split_at_mut(hi.start);
+		let lo = unsafe { left.get_unchecked_mut(lo) };
+		let hi = unsafe { right.get_unchecked_mut(.. count) };
+		lo.swap_with_bitslice(hi);
+	}
+
+	/// Accesses the total backing stoarge of the `BitSlice`, as a slice of its
+	/// aliased elements.
+	///
+	/// Because `BitSlice` is permitted to create aliasing views to memory at
+	/// runtime, this method is required to mark the entire slice as aliased in
+	/// order to include the maybe-aliased edge elements.
+	///
+	/// You should prefer using [`.domain()`] to produce a fine-grained
+	/// view that only aliases when necessary. This method is only appropriate
+	/// when you require a single, contiguous, slice for some API.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// An aliased view of the entire memory region this slice covers, including
+	/// contended edge elements.
+	///
+	/// [`.domain()`]: #method.domain
+	pub fn as_aliased_slice(&self) -> &[T::Alias] {
+		self.bitptr().aliased_slice()
+	}
+
+	/// Accesses the backing storage of the `BitSlice` as a slice of its
+	/// elements.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A slice of all the elements that the `BitSlice` uses for storage.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [1u8, 66];
+	/// let bits = src.bits::<Msb0>();
+	///
+	/// let accum = bits
+	///     .as_slice()
+	///     .iter()
+	///     .map(|elt| elt.count_ones())
+	///     .sum::<u32>();
+	/// assert_eq!(accum, 3);
+	/// ```
+	pub fn as_slice(&self) -> &[T] {
+		unsafe {
+			&*(match self.domain() {
+				Domain::Enclave { .. } => &[],
+				Domain::Region { body, .. } => body,
+			} as *const [T::NoAlias] as *const [T])
+		}
+	}
+
+	/// Accesses the underlying store.
+	///
+	/// This will not include partially-owned edge elements, as they may be
+	/// contended by other slice handles.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [1u8, 64];
+	/// let bits = src.bits_mut::<Msb0>();
+	/// for elt in bits.as_mut_slice() {
+	///     *elt |= 2;
+	/// }
+	/// assert_eq!(&[3, 66], bits.as_slice());
+	/// ```
+	pub fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe {
+			&mut *(match self.domain() {
+				Domain::Enclave { .. } => &[],
+				Domain::Region { body, .. } => body,
+			} as *const [T::NoAlias] as *const [T] as *mut [T])
+		}
+	}
+
+	/// Views the underlying store as raw bytes.
+	///
+	/// This is [`as_slice`], reinterpreted as `u8`, and likewise does not
+	/// include partially-owned edge elements. The bytes of each element appear
+	/// in the target’s native byte order, so a `u16`, `u32`, or `u64` store
+	/// produces different byte sequences on little- and big-endian targets.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let src = [0x1234u16, 0x5678, 0x9ABC];
+	/// let bits = &src.bits::<Lsb0>()[4 .. 40];
+	/// assert_eq!(bits.as_raw_bytes(), &0x5678u16.to_ne_bytes());
+	/// ```
+	///
+	/// [`as_slice`]: #method.as_slice
+	pub fn as_raw_bytes(&self) -> &[u8] {
+		let elts = self.as_slice();
+		unsafe {
+			slice::from_raw_parts(
+				elts.as_ptr() as *const u8,
+				elts.len() * mem::size_of::<T>(),
+			)
+		}
+	}
+
+	/// Views the underlying store as mutable raw bytes.
+	///
+	/// See [`as_raw_bytes`] for the layout of the view. Partially-owned edge
+	/// elements are not included, as they may be contended by other slice
+	/// handles.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut src = [0u8; 3];
+	/// let bits = &mut src.bits_mut::<Msb0>()[4 ..];
+	/// for byte in bits.as_raw_bytes_mut() {
+	///     *byte = !0;
+	/// }
+	/// assert_eq!(src, [0, !0, !0]);
+	/// ```
+	///
+	/// [`as_raw_bytes`]: #method.as_raw_bytes
+	pub fn as_raw_bytes_mut(&mut self) -> &mut [u8] {
+		let elts = self.as_mut_slice();
+		unsafe {
+			slice::from_raw_parts_mut(
+				elts.as_mut_ptr() as *mut u8,
+				elts.len() * mem::size_of::<T>(),
+			)
+		}
+	}
+
+	/// Reads the slice as an unsigned integer, with the first bit most
+	/// significant.
+	///
+	/// The bit at index `0` is the most significant bit of the produced value,
+	/// and the last bit of the slice is its least significant bit, as when
+	/// writing the bits out as a binary numeral. This is independent of the
+	/// slice’s order and storage types. Each memory element is loaded once.
+	///
+	/// [`to_uint_le`] reads the opposite direction.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any fundamental unsigned integer. It need not be the same type as
+	///   the slice’s storage element.
+	///
+	/// # Returns
+	///
+	/// The bits of the slice as an integer, or `None` if the slice is longer
+	/// than `U` is wide. An empty slice reads as zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = bits![Lsb0, u8; 1, 0, 1, 1];
+	/// assert_eq!(bits.to_uint::<u8>(), Some(0b1011));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(0b1101));
+	///
+	/// assert!(bits![0; 9].to_uint::<u8>().is_none());
+	/// ```
+	///
+	/// [`to_uint_le`]: #method.to_uint_le
+	pub fn to_uint<U>(&self) -> Option<U>
+	where U: BitMemory {
+		self.fold_uint(|out, _, bit| out << 1u8 | bit)
+	}
+
+	/// Reads the slice as an unsigned integer, with the first bit least
+	/// significant.
+	///
+	/// The bit at index `n` is bit `n` of the produced value, counting from the
+	/// least significant. This is the convention used by `BitVec::push_uint`
+	/// and `BitVec::pop_uint`, and is the reverse of [`to_uint`].
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any fundamental unsigned integer.
+	///
+	/// # Returns
+	///
+	/// The bits of the slice as an integer, or `None` if the slice is longer
+	/// than `U` is wide. An empty slice reads as zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = &0x1234u16.bits::<Msb0>()[4 .. 12];
+	/// assert_eq!(bits.to_uint::<u8>(), Some(0x23));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(0xC4));
+	/// ```
+	///
+	/// [`to_uint`]: #method.to_uint
+	pub fn to_uint_le<U>(&self) -> Option<U>
+	where U: BitMemory {
+		self.fold_uint(|out, n, bit| out | bit << n)
+	}
+
+	/// Writes an unsigned integer into the slice, with the first bit most
+	/// significant.
+	///
+	/// This is the inverse of [`to_uint`]: the last bit of the slice receives
+	/// the least significant bit of `value`, and each earlier index receives
+	/// the next more significant bit. Bits of `value` above the slice’s length
+	/// are ignored. When the slice is longer than `U` is wide, `value` is
+	/// zero-extended, and the excess leading bits of the slice are cleared.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The integer to write.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any fundamental unsigned integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let bits = &mut data.bits_mut::<Lsb0>()[2 .. 7];
+	/// bits.set_uint(0b1_0110u8);
+	/// assert_eq!(bits.to_uint::<u8>(), Some(0b1_0110));
+	/// ```
+	///
+	/// [`to_uint`]: #method.to_uint
+	pub fn set_uint<U>(&mut self, value: U)
+	where U: BitMemory {
+		let len = self.len();
+		let mut n = 0;
+		//  Collects the bits for the indices `from .. to` of an element, in the
+		//  positions that `O` assigns to them.
+		let mut next = |from: u8, to: u8| -> T::Mem {
+			let mut out = T::Mem::ZERO;
+			for idx in from .. to {
+				//  The significance of this index within `value`.
+				let sig = len - 1 - n;
+				let set = sig < U::BITS as usize
+					&& value >> sig as u8 & U::ONE != U::ZERO;
+				out.set::<O>(idx.idx(), set);
+				n += 1;
+			}
+			out
+		};
+
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let mask = O::mask(head, tail);
+				let bits = next(*head, *tail);
+				elem.clear_bits(mask);
+				elem.set_bits(mask & bits);
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					let mask = O::mask(head, None);
+					let bits = next(*head, T::Mem::BITS);
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+				for elem in body {
+					elem.set_elem(next(0, T::Mem::BITS).retype::<T::NoAlias>());
+				}
+				if let Some((elem, tail)) = tail {
+					let mask = O::mask(None, tail);
+					let bits = next(0, *tail);
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+			},
+		}
+	}
+
+	/// Compares two slices as unsigned integers, with the first bit least
+	/// significant.
+	///
+	/// The digit order is the same as [`to_uint_le`] and `BitVec::push_uint`:
+	/// the bit at index `n` has weight 2<sup>`n`</sup>. Slices of different
+	/// lengths are compared as if the shorter were zero-extended at its high
+	/// end, so trailing zeros never affect the result, and the two slices may
+	/// have any order and storage types.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The slice to compare against.
+	///
+	/// # Returns
+	///
+	/// The ordering of the numeric value of `self` against that of `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	/// use core::cmp::Ordering;
+	///
+	/// let six = bits![Lsb0, u8; 0, 1, 1];
+	/// let five = bits![Msb0, u16; 1, 0, 1, 0, 0, 0];
+	/// assert_eq!(six.cmp_numeric(five), Ordering::Greater);
+	/// assert_eq!(five.cmp_numeric(six), Ordering::Less);
+	/// assert_eq!(six.cmp_numeric(bits![0, 1, 1, 0]), Ordering::Equal);
+	///
+	/// //  Lexicographic comparison reads the other direction.
+	/// assert!(six < five);
+	/// ```
+	///
+	/// [`to_uint_le`]: #method.to_uint_le
+	pub fn cmp_numeric<D, U>(&self, other: &BitSlice<D, U>) -> cmp::Ordering
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let len = cmp::min(self.len(), other.len());
+		//  Any set bit beyond the shared length outweighs all shared bits.
+		if self[len ..].any() {
+			return cmp::Ordering::Greater;
+		}
+		if other[len ..].any() {
+			return cmp::Ordering::Less;
+		}
+		//  Walk down from the significant end, one machine word at a time.
+		let width = <usize as BitMemory>::BITS as usize;
+		let mut end = len;
+		while end > 0 {
+			let start = end.saturating_sub(width);
+			let this = self[start .. end].to_uint_le::<usize>();
+			let that = other[start .. end].to_uint_le::<usize>();
+			match this.cmp(&that) {
+				cmp::Ordering::Equal => end = start,
+				ord => return ord,
+			}
+		}
+		cmp::Ordering::Equal
+	}
+
+	/// Subtracts a stream of bits from the slice, in place, as unsigned
+	/// integers with the first bit least significant.
+	///
+	/// The digit order is the same as [`cmp_numeric`] and [`to_uint_le`]. The
+	/// subtraction is performed modulo 2<sup>`self.len()`</sup>: `subtrahend`
+	/// is zero-extended if it yields fewer bits than the slice is long, and
+	/// any bits it yields past the slice’s length are not consumed.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `subtrahend`: A stream of bits, least significant first.
+	///
+	/// # Returns
+	///
+	/// The borrow out of the most significant bit. This is `true` exactly when
+	/// the subtraction underflowed, and the slice now holds the wrapped
+	/// difference.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0b1010u8;
+	/// let bits = &mut data.bits_mut::<Lsb0>()[.. 4];
+	/// assert!(!bits.sub_assign_reverse(bits![1, 1].iter().copied()));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(7));
+	///
+	/// //  Subtracting 8 from 7 wraps to 15, with a borrow.
+	/// assert!(bits.sub_assign_reverse(bits![0, 0, 0, 1].iter().copied()));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(15));
+	/// ```
+	///
+	/// [`cmp_numeric`]: #method.cmp_numeric
+	/// [`to_uint_le`]: #method.to_uint_le
+	pub fn sub_assign_reverse<I>(&mut self, subtrahend: I) -> bool
+	where I: IntoIterator<Item = bool> {
+		let mut subtrahend = subtrahend.into_iter().fuse();
+		let mut borrow = false;
+		for mut bit in self.iter_mut() {
+			let (a, b) = (*bit, subtrahend.next().unwrap_or(false));
+			*bit = a ^ b ^ borrow;
+			borrow = !a & (b | borrow) | b & borrow;
+		}
+		borrow
+	}
+
+	/// Adds a stream of bits into the slice, in place, as unsigned integers
+	/// with the first bit least significant.
+	///
+	/// This is the counterpart of [`sub_assign_reverse`], with the same
+	/// zero-extension and wrapping policy: the sum is taken modulo
+	/// 2<sup>`self.len()`</sup>. The bits are added one at a time; to add
+	/// another slice, [`add_assign_reverse_slice`] can work an element at a
+	/// time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `addend`: A stream of bits, least significant first.
+	///
+	/// # Returns
+	///
+	/// The carry out of the most significant bit. This is `true` exactly when
+	/// the addition overflowed, and the slice now holds the wrapped sum.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0b0110u8;
+	/// let bits = &mut data.bits_mut::<Lsb0>()[.. 4];
+	/// assert!(!bits.add_assign_reverse(bits![1, 1].iter().copied()));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(9));
+	///
+	/// //  Adding 8 to 9 wraps to 1, with a carry.
+	/// assert!(bits.add_assign_reverse(bits![0, 0, 0, 1].iter().copied()));
+	/// assert_eq!(bits.to_uint_le::<u8>(), Some(1));
+	/// ```
+	///
+	/// [`add_assign_reverse_slice`]: #method.add_assign_reverse_slice
+	/// [`sub_assign_reverse`]: #method.sub_assign_reverse
+	pub fn add_assign_reverse<I>(&mut self, addend: I) -> bool
+	where I: IntoIterator<Item = bool> {
+		self.add_bits(addend, false)
+	}
+
+	/// Adds another slice into the slice, in place, as unsigned integers with
+	/// the first bit least significant.
+	///
+	/// This has the same result as [`add_assign_reverse`] over
+	/// `addend.iter().copied()`. When `addend` lays out its bits in memory the
+	/// same way as `self`, under [`Lsb0`] or [`Msb0`], each whole element of
+	/// `self` is added to the matching bits of `addend` with a single
+	/// `overflowing_add`, and only the partial elements at the edges of `self`
+	/// are added one bit at a time. Bits of `addend` at other positions within
+	/// their elements are shifted into place as they are loaded. Any other
+	/// `addend` is added one bit at a time.
+	///
+	/// A `BitVec` addend can be passed by reference, as it dereferences to a
+	/// `BitSlice`.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `addend`: The slice to add. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// The carry out of the most significant bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = [!0u8, 0x7F, 0];
+	/// let bits = &mut data.bits_mut::<Lsb0>()[4 ..];
+	/// let one = bitvec![Lsb0, u8; 1];
+	/// assert!(!bits.add_assign_reverse_slice(&one));
+	/// assert_eq!(data, [0x0F, 0x80, 0]);
+	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	/// [`add_assign_reverse`]: #method.add_assign_reverse
+	pub fn add_assign_reverse_slice<D, U>(
+		&mut self,
+		addend: &BitSlice<D, U>,
+	) -> bool
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let (lsb0, addend) = match (Self::linear_order(), Self::coerce(addend)) {
+			(Some(lsb0), Some(addend)) => (lsb0, addend),
+			_ => return self.add_assign_reverse(addend.iter().copied()),
+		};
+		//  Under `Msb0`, index `0` is the most significant bit of an element,
+		//  so its value is reversed to put the first index in the units place.
+		let digits = |elem: T::Mem| if lsb0 { elem } else { reverse_bits(elem) };
+
+		let len = self.len();
+		let n = cmp::min(len, addend.len());
+		let width = T::Mem::BITS as usize;
+		let head = *self.bitptr().head() as usize;
+		//  The bits before the first element boundary, and after the last
+		//  whole element, are added one at a time.
+		let front = cmp::min((width - head) % width, n);
+		let back = front + (n - front) / width * width;
+
+		let mut carry =
+			self[.. front].add_bits(addend[.. front].iter().copied(), false);
+		if back > front {
+			if let DomainMut::Region { body, .. } =
+				self[front .. back].domain_mut()
+			{
+				let mut start = front;
+				for elem in body {
+					let a = digits(elem.get_elem().retype::<T>());
+					let b = digits(addend.load_front(start, width as u8, lsb0));
+					let (sum, out) = add_carry(a, b, carry);
+					elem.set_elem(digits(sum).retype::<T::NoAlias>());
+					carry = out;
+					start += width;
+				}
+			}
+		}
+		carry =
+			self[back .. n].add_bits(addend[back .. n].iter().copied(), carry);
+
+		//  Past the end of `addend`, only the carry remains to be added.
+		if n < len && carry {
+			self[n ..].increment()
+		}
+		else {
+			carry && n == len
+		}
+	}
+
+	/// Negates the slice, in place, as a two’s-complement integer with the
+	/// first bit least significant.
+	///
+	/// The digit order is the same as [`sub_assign_reverse`]: index `0` is the
+	/// least significant bit, and the last index is the sign bit. This inverts
+	/// every bit and adds one, wrapping within the slice’s width, so zero and
+	/// the minimum value (only the sign bit set) are their own negations.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 5u8;
+	/// data.bits_mut::<Lsb0>().negate_in_place();
+	/// assert_eq!(data as i8, -5);
+	///
+	/// let mut data = 0b1000u8;
+	/// let min = &mut data.bits_mut::<Lsb0>()[.. 4];
+	/// min.negate_in_place();
+	/// assert_eq!(min.to_uint_le::<u8>(), Some(0b1000));
+	/// ```
+	///
+	/// [`sub_assign_reverse`]: #method.sub_assign_reverse
+	pub fn negate_in_place(&mut self) {
+		//  Adding one to the inverse clears the inverted trailing ones, and
+		//  sets the bit after them: everything up to and including the lowest
+		//  set bit is unchanged, and everything above it is inverted.
+		if let Some(idx) = self.iter().position(|&bit| bit) {
+			let _ = !&mut self[idx + 1 ..];
+		}
+	}
+
+	/// Replaces the slice with its absolute value, as a two’s-complement
+	/// integer with the first bit least significant.
+	///
+	/// The last bit is the sign. If it is set, the slice is negated with
+	/// [`negate_in_place`]. The minimum value has no positive counterpart in
+	/// the same width, and is left unchanged, as in `iN::wrapping_abs`.
+	///
+	/// # Returns
+	///
+	/// Whether the slice was negative.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = -6i16 as u16;
+	/// assert!(data.bits_mut::<Lsb0>().abs_in_place());
+	/// assert_eq!(data, 6);
+	/// ```
+	///
+	/// [`negate_in_place`]: #method.negate_in_place
+	pub fn abs_in_place(&mut self) -> bool {
+		let negative = self.last().copied().unwrap_or(false);
+		if negative {
+			self.negate_in_place();
+		}
+		negative
+	}
+
+	/// Adds one to the slice, in place, as an unsigned integer with the first
+	/// bit least significant.
+	///
+	/// The digit order is the same as [`sub_assign_reverse`]. The slice is
+	/// scanned an element at a time for its lowest unset bit, which is set,
+	/// and all bits below it are cleared. A counter that does not ripple past
+	/// its first element touches only that element.
+	///
+	/// # Returns
+	///
+	/// The carry out of the most significant bit. This is `true` only when
+	/// every bit was set, and the slice has wrapped to zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u16;
+	/// let counter = &mut data.bits_mut::<Lsb0>()[4 .. 7];
+	/// for n in 1 .. 8 {
+	///   assert!(!counter.increment());
+	///   assert_eq!(counter.to_uint_le::<u8>(), Some(n));
+	/// }
+	/// assert!(counter.increment());
+	/// assert!(counter.not_any());
+	/// ```
+	///
+	/// [`sub_assign_reverse`]: #method.sub_assign_reverse
+	pub fn increment(&mut self) -> bool {
+		self.ripple(true)
+	}
+
+	/// Subtracts one from the slice, in place, as an unsigned integer with
+	/// the first bit least significant.
+	///
+	/// This is the inverse of [`increment`]: the lowest set bit is cleared,
+	/// and all bits below it are set.
+	///
+	/// # Returns
+	///
+	/// The borrow out of the most significant bit. This is `true` only when
+	/// the slice was zero, and has wrapped to all ones.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let counter = &mut data.bits_mut::<Msb0>()[2 .. 6];
+	/// assert!(counter.decrement());
+	/// assert!(counter.all());
+	/// assert!(!counter.decrement());
+	/// assert_eq!(counter.to_uint_le::<u8>(), Some(14));
+	/// ```
+	///
+	/// [`increment`]: #method.increment
+	pub fn decrement(&mut self) -> bool {
+		self.ripple(false)
+	}
+
+	/// Propagates a carry or borrow from the least significant bit.
+	///
+	/// The run of bits equal to `run` at the low end is inverted, along with
+	/// the first bit after it. Incrementing ripples through set bits, and
+	/// decrementing through unset bits.
+	///
+	/// # Returns
+	///
+	/// Whether the run covered the entire slice.
+	fn ripple(&mut self, run: bool) -> bool {
+		match self.first_unlike(run) {
+			Some(idx) => {
+				self[.. idx].set_all(!run);
+				self.set(idx, run);
+				false
+			},
+			None => {
+				self.set_all(!run);
+				true
+			},
+		}
+	}
+
+	/// Multiplies two slices as unsigned integers, with the first bit least
+	/// significant.
+	///
+	/// The digit order is the same as [`sub_assign_reverse`] and
+	/// [`cmp_numeric`]. The product is computed by schoolbook shift-and-add
+	/// into a single accumulator, which is allocated once.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The multiplier. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// The product, exactly `self.len() + other.len()` bits long. This is
+	/// always wide enough to hold it, and leading zeros are **not** trimmed,
+	/// so that the width of the result depends only on the widths of the
+	/// operands.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let six = bits![Lsb0, u8; 0, 1, 1];
+	/// let five = bits![Msb0, u16; 1, 0, 1];
+	/// let prod = six.mul_reverse(five);
+	/// assert_eq!(prod.len(), 6);
+	/// assert_eq!(prod.to_uint_le::<u8>(), Some(30));
+	/// ```
+	///
+	/// [`cmp_numeric`]: #method.cmp_numeric
+	/// [`sub_assign_reverse`]: #method.sub_assign_reverse
+	#[cfg(feature = "alloc")]
+	pub fn mul_reverse<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let len = self.len();
+		let mut acc = BitVec::<O, T>::repeat(false, len + other.len());
+		for (shift, _) in other.iter().enumerate().filter(|(_, &bit)| bit) {
+			//  Before this step, the accumulator is less than `self << shift`,
+			//  so the sum fits in `len + 1` bits from `shift` and the carry
+			//  never leaves this window.
+			let window = &mut acc[shift ..= shift + len];
+			window.add_assign_reverse_slice(self);
+		}
+		acc
+	}
+
+	/// Adds a stream of bits into the slice, one bit at a time, with a carry
+	/// into the least significant bit.
+	///
+	/// # Returns
+	///
+	/// The carry out of the most significant bit.
+	fn add_bits<I>(&mut self, addend: I, carry: bool) -> bool
+	where I: IntoIterator<Item = bool> {
+		let mut addend = addend.into_iter().fuse();
+		let mut carry = carry;
+		for mut bit in self.iter_mut() {
+			let (a, b) = (*bit, addend.next().unwrap_or(false));
+			*bit = a ^ b ^ carry;
+			carry = a & b | carry & (a ^ b);
+		}
+		carry
+	}
+
+	/// Finds the first bit in the slice that is not `value`.
+	///
+	/// Each memory element is loaded once, and only an element that contains
+	/// such a bit is searched, with `O::first_one`.
+	fn first_unlike(&self, value: bool) -> Option<usize> {
+		let find = |elem: T::Mem, from: u8, to: u8| {
+			let elem = if value { !elem } else { elem };
+			let live = O::mask(from.idx(), to.tail()) & elem;
+			O::first_one(*live).map(|idx| (*idx - from) as usize)
+		};
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				find(elem.load(), *head, *tail)
+			},
+			Domain::Region { head, body, tail } => {
+				let mut n = 0;
+				if let Some((head, elem)) = head {
+					if let Some(idx) = find(elem.load(), *head, T::Mem::BITS) {
+						return Some(idx);
+					}
+					n += (T::Mem::BITS - *head) as usize;
+				}
+				for elem in body {
+					let elem = elem.get_elem().retype::<T>();
+					if let Some(idx) = find(elem, 0, T::Mem::BITS) {
+						return Some(n + idx);
+					}
+					n += T::Mem::BITS as usize;
+				}
+				tail.and_then(|(elem, tail)| find(elem.load(), 0, *tail))
+					.map(|idx| n + idx)
+			},
+		}
+	}
+
+	/// Finds the last bit in the slice that is not `value`.
+	///
+	/// This is the mirror of [`first_unlike`], and searches elements from the
+	/// back of the slice with `O::last_one`.
+	///
+	/// [`first_unlike`]: #method.first_unlike
+	fn last_unlike(&self, value: bool) -> Option<usize> {
+		let find = |elem: T::Mem, from: u8, to: u8| {
+			let elem = if value { !elem } else { elem };
+			let live = O::mask(from.idx(), to.tail()) & elem;
+			O::last_one(*live).map(|idx| (*idx - from) as usize)
+		};
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				find(elem.load(), *head, *tail)
+			},
+			Domain::Region { head, body, tail } => {
+				let mut n = self.len();
+				if let Some((elem, tail)) = tail {
+					n -= *tail as usize;
+					if let Some(idx) = find(elem.load(), 0, *tail) {
+						return Some(n + idx);
+					}
+				}
+				for elem in body.iter().rev() {
+					n -= T::Mem::BITS as usize;
+					let elem = elem.get_elem().retype::<T>();
+					if let Some(idx) = find(elem, 0, T::Mem::BITS) {
+						return Some(n + idx);
+					}
+				}
+				head.and_then(|(head, elem)| {
+					find(elem.load(), *head, T::Mem::BITS)
+				})
+			},
+		}
+	}
+
+	/// Combines each element of the slice with the corresponding bits of
+	/// `rhs`, which are zero past the end of `rhs`.
+	///
+	/// `func` receives an element of the slice and an element holding the bits
+	/// of `rhs` in the positions that `O` assigns to them. Only the live bits
+	/// of its result are written back.
+	pub(crate) fn combine_with<D, U, F>(&mut self, rhs: &BitSlice<D, U>, func: F)
+	where
+		D: BitOrder,
+		U: BitStore,
+		F: Fn(T::Mem, T::Mem) -> T::Mem,
+	{
+		let mut rhs = rhs.iter().copied();
+		let mut gather = |from, to| Self::gather(&mut rhs, from, to);
+		match self.domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let mask = O::mask(head, tail);
+				let bits = func(elem.load(), gather(*head, *tail));
+				elem.clear_bits(mask);
+				elem.set_bits(mask & bits);
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					let mask = O::mask(head, None);
+					let bits = func(elem.load(), gather(*head, T::Mem::BITS));
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+				for elem in body {
+					let val = elem.get_elem().retype::<T>();
+					let bits = gather(0, T::Mem::BITS);
+					elem.set_elem(func(val, bits).retype::<T::NoAlias>());
+				}
+				if let Some((elem, tail)) = tail {
+					let mask = O::mask(None, tail);
+					let bits = func(elem.load(), gather(0, *tail));
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+			},
+		}
+	}
+
+	/// Tests whether any element of the slice, combined with the
+	/// corresponding bits of `rhs`, has a live bit set.
+	///
+	/// This reads the slice in the same way as [`combine_with`], and stops at
+	/// the first element that satisfies the test. When `rhs` lays out its bits
+	/// in memory the same way as `self`, under [`Lsb0`] or [`Msb0`], its bits
+	/// are loaded an element at a time, shifted when the two slices begin at
+	/// different positions in their elements. Otherwise, they are gathered
+	/// one at a time.
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	/// [`combine_with`]: #method.combine_with
+	fn any_with<D, U, F>(&self, rhs: &BitSlice<D, U>, func: F) -> bool
+	where
+		D: BitOrder,
+		U: BitStore,
+		F: Fn(T::Mem, T::Mem) -> T::Mem,
+	{
+		if let (Some(lsb0), Some(rhs)) =
+			(Self::linear_order(), Self::coerce(rhs))
+		{
+			let len = rhs.len();
+			return self.any_elem(func, |start, from, to| {
+				let count =
+					cmp::min((to - from) as usize, len.saturating_sub(start));
+				if count == 0 {
+					return T::Mem::ZERO;
+				}
+				let count = count as u8;
+				let bits = rhs.load_front(start, count, lsb0);
+				shift_back(*(O::mask(None, count.tail()) & bits), from, lsb0)
+			});
+		}
+		let mut rhs = rhs.iter().copied();
+		self.any_elem(func, |_, from, to| Self::gather(&mut rhs, from, to))
+	}
+
+	/// Walks the elements of the slice, and tests whether any of them,
+	/// combined with the bits that `load` produces for it, has a live bit set.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `func`: Combines an element of the slice with the bits from `load`.
+	/// - `load`: Receives the index in `self` of the first live bit of an
+	///   element, and the range of live indices within that element. It returns
+	///   an element with the other operand’s bits at those indices. It is
+	///   called once per element, in order.
+	fn any_elem<F, L>(&self, func: F, mut load: L) -> bool
+	where
+		F: Fn(T::Mem, T::Mem) -> T::Mem,
+		L: FnMut(usize, u8, u8) -> T::Mem,
+	{
+		let width = T::Mem::BITS;
+		let test = |val, bits, mask: BitMask<T::Mem>| {
+			*(mask & func(val, bits)) != T::Mem::ZERO
+		};
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				test(elem.load(), load(0, *head, *tail), O::mask(head, tail))
+			},
+			Domain::Region { head, body, tail } => {
+				let mut start = 0;
+				if let Some((head, elem)) = head {
+					let bits = load(0, *head, width);
+					if test(elem.load(), bits, O::mask(head, None)) {
+						return true;
+					}
+					start += (width - *head) as usize;
+				}
+				for elem in body {
+					let val = elem.get_elem().retype::<T>();
+					if test(val, load(start, 0, width), BitMask::ALL) {
+						return true;
+					}
+					start += width as usize;
+				}
+				tail.map_or(false, |(elem, tail)| {
+					let bits = load(start, 0, *tail);
+					test(elem.load(), bits, O::mask(None, tail))
+				})
+			},
+		}
+	}
+
+	/// Views a slice with other type parameters as `Self`, if it lays out its
+	/// bits in memory the same way.
+	///
+	/// # Returns
+	///
+	/// `other`, if `U` has the same width as `T` and `D` assigns each index
+	/// to the same position that `O` does; otherwise `None`.
+	fn coerce<D, U>(other: &BitSlice<D, U>) -> Option<&Self>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let width = T::Mem::BITS;
+		if U::Mem::BITS != width {
+			return None;
+		}
+		let same = (0 .. width)
+			.all(|n| *D::at::<U::Mem>(n.idx()) == *O::at::<T::Mem>(n.idx()));
+		if same {
+			Some(unsafe { &*(other as *const BitSlice<D, U> as *const Self) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Collects the next `to - from` bits of a stream into an element, at the
+	/// positions that `O` assigns to the indices `from .. to`.
+	///
+	/// The stream is treated as zero once it is exhausted.
+	fn gather<I>(bits: &mut I, from: u8, to: u8) -> T::Mem
+	where I: Iterator<Item = bool> {
+		let mut out = T::Mem::ZERO;
+		for idx in from .. to {
+			out.set::<O>(idx.idx(), bits.next().unwrap_or(false));
+		}
+		out
+	}
+
+	/// Folds the bits of the slice, in index order, into an integer.
+	///
+	/// Each memory element is loaded once, and its live bits are passed to
+	/// `func` as `U::ONE` or `U::ZERO`, along with their index in the slice.
+	///
+	/// # Returns
+	///
+	/// The folded value, or `None` if the slice is longer than `U` is wide.
+	fn fold_uint<U, F>(&self, mut func: F) -> Option<U>
+	where
+		U: BitMemory,
+		F: FnMut(U, u8, U) -> U,
+	{
+		if self.len() > U::BITS as usize {
+			return None;
+		}
+		let (mut out, mut n) = (U::ZERO, 0u8);
+		let mut take = |elem: T::Mem, from: u8, to: u8| {
+			for idx in from .. to {
+				let bit = elem.get::<O>(idx.idx());
+				out = func(out, n, if bit { U::ONE } else { U::ZERO });
+				n += 1;
+			}
+		};
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				take(elem.load(), *head, *tail)
+			},
+			Domain::Region { head, body, tail } => {
+				if let Some((h, elem)) = head {
+					take(elem.load(), *h, T::Mem::BITS);
+				}
+				for elem in body {
+					take(elem.get_elem().retype::<T>(), 0, T::Mem::BITS);
+				}
+				if let Some((elem, t)) = tail {
+					take(elem.load(), 0, *t);
+				}
+			},
+		}
+		Some(out)
+	}
+
+	/// Copies the slice into a new vector with different order and storage
+	/// types, preserving the sequence of bits.
+	///
+	/// This is [`to_vec`] for a destination whose parameters differ from those
+	/// of `self`. Rather than collecting individual bits, it loads each memory
+	/// element of `self` once, moves its live bits into a destination element
+	/// held in a register, and stores each destination element once when it is
+	/// full. Use [`to_vec`] when the parameters are unchanged.
+	///
+	/// # Type Parameters
+	///
+	/// - `P`: The ordering type of the produced vector.
+	/// - `U`: The storage type of the produced vector.
+	///
+	/// # Returns
+	///
+	/// A vector whose bits are equal, in sequence, to those of `self`. Its
+	/// unused bits are zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let data = [0x96u8, 0x3C];
+	/// let bits = &data.bits::<Msb0>()[4 ..];
+	/// let bv = bits.recast::<Lsb0, u16>();
+	/// assert_eq!(bv, bits);
+	/// assert_eq!(bv.as_slice(), &[0b11_1100_0110]);
+	/// ```
+	///
+	/// [`to_vec`]: #method.to_vec
+	#[cfg(feature = "alloc")]
+	pub fn recast<P, U>(&self) -> BitVec<P, U>
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		let mut out = BitVec::<P, U>::repeat(false, self.len());
+		let mut dst = out.as_mut_slice().iter_mut();
+		let mut acc = U::Mem::ZERO;
+		let mut fill = 0u8;
+		//  Moves the live bits of a source element into the accumulator, and
+		//  stores the accumulator into the next destination element each time
+		//  it fills.
+		let mut push = |elem: T::Mem, live: Range<u8>| {
+			for n in live {
+				acc.set::<P>(fill.idx(), elem.get::<O>(n.idx()));
+				fill += 1;
+				if fill == U::Mem::BITS {
+					if let Some(slot) = dst.next() {
+						slot.set_elem(acc);
+					}
+					acc = U::Mem::ZERO;
+					fill = 0;
+				}
+			}
+		};
+
+		match self.domain() {
+			Domain::Enclave { head, elem, tail } => {
+				push(elem.load(), *head .. *tail);
+			},
+			Domain::Region { head, body, tail } => {
+				if let Some((h, elem)) = head {
+					push(elem.load(), *h .. T::Mem::BITS);
+				}
+				for elem in body {
+					push(elem.get_elem().retype::<T>(), 0 .. T::Mem::BITS);
+				}
+				if let Some((elem, t)) = tail {
+					push(elem.load(), 0 .. *t);
+				}
+			},
+		}
+		//  Store the final, partially-filled, element.
+		if fill > 0 {
+			if let Some(slot) = dst.next() {
+				slot.set_elem(acc);
+			}
+		}
+		out
+	}
+
+	/// Serializes the slice into bytes, independently of its order and storage
+	/// types.
+	///
+	/// The bits are packed in sequence, most significant bit first, into
+	/// `(self.len() + 7) / 8` bytes. The unused low bits of the last byte are
+	/// zero. This is the same layout as a `BitSlice<Msb0, u8>`, and so is read
+	/// back identically by [`BitVec::from_bytes`] under any order and storage
+	/// types, on any target.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bits = &0x1234_5678u32.bits::<Lsb0>()[.. 10];
+	/// assert_eq!(bits.to_bytes(), vec![0b0001_1110, 0b0100_0000]);
+	/// ```
+	///
+	/// [`BitVec::from_bytes`]: ../vec/struct.BitVec.html#method.from_bytes
+	#[cfg(feature = "alloc")]
+	pub fn to_bytes(&self) -> Vec<u8> {
+		self.recast::<Msb0, u8>().into_vec()
+	}
+
+	/// Unpacks the slice into a vector of `bool`, one per bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bits = &0b1011_0000u8.bits::<Msb0>()[.. 4];
+	/// assert_eq!(bits.to_bool_vec(), vec![true, false, true, true]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn to_bool_vec(&self) -> Vec<bool> {
+		self.iter().copied().collect()
+	}
+
+	/// Run-length encodes the slice.
+	///
+	/// This collects [`runs`] into a vector. [`BitVec::from_runs`] decodes it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bits = bits![0, 0, 1, 0, 0, 0];
+	/// assert_eq!(bits.to_runs(), vec![(false, 2), (true, 1), (false, 3)]);
+	/// ```
+	///
+	/// [`BitVec::from_runs`]: ../vec/struct.BitVec.html#method.from_runs
+	/// [`runs`]: #method.runs
+	#[cfg(feature = "alloc")]
+	pub fn to_runs(&self) -> Vec<(bool, usize)> {
+		self.runs().collect()
+	}
+
+	/// Computes the union of two slices, treated as sets of the indices of
+	/// their set bits.
+	///
+	/// The result is as long as the longer slice. The shorter slice is treated
+	/// as if it had trailing zeros.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// A new vector with each bit set if it is set in either slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 1, 0];
+	/// let b = bits![Lsb0, u16; 0, 1, 0, 1];
+	/// assert_eq!(a.union(b), bits![1, 1, 0, 1]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn union<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let mut out = BitVec::from_bitslice(self);
+		out.union_with(other);
+		out
+	}
+
+	/// Computes the intersection of two slices, treated as sets of the
+	/// indices of their set bits.
+	///
+	/// The result is as long as the longer slice, and its bits past the end of
+	/// the shorter slice are all unset.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// A new vector with each bit set if it is set in both slices.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 1, 0];
+	/// let b = bits![Lsb0, u16; 0, 1, 0, 1];
+	/// assert_eq!(a.intersection(b), bits![0, 1, 0, 0]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn intersection<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let mut out = BitVec::from_bitslice(self);
+		out.intersection_with(other);
+		out
+	}
+
+	/// Computes the difference of two slices, treated as sets of the indices
+	/// of their set bits.
+	///
+	/// The result is as long as the longer slice. The shorter slice is treated
+	/// as if it had trailing zeros.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The set to remove. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// A new vector with each bit set if it is set in `self` and not in
+	/// `other`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 1, 0];
+	/// let b = bits![Lsb0, u16; 0, 1, 0, 1];
+	/// assert_eq!(a.difference(b), bits![1, 0, 0, 0]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn difference<D, U>(&self, other: &BitSlice<D, U>) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let mut out = BitVec::from_bitslice(self);
+		out.difference_with(other);
+		out
+	}
+
+	/// Computes the symmetric difference of two slices, treated as sets of the
+	/// indices of their set bits.
+	///
+	/// The result is as long as the longer slice. The shorter slice is treated
+	/// as if it had trailing zeros.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `other`: The other set. It may have any order and storage types.
+	///
+	/// # Returns
+	///
+	/// A new vector with each bit set if it is set in exactly one slice.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let a = bits![Msb0, u8; 1, 1, 0];
+	/// let b = bits![Lsb0, u16; 0, 1, 0, 1];
+	/// assert_eq!(a.symmetric_difference(b), bits![1, 0, 0, 1]);
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn symmetric_difference<D, U>(
+		&self,
+		other: &BitSlice<D, U>,
+	) -> BitVec<O, T>
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let mut out = BitVec::from_bitslice(self);
+		out.symmetric_difference_with(other);
+		out
+	}
+
+	/// Splits the slice into the components of its memory domain.
+	///
+	/// This produces a set of read-only aliased and unaliased subslices,
+	/// according to its pointer information. See the `BitDomain` documentation
+	/// for more information about the returned descriptor.
+	pub fn bit_domain(&self) -> BitDomain<O, T> {
+		self.into()
+	}
+
+	/// Splits the slice into the components of its memory domain.
+	///
+	/// This produces a set of writable aliased and unaliased subslices,
+	/// according to its pointer information. See the `BitDomainMut`
+	/// documentation for more information about the returned descriptor.
+	pub fn bit_domain_mut(&mut self) -> BitDomainMut<O, T> {
+		self.into()
+	}
+
+	/// Splits the slice into references to its underlying memory elements.
+	///
+	/// Unlike `.bit_domain()` and `.bit_domain_mut()`, this does not return
+	/// smaller, specialized, `BitSlice` handles, but rather appropriately
+	/// un/aliased references to memory elements.
+	///
+	/// The aliased references allow mutation of these elements. You are
+	/// required to not mutate through these references. This function is not
+	/// marked `unsafe`, but this is a contract you must uphold. Use
+	/// [`.domain_mut()`] for mutation.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A read-only descriptor of the memory elements underneath `*self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   domain::Domain,
+	///   prelude::*,
+	/// };
+	///
+	/// let data = [0x3Cu8, 0xFF, 0xA5];
+	/// let bits = &data.bits::<Lsb0>()[4 .. 20];
+	///
+	/// if let Domain::Region { head, body, tail } = bits.domain() {
+	///   let (h, elem) = head.unwrap();
+	///   assert_eq!(*h, 4);
+	///   assert_eq!(elem.get_elem(), 0x3C);
+	///   assert_eq!(body, &[0xFF]);
+	///   let (elem, t) = tail.unwrap();
+	///   assert_eq!(elem.get_elem(), 0xA5);
+	///   assert_eq!(*t, 4);
+	/// }
+	/// else { unreachable!(); }
+	/// ```
+	///
+	/// [`.domain_mut()`]: #method.domain_mut
+	pub fn domain(&self) -> Domain<T> {
+		self.into()
+	}
+
+	/// Splits the slice into mutable references to its underlying memory
+	/// elements.
+	///
+	/// Like `.domain()`, this returns appropriately un/aliased references to
+	/// memory elements. These references are all writable.
+	///
+	/// The aliased edge references permit modifying memory beyond their bit
+	/// marker. You are required to only mutate the region of these edge
+	/// elements that you currently govern. This function is not marked
+	/// `unsafe`, but this is a contract you must uphold.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	///
+	/// # Returns
+	///
+	/// A descriptor of the memory elements underneath `*self`, permitting
+	/// mutation.
+	///
+	/// # Examples
+	///
+	/// The fully-spanned `body` elements are exclusively governed by the slice,
+	/// and may be written directly.
+	///
+	/// ```rust
+	/// use bitvec::{
+	///   domain::DomainMut,
+	///   prelude::*,
+	/// };
+	///
+	/// let mut data = [0u8; 4];
+	/// let bits = &mut data.bits_mut::<Msb0>()[6 .. 26];
+	///
+	/// if let DomainMut::Region { body, .. } = bits.domain_mut() {
+	///   for elem in body.iter_mut() {
+	///     *elem = !0;
+	///   }
+	/// }
+	/// assert_eq!(data, [0, !0, !0, 0]);
+	/// ```
+	pub fn domain_mut(&mut self) -> DomainMut<T> {
+		self.into()
+	}
+
+	/// Accesses the underlying pointer structure.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// The [`BitPtr`] structure of the slice handle.
+	///
+	/// [`BitPtr`]: ../pointer/struct.BitPtr.html
+	#[inline]
+	pub(crate) fn bitptr(&self) -> BitPtr<T> {
+		BitPtr::from_bitslice(self)
+	}
+
+	/// Finds which way the ordering moves through an element, if it is one of
+	/// the two orderings that place adjacent indices at adjacent positions.
+	///
+	/// # Returns
+	///
+	/// `Some(true)` if `O` behaves as `Lsb0` on `T`, `Some(false)` if it
+	/// behaves as `Msb0`, and `None` otherwise. Callers use this to move whole
+	/// elements with shifts, which is only correct for these two orderings.
+	pub(crate) fn linear_order() -> Option<bool> {
+		let width = T::Mem::BITS;
+		let (mut lsb0, mut msb0) = (true, true);
+		for n in 0 .. width {
+			let pos = *O::at::<T::Mem>(n.idx());
+			lsb0 &= pos == n;
+			msb0 &= pos == width - 1 - n;
+		}
+		if lsb0 {
+			Some(true)
+		}
+		else if msb0 {
+			Some(false)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Loads up to one element’s worth of bits into the front of an element.
+	///
+	/// This is only correct for the orderings that [`linear_order`] accepts.
+	/// At most two memory elements are read, and the one after the first is
+	/// only read when the requested bits reach into it.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `start`: The index in `self` of the first bit to load.
+	/// - `count`: The number of bits to load. `start + count` must not exceed
+	///   `self.len()`, and `count` must not exceed `T::Mem::BITS`.
+	/// - `lsb0`: The result of [`linear_order`] for this ordering.
+	///
+	/// # Returns
+	///
+	/// An element holding the requested bits at the indices `0 .. count`. The
+	/// bits after them are unspecified.
+	///
+	/// [`linear_order`]: #method.linear_order
+	pub(crate) fn load_front(
+		&self,
+		start: usize,
+		count: u8,
+		lsb0: bool,
+	) -> T::Mem {
+		let bitptr = self.bitptr();
+		let width = T::Mem::BITS as usize;
+		let abs = *bitptr.head() as usize + start;
+		let off = (abs % width) as u8;
+		unsafe {
+			let elem = bitptr.pointer().a().add(abs / width);
+			let lo = (*elem).load();
+			let hi = if off as usize + count as usize > width {
+				(*elem.add(1)).load()
+			}
+			else {
+				T::Mem::ZERO
+			};
+			funnel(lo, hi, off, lsb0)
+		}
+	}
+
+	/// Resolves a range of any kind against the slice, without checking it.
+	///
+	/// Inclusive bounds at `usize::MAX` saturate rather than overflow, so that
+	/// callers reject them with their ordinary out-of-bounds check.
+	pub(crate) fn bounds<R>(&self, range: R) -> Range<usize>
+	where R: RangeBounds<usize> {
+		let from = match range.start_bound() {
+			Bound::Included(&n) => n,
+			Bound::Excluded(&n) => n.saturating_add(1),
+			Bound::Unbounded => 0,
+		};
+		let upto = match range.end_bound() {
+			Bound::Included(&n) => n.saturating_add(1),
+			Bound::Excluded(&n) => n,
+			Bound::Unbounded => self.len(),
+		};
+		from .. upto
+	}
+
+	/// Copies a bit from one location in a slice to another, without doing
+	/// bounds checking.
+	///
+	/// # Parameters
 	///
 	/// - `&mut self`
 	/// - `from`: The index of the bit to be copied.
@@ -1065,10 +4274,23 @@ where
 	///
 	/// # Safety
 	///
-	/// `from` and `to` must be within the bounds of `self`. This is not
-	/// checked.
+	/// `from` and `to` must be within the bounds of `self`. This is only
+	/// checked in builds with `debug_assertions` enabled.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0x80u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// unsafe {
+	///     bits.copy_unchecked(0, 7);
+	/// }
+	/// assert_eq!(data, 0x81);
+	/// ```
 	#[inline]
-	pub(crate) unsafe fn copy_unchecked(&mut self, from: usize, to: usize) {
+	pub unsafe fn copy_unchecked(&mut self, from: usize, to: usize) {
 		self.set_unchecked(to, *self.get_unchecked(from));
 	}
 
@@ -1242,4 +4464,5 @@ pub use self::{
 };
 
 #[cfg(test)]
+#[allow(clippy::cognitive_complexity)] // Permit large test functions
 mod tests;
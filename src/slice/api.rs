@@ -12,8 +12,17 @@ be documented in a section called `API Differences`.
 
 use crate::{
 	access::BitAccess,
-	index::BitIdx,
-	mem::BitMemory,
+	index::{
+		BitIdx,
+		BitMask,
+		Indexable,
+	},
+	mem::{
+		funnel,
+		reverse_bits,
+		shift_back,
+		BitMemory,
+	},
 	order::BitOrder,
 	pointer::BitPtr,
 	slice::{
@@ -49,17 +58,24 @@ use core::{
 	cmp,
 	marker::PhantomData,
 	ops::{
+		BitXor,
 		Range,
+		RangeBounds,
 		RangeFrom,
 		RangeFull,
 		RangeInclusive,
 		RangeTo,
 		RangeToInclusive,
 	},
-	ptr::NonNull,
+	ptr::{
+		self,
+		NonNull,
+	},
 	slice,
 };
 
+use funty::IsInteger;
+
 #[cfg(feature = "alloc")]
 use crate::vec::BitVec;
 
@@ -81,8 +97,8 @@ inferred is a suitable lifetime for the returned slice.
 requirements in the `bitvec` data structure operations. You can obtain a pointer
 that is usable as `data` for zero-length slices from [`NonNull::dangling()`].
 
-The total size of the bit slice must be no larger than `BitPtr::<T>::MAX_BITS`
-**bits** in memory. See the safety documentation of `BitPtr` (if available).
+The total size of the bit slice must be no larger than `usize::MAX >> 3`
+**bits**, which is the longest length that a `BitSlice` handle can encode.
 
 # Caveat
 
@@ -133,7 +149,7 @@ This function is unsafe for the same reasons as [`bits_from_raw_parts`], as well
 as not being able to provide a non-aliasing guarantee of the returned mutable
 slice. `data` must be non-null and aligned even for zero-length slices as with
 [`bits_from_raw_parts`]. The total size of the slice must be no larger than
-`BitPtr::<T>::MAX_ELTS` **elements** `T` in memory.
+`usize::MAX >> 3` **bits**, as for [`bits_from_raw_parts`].
 
 See the documentation of [`bits_from_raw_parts`] for more details.
 
@@ -188,8 +204,8 @@ the returned slice.
 requirements in the `bitvec` data structure operations. You can obtain a pointer
 that is usable as `data` for zero-length slices from [`NonNull::dangling()`].
 
-The total size of the bit slice must be no larger than `BitPtr::<T>::MAX_BITS`
-**bits** in memory. See the safety documentation of `BitPtr` (if available).
+The total size of the bit slice must be no larger than `usize::MAX >> 3`
+**bits**, which is the longest length that a `BitSlice` handle can encode.
 
 # Caveat
 
@@ -233,7 +249,7 @@ This function is unsafe for the same reason as [`from_raw_parts`], as well as
 not being able to provide a non-aliasing guarantee of the returned mutable
 slice. `data` must be non-null and aligned even for zero-length slices as with
 [`from_raw_parts`]. The total size of the slice must be no larger than
-`BitPtr::<T>::MAX_ELTS` **elements** in memory.
+`usize::MAX >> 3` **bits**, as for [`from_raw_parts`].
 
 See the documentation of [`from_raw_parts`] for more details.
 
@@ -586,7 +602,8 @@ where
 	/// As this function does not perform boundary checking, the caller must
 	/// ensure that `self` is an index within the boundaries of `slice` before
 	/// calling in order to avoid boundary escapes and ensuing safety
-	/// violations.
+	/// violations. Builds with `debug_assertions` enabled check `usize`
+	/// indices anyway, and panic if they are out of bounds.
 	///
 	/// # Examples
 	///
@@ -618,7 +635,8 @@ where
 	/// As this function does not perform boundary checking, the caller must
 	/// ensure that `self` is an index within the boundaries of `slice` before
 	/// calling in order to avoid boundary escapes and ensuing safety
-	/// violations.
+	/// violations. Builds with `debug_assertions` enabled check `usize`
+	/// indices anyway, and panic if they are out of bounds.
 	///
 	/// # Examples
 	///
@@ -743,6 +761,11 @@ where
 
 	/// Reverses the order of bits in the slice, in place.
 	///
+	/// Under [`Lsb0`] and [`Msb0`], whole element-widths of bits are taken
+	/// from each end of the slice, bit-reversed in a register, and exchanged.
+	/// The fewer than two element-widths of bits left in the middle, and every
+	/// bit under other orderings, are exchanged one pair at a time.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -752,12 +775,74 @@ where
 	/// bits[1 ..].reverse();
 	/// assert_eq!(data, 0b1_0011001);
 	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
 	pub fn reverse(&mut self) {
+		let len = self.len();
+		let width = T::Mem::BITS as usize;
+		//  The number of bits at each end that are already exchanged.
+		let mut done = 0;
+
+		if let Some(lsb0) = Self::linear_order() {
+			let bitptr = self.bitptr();
+			let (base, head) = (bitptr.pointer().a(), *bitptr.head() as usize);
+			let w = T::Mem::BITS;
+			/* Element-width chunks of the slice are held so that reversing the
+			chunk is the same as reversing the bits of the register. Under
+			`Lsb0`, slice bit `start + n` is register bit `n`; under `Msb0`, it
+			is register bit `w - 1 - n`. In both cases, this is the position of
+			the bit in the memory elements, shifted by its offset.
+			*/
+			//  Finds the element holding slice bit `start`, and its offset. A
+			//  chunk at a nonzero offset continues into the next element.
+			let split = |start: usize| unsafe {
+				let abs = head + start;
+				(base.add(abs / width), (abs % width) as u8)
+			};
+			let read = |start: usize| unsafe {
+				let (elem, off) = split(start);
+				let lo = (*elem).load();
+				let hi = if off == 0 {
+					T::Mem::ZERO
+				}
+				else {
+					(*elem.add(1)).load()
+				};
+				funnel(lo, hi, off, lsb0)
+			};
+			let put = |elem: &T::Access, mask: BitMask<T::Mem>, bits: T::Mem| {
+				elem.clear_bits(mask);
+				elem.set_bits(mask & bits);
+			};
+			let write = |start: usize, bits: T::Mem| unsafe {
+				let (elem, off) = split(start);
+				if off == 0 {
+					put(&*elem, BitMask::ALL, bits);
+				}
+				else {
+					let front = shift_back(bits, off, lsb0);
+					let back = funnel(bits, T::Mem::ZERO, w - off, lsb0);
+					put(&*elem, O::mask(off.idx(), None), front);
+					put(&*elem.add(1), O::mask(None, off.tail()), back);
+				}
+			};
+
+			while len - 2 * done >= 2 * width {
+				let back = len - done - width;
+				let (front_bits, back_bits) = (read(done), read(back));
+				write(done, reverse_bits(back_bits));
+				write(back, reverse_bits(front_bits));
+				done += width;
+			}
+		}
+
 		/* This is better implemented as a recursive algorithm, but Rust doesn’t
 		yet flatten recursive tail calls into a loop, so it is done manually
 		here.
 		*/
-		let mut cur: &mut Self = self;
+		let mut cur: &mut Self =
+			unsafe { self.get_unchecked_mut(done .. len - done) };
 		loop {
 			let len = cur.len();
 			//  Terminate when only one or zero bits remain to switch.
@@ -840,6 +925,17 @@ where
 	/// let bits = data.bits::<Local>();
 	/// let mut iter = bits[.. 3].windows(4);
 	/// assert!(iter.next().is_none());
+	/// ```
+	///
+	/// Searching for a pattern:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let data = 0b0110_1001u8;
+	/// let bits = data.bits::<Msb0>();
+	/// let needle = bits![0, 0, 1];
+	/// assert_eq!(bits.windows(3).position(|w| w == needle), Some(5));
+	/// ```
 	pub fn windows(&self, width: usize) -> Windows<O, T> {
 		assert_ne!(width, 0, "Window width cannot be zero");
 		super::Windows { inner: self, width }
@@ -1714,6 +1810,13 @@ where
 
 	/// Returns `true` if `prefix` is a prefix of the slice.
 	///
+	/// This is `false` when `prefix` is longer than the slice, and `true` when
+	/// it is empty. The bits are compared one memory element of the slice at a
+	/// time. When `prefix` has the same memory layout as the slice, under
+	/// [`Lsb0`] or [`Msb0`], its elements are compared whole if both begin at
+	/// the same position in an element, and are shifted into place otherwise.
+	/// Prefixes with other layouts are read one bit at a time.
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -1722,17 +1825,25 @@ where
 	/// let bits = data.bits::<Msb0>();
 	/// assert!(bits.starts_with(&data.bits::<Lsb0>()[.. 2]));
 	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
 	pub fn starts_with<P, U>(&self, prefix: &BitSlice<P, U>) -> bool
 	where
 		P: BitOrder,
 		U: BitStore,
 	{
 		let plen = prefix.len();
-		self.len() >= plen && prefix == unsafe { self.get_unchecked(.. plen) }
+		self.len() >= plen
+			&& !unsafe { self.get_unchecked(.. plen) }
+				.any_with(prefix, BitXor::bitxor)
 	}
 
 	/// Returns `true` if `suffix` is a suffix of the slice.
 	///
+	/// This is `false` when `suffix` is longer than the slice, and `true` when
+	/// it is empty. It compares elements in the same way as [`starts_with`].
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -1741,6 +1852,8 @@ where
 	/// let bits = data.bits::<Msb0>();
 	/// assert!(bits.ends_with(&data.bits::<Lsb0>()[6 ..]));
 	/// ```
+	///
+	/// [`starts_with`]: #method.starts_with
 	pub fn ends_with<P, U>(&self, suffix: &BitSlice<P, U>) -> bool
 	where
 		P: BitOrder,
@@ -1748,7 +1861,120 @@ where
 	{
 		let slen = suffix.len();
 		let len = self.len();
-		len >= slen && suffix == unsafe { self.get_unchecked(len - slen ..) }
+		len >= slen
+			&& !unsafe { self.get_unchecked(len - slen ..) }
+				.any_with(suffix, BitXor::bitxor)
+	}
+
+	/// Returns the rest of the slice after `prefix`, if the slice starts with
+	/// it.
+	///
+	/// The returned slice is a view into the same memory as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let data = 0b0110_1110u8;
+	/// let bits = data.bits::<Msb0>();
+	/// assert_eq!(bits.strip_prefix(bits![0, 1, 1]), Some(&bits[3 ..]));
+	/// assert!(bits.strip_prefix(bits![1]).is_none());
+	/// ```
+	pub fn strip_prefix<P, U>(&self, prefix: &BitSlice<P, U>) -> Option<&Self>
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		if self.starts_with(prefix) {
+			Some(unsafe { self.get_unchecked(prefix.len() ..) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Returns the rest of the slice before `suffix`, if the slice ends with
+	/// it.
+	///
+	/// The returned slice is a view into the same memory as `self`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let data = 0b0110_1110u8;
+	/// let bits = data.bits::<Msb0>();
+	/// assert_eq!(bits.strip_suffix(bits![1, 1, 0]), Some(&bits[.. 5]));
+	/// assert!(bits.strip_suffix(bits![1]).is_none());
+	/// ```
+	pub fn strip_suffix<P, U>(&self, suffix: &BitSlice<P, U>) -> Option<&Self>
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		if self.ends_with(suffix) {
+			Some(unsafe { self.get_unchecked(.. self.len() - suffix.len()) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Returns the rest of the slice after `prefix`, mutably, if the slice
+	/// starts with it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut data = 0b0110_1110u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// bits.strip_prefix_mut(bits![0, 1, 1]).unwrap().set_all(false);
+	/// assert_eq!(data, 0b0110_0000);
+	/// ```
+	pub fn strip_prefix_mut<P, U>(
+		&mut self,
+		prefix: &BitSlice<P, U>,
+	) -> Option<&mut Self>
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		if self.starts_with(prefix) {
+			Some(unsafe { self.get_unchecked_mut(prefix.len() ..) })
+		}
+		else {
+			None
+		}
+	}
+
+	/// Returns the rest of the slice before `suffix`, mutably, if the slice
+	/// ends with it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut data = 0b0110_1110u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// bits.strip_suffix_mut(bits![0]).unwrap().set_all(true);
+	/// assert_eq!(data, 0b1111_1110);
+	/// ```
+	pub fn strip_suffix_mut<P, U>(
+		&mut self,
+		suffix: &BitSlice<P, U>,
+	) -> Option<&mut Self>
+	where
+		P: BitOrder,
+		U: BitStore,
+	{
+		if self.ends_with(suffix) {
+			let end = self.len() - suffix.len();
+			Some(unsafe { self.get_unchecked_mut(.. end) })
+		}
+		else {
+			None
+		}
 	}
 
 	/// Rotates the slice in-place such that the first `by` bits of the slice
@@ -2016,7 +2242,106 @@ where
 	///
 	/// [`split_at_mut`]: #method.split_at_mut
 	pub fn copy_from_slice(&mut self, src: &Self) {
-		self.clone_from_slice(src)
+		self.copy_from_bitslice(src)
+	}
+
+	/// Copies bits from one part of the slice to another part of itself.
+	///
+	/// `src` is the range within `self` to copy from. `dest` is the starting
+	/// index of the range within `self` to copy to, which will have the same
+	/// length as `src`. The two ranges may overlap. The ends of the two ranges
+	/// must be less than or equal to `self.len()`.
+	///
+	/// When `src` and `dest` begin at the same position within a memory
+	/// element, the elements between the partial edges are moved whole.
+	///
+	/// # Panics
+	///
+	/// This function will panic if either range exceeds the end of the slice,
+	/// or if the end of `src` is before the start.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut data = 0x07u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// bits.copy_within(5 .., 0);
+	/// assert_eq!(bits.as_slice(), &[0xE7]);
+	/// bits.copy_within(.. 4, 2);
+	/// assert_eq!(bits.as_slice(), &[0xFB]);
+	/// ```
+	pub fn copy_within<R>(&mut self, src: R, dest: usize)
+	where R: RangeBounds<usize> {
+		/// Copies the bits at `range` from `from + range` to `to + range`, in
+		/// the order that reads each overlapping bit before overwriting it.
+		unsafe fn copy_bits<O, T>(
+			this: &mut BitSlice<O, T>,
+			(from, to): (usize, usize),
+			range: Range<usize>,
+		) where
+			O: BitOrder,
+			T: BitStore,
+		{
+			if to > from {
+				for n in range.rev() {
+					this.copy_unchecked(from + n, to + n);
+				}
+			}
+			else {
+				for n in range {
+					this.copy_unchecked(from + n, to + n);
+				}
+			}
+		}
+
+		let len = self.len();
+		let src = self.bounds(src);
+		assert!(src.start <= src.end, "Range {:?} starts after it ends", src);
+		assert!(src.end <= len, "Range {:?} out of bounds: {}", src, len);
+		let Range { start, end } = src;
+		let count = end - start;
+		assert!(
+			dest <= len - count,
+			"Destination {:?} out of bounds: {}",
+			dest .. dest + count,
+			len,
+		);
+		if count == 0 || start == dest {
+			return;
+		}
+
+		let bits = T::Mem::BITS as usize;
+		let bitptr = self.bitptr();
+		let head = *bitptr.head() as usize;
+		//  The bits up to the first element boundary, then the whole elements
+		//  after it, are moved as a unit only when both ranges share a phase.
+		let (edge, whole) = if start % bits == dest % bits {
+			let edge = cmp::min(count, (bits - (head + start) % bits) % bits);
+			(edge, (count - edge) / bits)
+		}
+		else {
+			(count, 0)
+		};
+		let body = edge .. edge + whole * bits;
+		let ends = (start, dest);
+		let base = bitptr.pointer().w();
+		let from = ((head + start + edge) / bits) as isize;
+		let to = ((head + dest + edge) / bits) as isize;
+		unsafe {
+			let head = 0 .. edge;
+			let tail = body.end .. count;
+			//  Moving up copies from the back, and moving down from the front.
+			let (first, last) = if dest > start {
+				(tail, head)
+			}
+			else {
+				(head, tail)
+			};
+			copy_bits(self, ends, first);
+			ptr::copy(base.offset(from), base.offset(to), whole);
+			copy_bits(self, ends, last);
+		}
 	}
 
 	/// Swaps all bits in `self` with those in `other`.
@@ -2076,18 +2401,7 @@ where
 		P: BitOrder,
 		U: BitStore,
 	{
-		assert_eq!(
-			self.len(),
-			other.len(),
-			"Swapping between slices requires equal lengths",
-		);
-		self.iter_mut()
-			.zip(other.iter_mut())
-			.for_each(|(mut this, mut that)| {
-				let (a, b) = (*this, *that);
-				*this = b;
-				*that = a;
-			})
+		self.swap_with_bitslice(other)
 	}
 
 	/// Transmute the slice to a slice with a different backing store, ensuring
@@ -2099,6 +2413,20 @@ where
 	/// greatest length possible for a given type and input slice, but only your
 	/// algorithm’s performance should depend on that, not its correctness.
 	///
+	/// The middle slice only covers memory elements that lie entirely within
+	/// `self`, and begins and ends on element boundaries of both `T` and `U`,
+	/// so it never shares an element with the prefix or suffix.
+	///
+	/// # Bit Order
+	///
+	/// The middle slice views the same memory as a sequence of `U` elements,
+	/// ordered by `O` within each `U` element. Its bits therefore match the
+	/// corresponding bits of `self` only when the ordering and the host byte
+	/// order agree: [`Lsb0`] on little-endian targets and [`Msb0`] on
+	/// big-endian targets. In every other case, the middle slice is the
+	/// `U` values read from memory in native byte order, and then indexed by
+	/// `O`.
+	///
 	/// # Safety
 	///
 	/// This method is essentially a `transmute` with respect to the elements in
@@ -2128,14 +2456,32 @@ where
 	///     }
 	/// }
 	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
 	pub unsafe fn align_to<U>(&self) -> (&Self, &BitSlice<O, U>, &Self)
 	where U: BitStore {
 		let bitptr = self.bitptr();
-		let (l, c, r) = bitptr.as_slice().align_to::<U>();
-		let l_start = *bitptr.head() as usize;
-		let l = &Self::from_slice(l)[l_start ..];
-		let c = BitSlice::from_slice(c);
-		let r = &Self::from_slice(r)[.. bitptr.len() - l.len() - c.len()];
+		let (head, len) = (*bitptr.head() as usize, bitptr.len());
+		let (l, c, _) = bitptr.as_slice().align_to::<U>();
+		//  Measure the live bits against the start of the aligned elements,
+		let base = l.len() * T::Mem::BITS as usize;
+		let start = head.saturating_sub(base);
+		let end = cmp::min(
+			(head + len).saturating_sub(base),
+			c.len() * U::Mem::BITS as usize,
+		);
+		//  And round them inwards to boundaries of both element types.
+		let step = cmp::max(T::Mem::BITS, U::Mem::BITS) as usize;
+		let from = (start + step - 1) / step * step;
+		let upto = end / step * step;
+		if from >= upto {
+			return (self, BitSlice::empty(), &self[len ..]);
+		}
+		let width = U::Mem::BITS as usize;
+		let c = BitSlice::from_slice(&c[from / width .. upto / width]);
+		let l = &self[.. base + from - head];
+		let r = &self[base + upto - head ..];
 		(l, c, r)
 	}
 
@@ -2196,6 +2542,58 @@ where
 	pub fn to_vec(&self) -> BitVec<O, T> {
 		BitVec::from_bitslice(self)
 	}
+
+	/// Creates a vector by repeating a slice `n` times.
+	///
+	/// The vector is allocated once, at its full length. The first copy of
+	/// `self` is then repeatedly doubled by copying the already-written front
+	/// of the vector onto its back, so `self` is only read once.
+	///
+	/// # Panics
+	///
+	/// This function will panic if the length of the produced vector would
+	/// overflow `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bits = bits![0, 1, 1];
+	/// assert_eq!(bits.repeat(3), bitvec![0, 1, 1, 0, 1, 1, 0, 1, 1]);
+	/// assert!(bits.repeat(0).is_empty());
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn repeat(&self, n: usize) -> BitVec<O, T> {
+		let len = self.len();
+		let total = len
+			.checked_mul(n)
+			.filter(|&total| total <= BitPtr::<T>::MAX_BITS)
+			.unwrap_or_else(|| {
+				panic!(
+					"Capacity overflow: {} repetitions of {} bits exceeds {}",
+					n,
+					len,
+					BitPtr::<T>::MAX_BITS,
+				)
+			});
+		if total == 0 {
+			return BitVec::new();
+		}
+
+		let mut out = BitVec::from_bitslice(self);
+		out.reserve(total - len);
+		unsafe {
+			out.set_len(total);
+		}
+		let mut filled = len;
+		while filled < total {
+			let chunk = cmp::min(filled, total - filled);
+			let (src, dest) = out.split_at_mut(filled);
+			dest[.. chunk].copy_from_slice(&src[.. chunk]);
+			filled += chunk;
+		}
+		out
+	}
 }
 
 /** Replacement for [`slice::SliceIndex`].
@@ -2351,6 +2749,12 @@ where
 	}
 
 	unsafe fn get_unchecked(self, slice: &'a BitSlice<O, T>) -> Self::Immut {
+		debug_assert!(
+			self < slice.len(),
+			"Index out of range: {} >= {}",
+			self,
+			slice.len(),
+		);
 		let bitptr = slice.bitptr();
 		let (elt, bit) = bitptr.head().offset(self as isize);
 		let data_ptr = bitptr.pointer().a();
@@ -2369,14 +2773,22 @@ where
 		slice: &'a mut BitSlice<O, T>,
 	) -> Self::Mut
 	{
+		debug_assert!(
+			self < slice.len(),
+			"Index out of range: {} >= {}",
+			self,
+			slice.len(),
+		);
 		let bp = slice.bitptr();
 		let (offset, head) = bp.head().offset(self as isize);
 		let ptr = bp.pointer().a().offset(offset);
+		let bit = (*ptr).get::<O>(head);
 		BitMut {
 			_parent: PhantomData,
 			data: NonNull::new_unchecked(ptr as *mut T::Access),
 			head,
-			bit: (*ptr).get::<O>(head),
+			bit,
+			orig: bit,
 		}
 	}
 
@@ -2546,15 +2958,16 @@ range_impl! {
 	};
 }
 
+//  Saturation keeps `..= usize::MAX` from overflowing. No slice is that long,
+//  so the saturated range is still out of bounds.
 range_impl! {
 	RangeInclusive<usize> => map |this: Self| {
-		#[allow(clippy::range_plus_one)]
-		(*this.start() .. *this.end() + 1)
+		let end: usize = *this.end();
+		*this.start() .. end.saturating_add(1)
 	};
 
-	RangeToInclusive<usize> => map |RangeToInclusive { end }| {
-		#[allow(clippy::range_plus_one)]
-		(.. end + 1)
+	RangeToInclusive<usize> => map |RangeToInclusive { end }: Self| {
+		.. end.saturating_add(1)
 	};
 }
 
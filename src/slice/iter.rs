@@ -12,6 +12,7 @@ use core::{
 		Formatter,
 	},
 	iter::FusedIterator,
+	marker::PhantomData,
 	mem,
 };
 
@@ -358,6 +359,236 @@ where
 {
 }
 
+/** An iterator over the indices of the set bits in a slice.
+
+This struct is created by the [`iter_ones`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`iter_ones`]: struct.BitSlice.html#method.iter_ones
+**/
+#[derive(Clone, Debug)]
+pub struct IterOnes<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The index in the original slice of the first bit of `inner`.
+	pub(super) front: usize,
+}
+
+impl<'a, O, T> Iterator for IterOnes<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.inner.first_unlike(false) {
+			Some(idx) => {
+				self.inner = unsafe { self.inner.get_unchecked(idx + 1 ..) };
+				let out = self.front + idx;
+				self.front = out + 1;
+				Some(out)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len()))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<'a, O, T> DoubleEndedIterator for IterOnes<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match self.inner.last_unlike(false) {
+			Some(idx) => {
+				self.inner = unsafe { self.inner.get_unchecked(.. idx) };
+				Some(self.front + idx)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+}
+
+impl<O, T> FusedIterator for IterOnes<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** An iterator over the indices of the unset bits in a slice.
+
+This struct is created by the [`iter_zeros`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`iter_zeros`]: struct.BitSlice.html#method.iter_zeros
+**/
+#[derive(Clone, Debug)]
+pub struct IterZeros<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The index in the original slice of the first bit of `inner`.
+	pub(super) front: usize,
+}
+
+impl<'a, O, T> Iterator for IterZeros<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.inner.first_unlike(true) {
+			Some(idx) => {
+				self.inner = unsafe { self.inner.get_unchecked(idx + 1 ..) };
+				let out = self.front + idx;
+				self.front = out + 1;
+				Some(out)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len()))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<'a, O, T> DoubleEndedIterator for IterZeros<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		match self.inner.last_unlike(true) {
+			Some(idx) => {
+				self.inner = unsafe { self.inner.get_unchecked(.. idx) };
+				Some(self.front + idx)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+}
+
+impl<O, T> FusedIterator for IterZeros<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
+/** An iterator over the maximal runs of identical bits in a slice.
+
+Each item is the value of a run and its length. This struct is created by the
+[`runs`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`runs`]: struct.BitSlice.html#method.runs
+**/
+#[derive(Clone, Debug)]
+pub struct Runs<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+}
+
+impl<'a, O, T> Iterator for Runs<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Item = (bool, usize);
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.first()?;
+		let len = self
+			.inner
+			.first_unlike(value)
+			.unwrap_or_else(|| self.inner.len());
+		self.inner = unsafe { self.inner.get_unchecked(len ..) };
+		Some((value, len))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.inner.len();
+		((len > 0) as usize, Some(len))
+	}
+
+	#[inline]
+	fn last(mut self) -> Option<Self::Item> {
+		self.next_back()
+	}
+}
+
+impl<'a, O, T> DoubleEndedIterator for Runs<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		let value = *self.inner.last()?;
+		let start = self.inner.last_unlike(value).map_or(0, |idx| idx + 1);
+		let len = self.inner.len() - start;
+		self.inner = unsafe { self.inner.get_unchecked(.. start) };
+		Some((value, len))
+	}
+}
+
+impl<O, T> FusedIterator for Runs<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+}
+
 /** An iterator over a slice in (non-overlapping) chunks (`width` bits at a
 time), starting at the beginning of the slice.
 
@@ -2020,6 +2251,368 @@ forward_iterator!(RSplitN);
 forward_iterator!(SplitNMut mut);
 forward_iterator!(RSplitNMut mut);
 
+/** A search pattern held by the pattern iterators.
+
+A pattern whose memory layout differs from the searched slice is packed into a
+`BitVec` of the slice’s own type once, when the iterator is created, rather
+than on every search. This requires the `alloc` feature; without it, the
+pattern is always searched as it is.
+**/
+#[derive(Clone, Debug)]
+pub(crate) enum Needle<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// The pattern, searched as it is. The marker names the searched slice’s
+	/// type, which the `Packed` variant does not when `alloc` is disabled.
+	Borrowed(&'a BitSlice<D, U>, PhantomData<&'a BitSlice<O, T>>),
+	/// The pattern, packed into the searched slice’s layout.
+	#[cfg(feature = "alloc")]
+	Packed(BitVec<O, T>),
+}
+
+impl<'a, O, T, D, U> Needle<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// Prepares a pattern for repeated searches of a `BitSlice<O, T>`.
+	pub(crate) fn new(needle: &'a BitSlice<D, U>) -> Self {
+		#[cfg(feature = "alloc")]
+		{
+			if BitSlice::<O, T>::linear_order().is_some()
+				&& BitSlice::<O, T>::coerce(needle).is_none()
+			{
+				return Needle::Packed(needle.iter().copied().collect());
+			}
+		}
+		Needle::Borrowed(needle, PhantomData)
+	}
+
+	/// The length of the pattern.
+	fn len(&self) -> usize {
+		match self {
+			Needle::Borrowed(needle, _) => needle.len(),
+			#[cfg(feature = "alloc")]
+			Needle::Packed(needle) => needle.len(),
+		}
+	}
+
+	/// Finds the first occurrence of the pattern in `hay`.
+	fn find_in(&self, hay: &BitSlice<O, T>) -> Option<usize> {
+		match self {
+			Needle::Borrowed(needle, _) => hay.find_raw(needle),
+			#[cfg(feature = "alloc")]
+			Needle::Packed(needle) => hay.find_raw(needle),
+		}
+	}
+
+	/// Finds the last occurrence of the pattern in `hay`.
+	fn rfind_in(&self, hay: &BitSlice<O, T>) -> Option<usize> {
+		match self {
+			Needle::Borrowed(needle, _) => hay.rfind_raw(needle),
+			#[cfg(feature = "alloc")]
+			Needle::Packed(needle) => hay.rfind_raw(needle),
+		}
+	}
+}
+
+/** An iterator over subslices separated by occurrences of a bit pattern.
+
+The occurrences do not overlap: the search for the next separator begins after
+the end of the previous one.
+
+This struct is created by the [`split_pattern`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`split_pattern`]: struct.BitSlice.html#method.split_pattern
+**/
+#[derive(Clone, Debug)]
+pub struct SplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The separator pattern.
+	pub(super) sep: Needle<'a, O, T, D, U>,
+	/// Marks whether the final subslice has been produced.
+	pub(super) done: bool,
+}
+
+impl<'a, O, T, D, U> SplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// Marks the iterator as complete, extracting the remaining portion of the
+	/// slice.
+	fn finish(&mut self) -> Option<&'a BitSlice<O, T>> {
+		if self.done {
+			return None;
+		}
+		self.done = true;
+		Some(mem::replace(&mut self.inner, BitSlice::empty()))
+	}
+}
+
+impl<'a, O, T, D, U> Iterator for SplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.sep.find_in(self.inner) {
+			None => self.finish(),
+			Some(idx) => unsafe {
+				let out = self.inner.get_unchecked(.. idx);
+				self.inner = self.inner.get_unchecked(idx + self.sep.len() ..);
+				Some(out)
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.done {
+			return (0, Some(0));
+		}
+		(1, Some(self.inner.len() / self.sep.len() + 1))
+	}
+}
+
+impl<'a, O, T, D, U> FusedIterator for SplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+}
+
+/** An iterator over subslices separated by occurrences of a bit pattern,
+starting from the end of the slice.
+
+The occurrences do not overlap: the search for the next separator ends before
+the start of the previous one.
+
+This struct is created by the [`rsplit_pattern`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`rsplit_pattern`]: struct.BitSlice.html#method.rsplit_pattern
+**/
+#[derive(Clone, Debug)]
+pub struct RSplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The separator pattern.
+	pub(super) sep: Needle<'a, O, T, D, U>,
+	/// Marks whether the final subslice has been produced.
+	pub(super) done: bool,
+}
+
+impl<'a, O, T, D, U> Iterator for RSplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.done {
+			return None;
+		}
+		match self.sep.rfind_in(self.inner) {
+			None => {
+				self.done = true;
+				Some(mem::replace(&mut self.inner, BitSlice::empty()))
+			},
+			Some(idx) => unsafe {
+				let out = self.inner.get_unchecked(idx + self.sep.len() ..);
+				self.inner = self.inner.get_unchecked(.. idx);
+				Some(out)
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.done {
+			return (0, Some(0));
+		}
+		(1, Some(self.inner.len() / self.sep.len() + 1))
+	}
+}
+
+impl<'a, O, T, D, U> FusedIterator for RSplitPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+}
+
+/** An iterator over subslices separated by occurrences of a bit pattern,
+limited to a given number of splits.
+
+This struct is created by the [`splitn_pattern`] method on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`splitn_pattern`]: struct.BitSlice.html#method.splitn_pattern
+**/
+#[derive(Clone, Debug)]
+pub struct SplitNPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// The interior splitter.
+	pub(super) inner: SplitPattern<'a, O, T, D, U>,
+	/// The count of remaining subslices that may be produced.
+	pub(super) count: usize,
+}
+
+impl<'a, O, T, D, U> Iterator for SplitNPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	type Item = &'a BitSlice<O, T>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.count {
+			0 => None,
+			1 => {
+				self.count = 0;
+				self.inner.finish()
+			},
+			_ => {
+				self.count -= 1;
+				self.inner.next()
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (floor, ceil) = self.inner.size_hint();
+		(
+			cmp::min(self.count, floor),
+			ceil.map(|c| cmp::min(self.count, c)),
+		)
+	}
+}
+
+impl<'a, O, T, D, U> FusedIterator for SplitNPattern<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+}
+
+/** An iterator over the indices of occurrences of a bit pattern.
+
+This struct is created by the [`match_indices`] and [`overlapping_matches`]
+methods on [`BitSlice`]s.
+
+[`BitSlice`]: struct.BitSlice.html
+[`match_indices`]: struct.BitSlice.html#method.match_indices
+[`overlapping_matches`]: struct.BitSlice.html#method.overlapping_matches
+**/
+#[derive(Clone, Debug)]
+pub struct MatchIndices<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	/// The `BitSlice` undergoing iteration.
+	pub(super) inner: &'a BitSlice<O, T>,
+	/// The index in the original slice of the first bit of `inner`.
+	pub(super) front: usize,
+	/// The pattern being searched.
+	pub(super) needle: Needle<'a, O, T, D, U>,
+	/// Marks whether a match may begin inside the previous match.
+	pub(super) overlap: bool,
+}
+
+impl<'a, O, T, D, U> Iterator for MatchIndices<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+	type Item = usize;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.needle.find_in(self.inner) {
+			Some(idx) => {
+				let out = self.front + idx;
+				let skip = if self.overlap { 1 } else { self.needle.len() };
+				self.inner = unsafe { self.inner.get_unchecked(idx + skip ..) };
+				self.front = out + skip;
+				Some(out)
+			},
+			None => {
+				self.inner = BitSlice::empty();
+				None
+			},
+		}
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.inner.len()))
+	}
+}
+
+impl<'a, O, T, D, U> FusedIterator for MatchIndices<'a, O, T, D, U>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'a + BitStore,
+{
+}
+
 /** An iterator over overlapping subslices of some width.
 
 This struct is created by the [`windows`] method on [`BitSlice`]s.
@@ -2106,7 +2699,7 @@ where
 	#[inline]
 	fn next_back(&mut self) -> Option<Self::Item> {
 		let len = self.inner.len();
-		if !(1 .. self.width).contains(&len) {
+		if self.width > len {
 			self.inner = BitSlice::empty();
 			return None;
 		}
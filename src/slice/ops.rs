@@ -3,14 +3,22 @@
 use super::api::BitSliceIndex;
 
 use crate::{
-	access::BitAccess,
-	domain::DomainMut,
 	mem::BitMemory,
 	order::BitOrder,
 	slice::BitSlice,
 	store::BitStore,
 };
 
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
+#[cfg(feature = "alloc")]
+use core::ops::{
+	BitAnd,
+	BitOr,
+	BitXor,
+};
+
 use core::{
 	ops::{
 		BitAndAssign,
@@ -166,6 +174,124 @@ where
 	}
 }
 
+/** Performs the Boolean `AND` operation between two slices, producing a new
+vector.
+
+The vector has the length of the left-hand slice. If the right-hand slice is
+shorter, its missing bits are treated as zero, and so the excess bits of the
+vector are cleared.
+**/
+#[cfg(feature = "alloc")]
+impl<'a, 'b, O, T, D, U> BitAnd<&'b BitSlice<D, U>> for &'a BitSlice<O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'b + BitStore,
+{
+	type Output = BitVec<O, T>;
+
+	/// `AND`s two slices, producing a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = &0b0101_1111u8.bits::<Msb0>()[.. 6];
+	/// let rhs = &0b0011_0000u16.bits::<Lsb0>()[4 .. 8];
+	/// assert_eq!(lhs & rhs, bits![0, 1, 0, 0, 0, 0]);
+	/// ```
+	fn bitand(self, rhs: &'b BitSlice<D, U>) -> Self::Output {
+		bitwise(self, rhs, BitAnd::bitand)
+	}
+}
+
+/** Performs the Boolean `OR` operation between two slices, producing a new
+vector.
+
+The vector has the length of the left-hand slice. If the right-hand slice is
+shorter, its missing bits are treated as zero, and so the excess bits of the
+vector are copied from the left-hand slice.
+**/
+#[cfg(feature = "alloc")]
+impl<'a, 'b, O, T, D, U> BitOr<&'b BitSlice<D, U>> for &'a BitSlice<O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'b + BitStore,
+{
+	type Output = BitVec<O, T>;
+
+	/// `OR`s two slices, producing a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = &0b0101_1111u8.bits::<Msb0>()[.. 6];
+	/// let rhs = &0b0011_0000u16.bits::<Lsb0>()[4 .. 8];
+	/// assert_eq!(lhs | rhs, bits![1, 1, 0, 1, 1, 1]);
+	/// ```
+	fn bitor(self, rhs: &'b BitSlice<D, U>) -> Self::Output {
+		bitwise(self, rhs, BitOr::bitor)
+	}
+}
+
+/** Performs the Boolean `XOR` operation between two slices, producing a new
+vector.
+
+The vector has the length of the left-hand slice. If the right-hand slice is
+shorter, its missing bits are treated as zero, and so the excess bits of the
+vector are copied from the left-hand slice.
+**/
+#[cfg(feature = "alloc")]
+impl<'a, 'b, O, T, D, U> BitXor<&'b BitSlice<D, U>> for &'a BitSlice<O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	D: BitOrder,
+	U: 'b + BitStore,
+{
+	type Output = BitVec<O, T>;
+
+	/// `XOR`s two slices, producing a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let lhs = &0b0101_1111u8.bits::<Msb0>()[.. 6];
+	/// let rhs = &0b0011_0000u16.bits::<Lsb0>()[4 .. 8];
+	/// assert_eq!(lhs ^ rhs, bits![1, 0, 0, 1, 1, 1]);
+	/// ```
+	fn bitxor(self, rhs: &'b BitSlice<D, U>) -> Self::Output {
+		bitwise(self, rhs, BitXor::bitxor)
+	}
+}
+
+/// Copies `lhs` into a new vector, and combines it with `rhs`.
+#[cfg(feature = "alloc")]
+fn bitwise<O, T, D, U, F>(
+	lhs: &BitSlice<O, T>,
+	rhs: &BitSlice<D, U>,
+	func: F,
+) -> BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+	D: BitOrder,
+	U: BitStore,
+	F: Fn(T::Mem, T::Mem) -> T::Mem,
+{
+	let mut out = BitVec::from_bitslice(lhs);
+	out.combine_with(rhs, func);
+	out
+}
+
 impl<O, T> Index<usize> for BitSlice<O, T>
 where
 	O: BitOrder,
@@ -310,6 +436,32 @@ where
 	}
 }
 
+/// Flips all bits in a copy of the slice.
+#[cfg(feature = "alloc")]
+impl<'a, O, T> Not for &'a BitSlice<O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	type Output = BitVec<O, T>;
+
+	/// Inverts all bits of the slice into a new vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bits = &0b0110_0000u8.bits::<Msb0>()[1 .. 4];
+	/// assert_eq!(!bits, bits![0, 0, 1]);
+	/// ```
+	fn not(self) -> Self::Output {
+		let mut out = BitVec::from_bitslice(self);
+		let _ = !out.as_mut_bitslice();
+		out
+	}
+}
+
 /// Flips all bits in the slice, in place.
 impl<'a, O, T> Not for &'a mut BitSlice<O, T>
 where
@@ -340,22 +492,7 @@ where
 	/// assert_eq!(src, [0x3F, 0xFC]);
 	/// ```
 	fn not(self) -> Self::Output {
-		match self.domain_mut() {
-			DomainMut::Enclave { head, elem, tail } => {
-				elem.invert_bits(O::mask(head, tail));
-			},
-			DomainMut::Region { head, body, tail } => {
-				if let Some((h, head)) = head {
-					head.invert_bits(O::mask(h, None));
-				}
-				for elem in body {
-					elem.set_elem(!elem.get_elem());
-				}
-				if let Some((tail, t)) = tail {
-					tail.invert_bits(O::mask(None, t));
-				}
-			},
-		}
+		self.invert();
 		self
 	}
 }
@@ -425,8 +562,9 @@ where
 			return;
 		}
 		//  If the slice fully owns its memory, then a fast path is available
-		//  with element-wise `memmove`.
-		if self.domain().is_spanning() {
+		//  with element-wise `memmove`. It must move at least one element, or
+		//  the shift of the remainder below would never terminate.
+		if self.domain().is_spanning() && shamt >= T::Mem::BITS as usize {
 			//  Compute the shift distance measured in elements.
 			let offset = shamt >> T::Mem::INDX;
 			//  Compute the number of elements that will remain.
@@ -458,7 +596,7 @@ where
 			}
 			//  Any remaining shift amount only needs to shift the `after` block
 			//  above.
-			self[.. rem << T::Mem::INDX] <<= shamt & T::Mem::INDX as usize;
+			self[.. rem << T::Mem::INDX] <<= shamt & T::Mem::MASK as usize;
 			return;
 		}
 		//  Otherwise, crawl.
@@ -534,8 +672,9 @@ where
 			return;
 		}
 		//  If the slice fully owns its memory, then a fast path is available
-		//  with element-wise `memmove`.
-		if self.domain().is_spanning() {
+		//  with element-wise `memmove`. It must move at least one element, or
+		//  the shift of the remainder below would never terminate.
+		if self.domain().is_spanning() && shamt >= T::Mem::BITS as usize {
 			//  Compute the shift amount measured in elements.
 			let offset = shamt >> T::Mem::INDX;
 			// Compute the number of elements that will remain.
@@ -560,7 +699,7 @@ where
 			}
 			//  Any remaining shift amount only needs to shift the `after` block
 			//  above.
-			self[offset << T::Mem::INDX ..] >>= shamt & T::Mem::INDX as usize;
+			self[offset << T::Mem::INDX ..] >>= shamt & T::Mem::MASK as usize;
 			return;
 		}
 		//  Otherwise, crawl.
@@ -25,6 +25,7 @@ use crate::{
 
 use core::{
 	marker::PhantomData,
+	mem,
 	ops::{
 		Deref,
 		DerefMut,
@@ -58,6 +59,65 @@ where
 	pub(super) head: BitIdx<T::Mem>,
 	/// A local cache for `Deref` usage.
 	pub(super) bit: bool,
+	/// The value of the proxied bit when the proxy was created.
+	pub(super) orig: bool,
+}
+
+impl<O, T> BitMut<'_, O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Replaces the proxied bit, returning its previous value.
+	///
+	/// The new value is not written into memory until the proxy is dropped.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The new value of the proxied bit.
+	///
+	/// # Returns
+	///
+	/// The value of the proxied bit before this call.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = data.bits_mut::<Msb0>();
+	/// let mut bit = bits.get_mut(1).unwrap();
+	/// assert!(!bit.replace(true));
+	/// assert!(bit.replace(true));
+	/// drop(bit);
+	/// assert_eq!(data, 0x40);
+	/// ```
+	pub fn replace(&mut self, value: bool) -> bool {
+		mem::replace(&mut self.bit, value)
+	}
+
+	/// Writes a value into the proxied bit, and destroys the proxy.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `value`: The new value of the proxied bit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut data = 0u8;
+	/// let bits = data.bits_mut::<Lsb0>();
+	/// bits.get_mut(2).unwrap().commit(true);
+	/// assert_eq!(data, 4);
+	/// ```
+	pub fn commit(mut self, value: bool) {
+		self.bit = value;
+	}
 }
 
 impl<O, T> Deref for BitMut<'_, O, T>
@@ -87,7 +147,10 @@ where
 	O: BitOrder,
 	T: BitStore,
 {
+	/// Writes the cached bit into memory, if it was changed.
 	fn drop(&mut self) {
-		unsafe { (*self.data.as_ptr()).set::<O>(self.head, self.bit) }
+		if self.bit != self.orig {
+			unsafe { (*self.data.as_ptr()).set::<O>(self.head, self.bit) }
+		}
 	}
 }
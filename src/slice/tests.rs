@@ -3,15 +3,25 @@
 
 use crate::{
 	order::{
+		BitOrder,
 		Local,
 		Msb0,
 	},
 	slice::{
 		AsBits,
+		BitMut,
 		BitSlice,
 	},
+	store::BitStore,
+	testing::{
+		check_ranges,
+		xorshift,
+	},
 };
 
+#[cfg(feature = "alloc")]
+use crate::vec::BitVec;
+
 #[test]
 fn all() {
 	assert!(BitSlice::<Local, usize>::empty().all());
@@ -148,3 +158,2247 @@ fn set_all() {
 	bits.set_all(true);
 	assert_eq!(data, [!0; 5]);
 }
+
+#[test]
+#[cfg(feature = "alloc")]
+fn repeat() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Msb0>();
+
+	for start in 0 .. 8 {
+		for end in start .. 20 {
+			let src = &bits[start .. end];
+			for n in 0 .. 6 {
+				let mut expected = BitVec::<Msb0, u8>::new();
+				for _ in 0 .. n {
+					for bit in src.iter() {
+						expected.push(*bit);
+					}
+				}
+				assert_eq!(src.repeat(n), expected);
+			}
+		}
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+#[should_panic(expected = "Capacity overflow")]
+fn repeat_overflow() {
+	let bits = 0u8.bits::<Msb0>();
+	bits.repeat(!0 / 4);
+}
+
+#[test]
+fn swap_ranges() {
+	let data = [
+		0x96A5u16, 0x3C69, 0xF00F, 0x5AC3, 0x0FF0, 0xC35A, 0x1234, 0xFEDC,
+	];
+	for &(a, b) in &[
+		(0, 64),
+		(3, 19),
+		(3, 51),
+		(3, 40),
+		(7, 90),
+		(16, 48),
+		(90, 5),
+	] {
+		let gap = if a < b { b - a } else { a - b };
+		for count in 0 .. core::cmp::min(gap, 37) {
+			let mut copy = data;
+			let bits = copy.bits_mut::<Msb0>();
+			let before = bits.iter().copied().collect::<Vec<bool>>();
+			bits.swap_ranges(a .. a + count, b .. b + count);
+
+			let mut expected = before.clone();
+			for n in 0 .. count {
+				expected.swap(a + n, b + n);
+			}
+			assert!(bits.iter().copied().eq(expected.into_iter()));
+		}
+	}
+
+	//  Empty ranges never overlap.
+	let mut data = 0xA5u8;
+	data.bits_mut::<Local>().swap_ranges(3 .. 3, 2 .. 2);
+	assert_eq!(data, 0xA5);
+}
+
+#[test]
+#[should_panic(expected = "overlap")]
+fn swap_ranges_overlap() {
+	let mut data = [0u8; 2];
+	data.bits_mut::<Local>().swap_ranges(2 .. 10, 8 .. 16);
+}
+
+#[test]
+#[should_panic(expected = "equal lengths")]
+fn swap_ranges_unequal() {
+	let mut data = [0u8; 2];
+	data.bits_mut::<Local>().swap_ranges(0 .. 4, 8 .. 11);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn swap_ranges_out_of_bounds() {
+	let mut data = [0u8; 2];
+	data.bits_mut::<Local>().swap_ranges(0 .. 4, 14 .. 18);
+}
+
+#[test]
+fn as_raw_bytes() {
+	macro_rules! check {
+		($($t:ty),+ $(,)?) => { $(
+			let mut data: [$t; 3] = [!0 / 3, !0 / 5, !0 / 7];
+			let width = core::mem::size_of::<$t>();
+			let bytes = data[1].to_ne_bytes();
+
+			//  Only the fully-owned middle element is visible.
+			let bits = &data.bits::<Msb0>()[3 .. 3 * width * 8 - 3];
+			assert_eq!(bits.as_raw_bytes(), &bytes[..]);
+			assert!(bits[.. width * 8].as_raw_bytes().is_empty());
+			assert_eq!(
+				data.bits::<Local>().as_raw_bytes().len(),
+				3 * width,
+			);
+
+			let bits = &mut data.bits_mut::<Msb0>()[3 ..];
+			for byte in bits.as_raw_bytes_mut() {
+				*byte = 0;
+			}
+			assert_eq!(data, [!0 / 3, 0, 0]);
+		)+ };
+	}
+
+	check!(u8, u16, u32);
+	#[cfg(target_pointer_width = "64")]
+	check!(u64);
+}
+
+#[test]
+fn to_uint() {
+	use crate::order::Lsb0;
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let data: [$t; 11] = [
+				!0 / 3, !0 / 5, !0 / 7, !0 / 11, !0 / 13, !0 / 17,
+				!0 / 19, !0 / 23, !0 / 29, !0 / 31, !0 / 37,
+			];
+			let bits = data.bits::<$o>();
+			for head in 0 .. 17 {
+				for len in 0 ..= 64 {
+					let slice = &bits[head .. head + len];
+					let be = slice.iter().fold(0u64, |out, &b| out << 1 | b as u64);
+					let le = slice
+						.iter()
+						.rev()
+						.fold(0u64, |out, &b| out << 1 | b as u64);
+					assert_eq!(slice.to_uint::<u64>(), Some(be));
+					assert_eq!(slice.to_uint_le::<u64>(), Some(le));
+					if len <= 8 {
+						assert_eq!(slice.to_uint::<u8>(), Some(be as u8));
+						assert_eq!(slice.to_uint_le::<u8>(), Some(le as u8));
+					}
+					else {
+						assert!(slice.to_uint::<u8>().is_none());
+						assert!(slice.to_uint_le::<u8>().is_none());
+					}
+				}
+				assert!(bits[head .. head + 65].to_uint::<u64>().is_none());
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+}
+
+#[test]
+fn cmp_numeric() {
+	use crate::order::Lsb0;
+	use core::cmp::Ordering;
+
+	let mut next = xorshift();
+	fn value<O, T>(bits: &BitSlice<O, T>) -> u128
+	where
+		O: crate::order::BitOrder,
+		T: crate::store::BitStore,
+	{
+		bits.iter()
+			.rev()
+			.fold(0, |out, &bit| out << 1 | bit as u128)
+	}
+
+	for _ in 0 .. 256 {
+		let a_data = [next(), next()];
+		let a = &a_data.bits::<Lsb0>()[.. (next() % 129) as usize];
+
+		//  The other operand has a different order, storage, and head. It
+		//  sometimes copies `a`, with extra zeros, to exercise ties.
+		let mut b_data = [0u16; 10];
+		let head = (next() % 16) as usize;
+		let len = (next() % 129) as usize;
+		let b = &mut b_data.bits_mut::<Msb0>()[head .. head + len];
+		let b_val = if next() % 4 == 0 {
+			value(a)
+		}
+		else {
+			u128::from(next()) | u128::from(next()) << 64
+		};
+		for idx in 0 .. len {
+			b.set(idx, b_val >> idx & 1 == 1);
+		}
+		let b = &*b;
+
+		let (a_val, b_val) = (value(a), value(b));
+		assert_eq!(a.cmp_numeric(b), a_val.cmp(&b_val));
+		assert_eq!(b.cmp_numeric(a), b_val.cmp(&a_val));
+		assert_eq!(a.cmp_numeric(a), Ordering::Equal);
+	}
+}
+
+#[test]
+fn sub_assign_reverse() {
+	use crate::order::Lsb0;
+
+	//  `0 - 1` borrows and wraps to all ones, at every width.
+	for len in 0 .. 20 {
+		let mut data = [0u8; 3];
+		let bits = &mut data.bits_mut::<Msb0>()[2 .. 2 + len];
+		assert_eq!(bits.sub_assign_reverse(Some(true)), len > 0);
+		assert!(bits.all());
+	}
+
+	let mut next = xorshift();
+
+	//  The low `len` bits of a `u128`.
+	let low = |len: usize| (!0u128).checked_shr(128 - len as u32).unwrap_or(0);
+
+	for _ in 0 .. 256 {
+		let len = (next() % 129) as usize;
+		let mask = low(len);
+		let a = (u128::from(next()) | u128::from(next()) << 64) & mask;
+		let b = u128::from(next()) | u128::from(next()) << 64;
+		//  The subtrahend may be longer or shorter than the minuend.
+		let b_len = (next() % 129) as usize;
+		let b = b & low(b_len);
+
+		let mut data = [0u16; 10];
+		let head = (next() % 16) as usize;
+		let bits = &mut data.bits_mut::<Lsb0>()[head .. head + len];
+		for idx in 0 .. len {
+			bits.set(idx, a >> idx & 1 == 1);
+		}
+		let borrow =
+			bits.sub_assign_reverse((0 .. b_len).map(|n| b >> n & 1 == 1));
+		let diff = bits
+			.iter()
+			.rev()
+			.fold(0u128, |out, &bit| out << 1 | bit as u128);
+		assert_eq!(diff, a.wrapping_sub(b & mask) & mask);
+		assert_eq!(borrow, a < b & mask);
+	}
+}
+
+#[test]
+fn add_assign_reverse_slice() {
+	use crate::order::Lsb0;
+
+	/* Adds `b` into `a`, both `len` bits long and starting `a_head` and
+	`b_head` bits into their buffers, and checks the sum against `u128`
+	arithmetic and against the bit-wise addition.
+	*/
+	fn check<O, D, U>(
+		next: &mut dyn FnMut() -> u64,
+		a_head: usize,
+		b: &mut BitSlice<D, U>,
+	) where
+		O: BitOrder,
+		D: BitOrder,
+		U: BitStore,
+	{
+		let low =
+			|len: usize| (!0u128).checked_shr(128 - len as u32).unwrap_or(0);
+		let len = (next() % 129) as usize;
+		let (a, b_val) = (
+			(u128::from(next()) | u128::from(next()) << 64) & low(len),
+			(u128::from(next()) | u128::from(next()) << 64) & low(b.len()),
+		);
+		let mut noise = [0u16; 10];
+		for elem in noise.iter_mut() {
+			*elem = next() as u16;
+		}
+
+		let mut data = noise;
+		for idx in 0 .. len {
+			data.bits_mut::<O>().set(a_head + idx, a >> idx & 1 == 1);
+		}
+		for idx in 0 .. b.len() {
+			b.set(idx, b_val >> idx & 1 == 1);
+		}
+		let mut copy = data;
+		let bits = &mut data.bits_mut::<O>()[a_head .. a_head + len];
+		let carry = bits.add_assign_reverse_slice(b);
+		let sum = bits
+			.iter()
+			.rev()
+			.fold(0u128, |out, &bit| out << 1 | bit as u128);
+		assert_eq!(sum, a.wrapping_add(b_val & low(len)) & low(len));
+		let (wide, over) = a.overflowing_add(b_val & low(len));
+		assert_eq!(carry, over || wide & !low(len) != 0);
+
+		//  The bits around the sum are untouched.
+		let all = data.bits::<O>();
+		let noise = noise.bits::<O>();
+		assert_eq!(all[.. a_head], noise[.. a_head]);
+		assert_eq!(all[a_head + len ..], noise[a_head + len ..]);
+
+		let bits = &mut copy.bits_mut::<O>()[a_head .. a_head + len];
+		let bit_carry = bits.add_assign_reverse(b.iter().copied());
+		let bit_sum = bits
+			.iter()
+			.rev()
+			.fold(0u128, |out, &bit| out << 1 | bit as u128);
+		assert_eq!(bit_sum, sum);
+		assert_eq!(bit_carry, carry);
+	}
+
+	let mut next = xorshift();
+	for _ in 0 .. 256 {
+		let head = (next() % 16) as usize;
+		let b_len = (next() % 129) as usize;
+		//  Same layout: the whole elements are added with `overflowing_add`.
+		let mut b_data = [0u16; 10];
+		check::<Lsb0, _, _>(
+			&mut next,
+			head,
+			&mut b_data.bits_mut::<Lsb0>()[head ..][.. b_len],
+		);
+		let mut b_data = [0u16; 10];
+		check::<Msb0, _, _>(
+			&mut next,
+			head,
+			&mut b_data.bits_mut::<Msb0>()[head ..][.. b_len],
+		);
+		//  The addend starts at a different position in its elements.
+		let b_head = (next() % 16) as usize;
+		let mut b_data = [0u16; 10];
+		check::<Lsb0, _, _>(
+			&mut next,
+			head,
+			&mut b_data.bits_mut::<Lsb0>()[b_head ..][.. b_len],
+		);
+		//  The addend has a different layout, and is added bit by bit.
+		let mut b_data = [0u8; 20];
+		check::<Msb0, _, _>(
+			&mut next,
+			head,
+			&mut b_data.bits_mut::<Lsb0>()[b_head ..][.. b_len],
+		);
+	}
+
+	//  A carry out of the addend ripples through the rest of the slice.
+	let mut data = [0xF0u8, !0, 0x0F];
+	let one = [1u8];
+	let bits = &mut data.bits_mut::<Lsb0>()[4 .. 20];
+	assert!(bits.add_assign_reverse_slice(&one.bits::<Lsb0>()[.. 1]));
+	assert_eq!(data, [0x00, 0x00, 0x00]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn mul_reverse() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+	let low = |len: usize| (!0u64).checked_shr(64 - len as u32).unwrap_or(0);
+
+	for _ in 0 .. 256 {
+		let (a_len, b_len) = ((next() % 65) as usize, (next() % 65) as usize);
+		let (a, b) = (next() & low(a_len), next() & low(b_len));
+		let a_bits = &a.bits::<Lsb0>()[.. a_len];
+		let mut b_data = [0u8; 10];
+		let head = (next() % 8) as usize;
+		let b_bits = &mut b_data.bits_mut::<Msb0>()[head .. head + b_len];
+		for idx in 0 .. b_len {
+			b_bits.set(idx, b >> idx & 1 == 1);
+		}
+
+		let prod = a_bits.mul_reverse(b_bits);
+		assert_eq!(prod.len(), a_len + b_len);
+		let value = prod
+			.iter()
+			.rev()
+			.fold(0u128, |out, &bit| out << 1 | bit as u128);
+		assert_eq!(value, u128::from(a) * u128::from(b));
+	}
+
+	//  (2^500 - 1)^2 = 2^1000 - 2^501 + 1
+	let ones = BitVec::<Lsb0, u32>::repeat(true, 500);
+	let square = ones.mul_reverse(&ones);
+	let mut expected = BitVec::<Lsb0, u32>::repeat(false, 1000);
+	expected.set(0, true);
+	expected[501 ..].set_all(true);
+	assert_eq!(square, expected);
+
+	//  Odd operands in both directions must agree.
+	let a = BitVec::<Msb0, u8>::from_uint(0xDEAD_BEEFu32, 997);
+	let b = BitVec::<Lsb0, u16>::from_uint(0x1234u16, 1003);
+	assert_eq!(a.mul_reverse(&b), b.mul_reverse(&a));
+}
+
+#[test]
+fn negate_in_place() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($($i:ty, $u:ty);+ $(;)?) => { $(
+			let width = <$u>::max_value().count_ones() as usize;
+			let min = <$i>::min_value() as $u;
+			let specials = [0, 1, !0, min, min - 1];
+			for n in 0 .. 64 + specials.len() {
+				let value = specials.get(n).copied().unwrap_or(next() as $u);
+				let head = (next() % 16) as usize;
+
+				let mut lsb0 = [0u16; 6];
+				let bits = &mut lsb0.bits_mut::<Lsb0>()[head .. head + width];
+				for idx in 0 .. width {
+					bits.set(idx, value >> idx & 1 == 1);
+				}
+				bits.negate_in_place();
+				assert_eq!(
+					bits.to_uint_le::<u64>(),
+					Some((value as $i).wrapping_neg() as $u as u64),
+				);
+
+				let mut msb0 = [0u8; 10];
+				let bits = &mut msb0.bits_mut::<Msb0>()[head .. head + width];
+				for idx in 0 .. width {
+					bits.set(idx, value >> idx & 1 == 1);
+				}
+				assert_eq!(bits.abs_in_place(), (value as $i) < 0);
+				assert_eq!(
+					bits.to_uint_le::<u64>(),
+					Some((value as $i).wrapping_abs() as $u as u64),
+				);
+			}
+		)+ };
+	}
+
+	check!(i8, u8; i16, u16; i32, u32);
+	#[cfg(target_pointer_width = "64")]
+	check!(i64, u64);
+}
+
+#[test]
+fn increment_decrement() {
+	use crate::order::Lsb0;
+
+	//  A 9-bit counter, straddling three elements, through every state.
+	let mut data = [0u8; 3];
+	let counter = &mut data.bits_mut::<Msb0>()[5 .. 14];
+	for n in 1 ..= 512u16 {
+		assert_eq!(counter.increment(), n == 512);
+		assert_eq!(counter.to_uint_le::<u16>(), Some(n % 512));
+	}
+	assert_eq!(data, [0; 3]);
+	let counter = &mut data.bits_mut::<Msb0>()[5 .. 14];
+	for n in (0 .. 512u16).rev() {
+		assert_eq!(counter.decrement(), n == 511);
+		assert_eq!(counter.to_uint_le::<u16>(), Some(n));
+	}
+
+	//  The bits around the counter are untouched.
+	let mut data = [!0u16; 3];
+	let counter = &mut data.bits_mut::<Lsb0>()[3 .. 40];
+	counter.set_all(false);
+	assert!(counter.decrement());
+	assert!(counter.all());
+	assert!(counter.increment());
+	assert!(counter.not_any());
+	assert_eq!(data, [0b111, 0, 0xFF00]);
+
+	//  The empty counter always wraps.
+	assert!(BitSlice::<Lsb0, u8>::empty_mut().increment());
+	assert!(BitSlice::<Lsb0, u8>::empty_mut().decrement());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn shift_in() {
+	use crate::order::Lsb0;
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let width = <$t>::max_value().count_ones() as usize;
+			let data: [$t; 5] = [!0 / 3, !0 / 5, !0 / 7, !0 / 11, !0 / 13];
+			//  A partial slice, and one that spans whole elements.
+			for &(start, end) in &[(3, 3 + 4 * width), (0, 5 * width)] {
+				let len = end - start;
+				let src = &data.bits::<$o>()[start .. end];
+				let shifts = [0, 1, width, width + 1, 2 * width + 3];
+				let ends = [len - 1, len, len + 1];
+				for &shamt in shifts.iter().chain(&ends) {
+					let moved = core::cmp::min(shamt, len);
+					let kept = len - moved;
+					for &fill in &[false, true] {
+						let mut copy = data;
+						let bits = &mut copy.bits_mut::<$o>()[start .. end];
+						let out = bits.shift_left_in(shamt, fill);
+						assert_eq!(out, &src[.. moved]);
+						assert_eq!(bits[.. kept], src[moved ..]);
+						assert!(bits[kept ..].iter().all(|&bit| bit == fill));
+
+						let mut copy = data;
+						let bits = &mut copy.bits_mut::<$o>()[start .. end];
+						let out = bits.shift_right_in(shamt, fill);
+						assert_eq!(out, &src[kept ..]);
+						assert_eq!(bits[moved ..], src[.. kept]);
+						assert!(bits[.. moved].iter().all(|&bit| bit == fill));
+
+						//  The bits around the slice are untouched.
+						let (now, was) = (copy.bits::<$o>(), data.bits::<$o>());
+						assert_eq!(now[.. start], was[.. start]);
+						assert_eq!(now[end ..], was[end ..]);
+					}
+				}
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn bitwise_refs() {
+	use crate::order::Lsb0;
+
+	let lhs_data = [0x5Au8, 0xC3, 0x96, 0x0F, 0xE1, 0x3C];
+	let rhs_data = [0x1234u16, 0xABCD, 0x0FF0];
+	let lhs_all = lhs_data.bits::<Msb0>();
+	let rhs_all = rhs_data.bits::<Lsb0>();
+	for lhs_head in 0 .. 9 {
+		for lhs_len in [0, 1, 7, 8, 9, 23, 30].iter().copied() {
+			let lhs = &lhs_all[lhs_head ..][.. lhs_len];
+			for rhs_head in [0, 3, 15].iter().copied() {
+				for rhs_len in [0, 5, 9, 30].iter().copied() {
+					let rhs = &rhs_all[rhs_head ..][.. rhs_len];
+					let (and, or, xor, not) =
+						(lhs & rhs, lhs | rhs, lhs ^ rhs, !lhs);
+					assert_eq!(and.len(), lhs_len);
+					assert_eq!(or.len(), lhs_len);
+					assert_eq!(xor.len(), lhs_len);
+					assert_eq!(not.len(), lhs_len);
+					for (idx, &l) in lhs.iter().enumerate() {
+						let r = rhs.get(idx).copied().unwrap_or(false);
+						assert_eq!(and[idx], l & r);
+						assert_eq!(or[idx], l | r);
+						assert_eq!(xor[idx], l ^ r);
+						assert_eq!(not[idx], !l);
+					}
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn set_predicates() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	for _ in 0 .. 64 {
+		let head = 1 + (next() % 7) as usize;
+		let len = 2 + (next() % 40) as usize;
+		//  Every bit around `a` is set, so that unmasked reads see them.
+		let mut a_data = [!0u8; 7];
+		for idx in head .. head + len - 1 {
+			a_data.bits_mut::<Msb0>().set(idx, next() & 1 == 1);
+		}
+		a_data.bits_mut::<Msb0>().set(head + len - 1, false);
+		let a = &a_data.bits::<Msb0>()[head .. head + len];
+
+		//  `b` matches `a`, but has one more bit and is otherwise dense.
+		let mut b_data = [!0u16; 4];
+		let b = &mut b_data.bits_mut::<Lsb0>()[5 ..= 5 + len];
+		for idx in 0 .. len {
+			b.set(idx, a[idx]);
+		}
+		assert!(a.is_subset(b));
+		assert!(b.is_superset(a));
+		assert!(!b.is_subset(a));
+
+		//  The only bit of `a` outside `b` is in its partial tail element.
+		let mut c_data = a_data;
+		let c = &mut c_data.bits_mut::<Msb0>()[head .. head + len];
+		c.set(len - 1, true);
+		assert!(!c.is_subset(&b[.. len - 1]));
+		assert!(!c.is_subset(&*b));
+		b.set(len - 1, true);
+		assert!(c.is_subset(&*b));
+
+		//  Complements are disjoint, until they share the last bit.
+		let mut d_data = [!0u32; 2];
+		let d = &mut d_data.bits_mut::<Lsb0>()[3 .. 3 + len];
+		for idx in 0 .. len {
+			d.set(idx, !a[idx]);
+		}
+		d.set(len - 1, false);
+		assert!(a.is_disjoint(d));
+		assert!(d.is_disjoint(a));
+		assert!(c.is_disjoint(&d[.. len - 1]));
+		d.set(len - 1, true);
+		assert!(!c.is_disjoint(d));
+		assert!(!d.is_disjoint(c));
+	}
+}
+
+#[test]
+fn set_predicates_same_layout() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($($o:ty),+ $(,)?) => { $(
+			for _ in 0 .. 256 {
+				let a_data = [next() as u16, next() as u16, next() as u16];
+				let mut b_data = [next() as u16, next() as u16, next() as u16];
+				let (ha, hb) = ((next() % 16) as usize, (next() % 16) as usize);
+				let la = (next() % 33) as usize;
+				let lb = (next() % 33) as usize;
+				let a = &a_data.bits::<$o>()[ha .. ha + la];
+				let b = &mut b_data.bits_mut::<$o>()[hb .. hb + lb];
+				//  Bias `b` towards the cases where the predicates hold.
+				match next() % 3 {
+					0 => for idx in 0 .. la.min(lb) {
+						let bit = b[idx] | a[idx];
+						b.set(idx, bit);
+					},
+					1 => for idx in 0 .. la.min(lb) {
+						let bit = b[idx] & !a[idx];
+						b.set(idx, bit);
+					},
+					_ => {},
+				}
+
+				let subset = a
+					.iter()
+					.enumerate()
+					.all(|(idx, &bit)| !bit || idx < lb && b[idx]);
+				let disjoint = a.iter().zip(b.iter()).all(|(&l, &r)| !(l & r));
+				assert_eq!(a.is_subset(&*b), subset);
+				assert_eq!(a.is_disjoint(&*b), disjoint);
+			}
+		)+ };
+	}
+
+	check!(Msb0, Lsb0);
+}
+
+#[test]
+fn count_in() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	let data = [next(), next(), next()];
+	for _ in 0 .. 256 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 129) as usize;
+		let bits = &data.bits::<Lsb0>()[head .. head + len];
+		let from = (next() as usize) % (len + 1);
+		let upto = from + (next() as usize) % (len - from + 1);
+
+		let ones = bits[from .. upto].iter().filter(|&&bit| bit).count();
+		let zeros = upto - from - ones;
+		assert_eq!(bits.count_ones_in(from .. upto), ones);
+		assert_eq!(bits.count_zeros_in(from .. upto), zeros);
+		assert_eq!(bits.count_ones_in(.. upto), bits[.. upto].count_ones());
+		assert_eq!(bits.count_ones_in(from ..), bits[from ..].count_ones());
+		assert_eq!(bits.count_ones_in(..), bits.count_ones());
+		assert_eq!(bits.count_ones_in(from .. from), 0);
+		assert_eq!(bits.count_zeros_in(len ..), 0);
+	}
+
+	//  Every range over narrow elements, under the other ordering.
+	let data = [0x96u8, 0xA5, 0x3C, 0x69];
+	let bits = &data.bits::<Msb0>()[3 ..];
+	for from in 0 ..= bits.len() {
+		for upto in from ..= bits.len() {
+			let ones = bits[from .. upto].iter().filter(|&&bit| bit).count();
+			assert_eq!(bits.count_ones_in(from .. upto), ones);
+			assert_eq!(bits.count_zeros_in(from .. upto), upto - from - ones);
+		}
+	}
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn count_in_inclusive_max() {
+	let bits = [0xFFu8; 2].bits::<Msb0>();
+	bits.count_ones_in(4 ..= usize::max_value());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn select() {
+	use crate::order::Lsb0;
+
+	//  Ones only in the last element.
+	let mut bv = BitVec::<Msb0, u16>::repeat(false, 1000);
+	bv[992 ..].set_all(true);
+	for n in 0 .. 8 {
+		assert_eq!(bv.select_one(n), Some(992 + n));
+	}
+	assert_eq!(bv.select_one(8), None);
+	assert_eq!(bv.select_zero(991), Some(991));
+	assert_eq!(bv.select_zero(992), None);
+
+	//  All ones.
+	let bv = BitVec::<Lsb0, u32>::repeat(true, 77);
+	for n in 0 .. 77 {
+		assert_eq!(bv.select_one(n), Some(n));
+	}
+	assert_eq!(bv.select_one(77), None);
+	assert_eq!(bv.select_zero(0), None);
+
+	let mut next = xorshift();
+
+	let data = [next(), next() & next(), next() | next(), next()];
+	for _ in 0 .. 128 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 190) as usize;
+		let bits = &data.bits::<Msb0>()[head .. head + len];
+		for n in 0 ..= len {
+			let one = bits.iter().enumerate().filter(|(_, &b)| b).nth(n);
+			let zero = bits.iter().enumerate().filter(|(_, &b)| !b).nth(n);
+			assert_eq!(bits.select_one(n), one.map(|(idx, _)| idx));
+			assert_eq!(bits.select_zero(n), zero.map(|(idx, _)| idx));
+		}
+	}
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn iter_ones_zeros() {
+	use crate::{
+		order::{
+			BitOrder,
+			Lsb0,
+		},
+		store::BitStore,
+	};
+	use alloc::vec::Vec;
+
+	fn check<O: BitOrder, T: BitStore>(bits: &BitSlice<O, T>) {
+		let ones = bits
+			.iter()
+			.enumerate()
+			.filter(|(_, &b)| b)
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>();
+		let zeros = bits
+			.iter()
+			.enumerate()
+			.filter(|(_, &b)| !b)
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>();
+		assert!(bits.iter_ones().eq(ones.iter().copied()));
+		assert!(bits.iter_zeros().eq(zeros.iter().copied()));
+		assert!(bits.iter_ones().rev().eq(ones.iter().rev().copied()));
+		assert!(bits.iter_zeros().rev().eq(zeros.iter().rev().copied()));
+
+		//  Alternate between the ends.
+		let mut iter = bits.iter_ones();
+		let (mut front, mut back) = (0, ones.len());
+		while front < back {
+			assert_eq!(iter.next(), Some(ones[front]));
+			front += 1;
+			if front < back {
+				back -= 1;
+				assert_eq!(iter.next_back(), Some(ones[back]));
+			}
+		}
+		assert!(iter.next().is_none());
+		assert!(iter.next_back().is_none());
+		assert!(iter.next().is_none());
+	}
+
+	let mut next = xorshift();
+
+	//  Sparse, random, and dense data.
+	let mut sparse = [0u16; 64];
+	sparse[3] = 0x0100;
+	sparse[40] = 0x8001;
+	let random = [next(), next() & next(), next() | next(), next()];
+	let dense = [!0u64, !0x0004_0000, !0, !(1 << 63)];
+
+	for _ in 0 .. 64 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 190) as usize;
+		check(&random.bits::<Msb0>()[head .. head + len]);
+		check(&random.bits::<Lsb0>()[head .. head + len]);
+		check(&dense.bits::<Msb0>()[head .. head + len]);
+		check(&dense.bits::<Lsb0>()[head .. head + len]);
+		let head = (next() % 300) as usize;
+		let len = (next() % 700) as usize;
+		check(&sparse.bits::<Msb0>()[head .. head + len]);
+		check(&sparse.bits::<Lsb0>()[head .. head + len]);
+	}
+	check(sparse.bits::<Msb0>());
+	check(BitSlice::<Lsb0, u8>::empty());
+
+	let msb0 = sparse.bits::<Msb0>().iter_ones();
+	let lsb0 = sparse.bits::<Lsb0>().iter_ones();
+	assert!(msb0.eq([55, 640, 655].iter().copied()));
+	assert!(lsb0.eq([56, 640, 655].iter().copied()));
+}
+
+#[test]
+fn first_last() {
+	use crate::order::Lsb0;
+
+	//  Answers at element boundaries.
+	let data = [0u16, 0x8000, 0x0001, 0];
+	let bits = data.bits::<Msb0>();
+	assert_eq!(bits.first_one(), Some(16));
+	assert_eq!(bits.last_one(), Some(47));
+	assert_eq!(bits[17 ..].first_one(), Some(30));
+	assert_eq!(bits[.. 47].last_one(), Some(16));
+	let bits = data.bits::<Lsb0>();
+	assert_eq!(bits.first_one(), Some(31));
+	assert_eq!(bits.last_one(), Some(32));
+
+	let data = [!0u16, !0x8000, !0x0001, !0];
+	let bits = data.bits::<Msb0>();
+	assert_eq!(bits.first_zero(), Some(16));
+	assert_eq!(bits.last_zero(), Some(47));
+	assert_eq!(bits[3 .. 60].first_zero(), Some(13));
+	assert_eq!(bits[3 .. 60].last_zero(), Some(44));
+
+	//  Answers in the partial tail, where the dead bits disagree.
+	let data = [0u8, 0x20, 0xFF];
+	let bits = &data.bits::<Msb0>()[3 .. 11];
+	assert_eq!(bits.first_one(), Some(7));
+	assert_eq!(bits.last_one(), Some(7));
+	assert_eq!(bits.last_zero(), Some(6));
+	assert_eq!(data.bits::<Msb0>()[9 .. 16].first_zero(), Some(0));
+	assert_eq!(data.bits::<Msb0>()[2 .. 10].first_one(), None);
+	assert_eq!(data.bits::<Msb0>()[11 .. 21].last_zero(), Some(4));
+
+	//  No such bit.
+	let data = [0u32; 3];
+	assert_eq!(data.bits::<Lsb0>()[5 .. 90].first_one(), None);
+	assert_eq!(data.bits::<Lsb0>()[5 .. 90].last_one(), None);
+	let data = [!0u32; 3];
+	assert_eq!(data.bits::<Lsb0>()[5 .. 90].first_zero(), None);
+	assert_eq!(data.bits::<Lsb0>()[5 .. 90].last_zero(), None);
+	assert_eq!(BitSlice::<Msb0, u8>::empty().first_one(), None);
+	assert_eq!(BitSlice::<Msb0, u8>::empty().last_zero(), None);
+}
+
+#[test]
+fn edge_runs() {
+	use crate::order::Lsb0;
+
+	let empty = BitSlice::<Msb0, u8>::empty();
+	assert_eq!(empty.leading_zeros(), 0);
+	assert_eq!(empty.trailing_zeros(), 0);
+	assert_eq!(empty.leading_ones(), 0);
+	assert_eq!(empty.trailing_ones(), 0);
+
+	let data = [0u16; 3];
+	let bits = &data.bits::<Lsb0>()[5 ..];
+	assert_eq!(bits.leading_zeros(), 43);
+	assert_eq!(bits.trailing_zeros(), 43);
+	assert_eq!(bits.leading_ones(), 0);
+	assert_eq!(bits.trailing_ones(), 0);
+
+	//  The first element is partial, and its dead bits are set.
+	let data = [0xF0u8, 0x04, 0x00, 0x21];
+	let bits = &data.bits::<Msb0>()[4 ..];
+	assert_eq!(bits.leading_zeros(), 9);
+	assert_eq!(bits.trailing_zeros(), 0);
+	assert_eq!(bits.leading_ones(), 0);
+	assert_eq!(bits.trailing_ones(), 1);
+	let bits = &data.bits::<Msb0>()[4 .. 31];
+	assert_eq!(bits.trailing_zeros(), 4);
+	assert_eq!(bits.trailing_ones(), 0);
+
+	let data = [0x0Fu8, 0xFF, 0xFB];
+	let bits = &data.bits::<Msb0>()[4 ..];
+	assert_eq!(bits.leading_ones(), 17);
+	assert_eq!(bits.leading_zeros(), 0);
+	assert_eq!(bits.trailing_ones(), 2);
+	let bits = &data.bits::<Lsb0>()[1 .. 18];
+	assert_eq!(bits.leading_ones(), 3);
+	assert_eq!(bits.trailing_ones(), 10);
+
+	let data = [!0u32; 2];
+	let bits = &data.bits::<Lsb0>()[7 .. 50];
+	assert_eq!(bits.leading_ones(), 43);
+	assert_eq!(bits.trailing_ones(), 43);
+	assert_eq!(bits.trailing_zeros(), 0);
+}
+
+#[test]
+fn parity() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	let data = [next(), next(), next(), next()];
+	let bytes = [next() as u8, next() as u8, next() as u8, next() as u8];
+	for _ in 0 .. 256 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 190) as usize;
+		let bits = &data.bits::<Msb0>()[head .. head + len];
+		assert_eq!(bits.parity(), bits.count_ones() & 1 == 1);
+		let bits = &data.bits::<Lsb0>()[head .. head + len];
+		assert_eq!(bits.parity(), bits.count_ones() & 1 == 1);
+
+		let head = (next() % 8) as usize;
+		let len = (next() % 24) as usize;
+		let bits = &bytes.bits::<Lsb0>()[head .. head + len];
+		assert_eq!(bits.parity(), bits.count_ones() & 1 == 1);
+	}
+}
+
+#[test]
+fn find() {
+	use crate::{
+		order::{
+			BitOrder,
+			Lsb0,
+		},
+		store::BitStore,
+	};
+
+	/// Finds the first and last matches by comparing every window.
+	fn naive<D, U>(
+		hay: &BitSlice<Msb0, u64>,
+		needle: &BitSlice<D, U>,
+	) -> (Option<usize>, Option<usize>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		let len = needle.len();
+		let mut hits = (0 ..= hay.len() - len)
+			.filter(|&idx| hay[idx .. idx + len] == needle);
+		let first = hits.next();
+		(first, hits.last().or(first))
+	}
+
+	let mut next = xorshift();
+
+	let data = [next(), next(), next(), next()];
+	let hay = data.bits::<Msb0>();
+	for _ in 0 .. 128 {
+		//  Needles cut from the haystack, which straddle element boundaries.
+		let start = (next() % 200) as usize;
+		let needle = &hay[start .. start + (next() % 56) as usize];
+		let (first, last) = naive(hay, needle);
+		assert_eq!(hay.find(needle), first);
+		assert_eq!(hay.rfind(needle), last);
+		assert!(first.unwrap() <= start && start <= last.unwrap());
+
+		//  A needle in another ordering and storage type.
+		let other = next() as u16;
+		let needle = &other.bits::<Lsb0>()[.. (next() % 17) as usize];
+		let (first, last) = naive(hay, needle);
+		assert_eq!(hay.find(needle), first);
+		assert_eq!(hay.rfind(needle), last);
+	}
+
+	//  Needles longer than, or equal to, the haystack.
+	let hay = &data.bits::<Msb0>()[3 .. 70];
+	assert_eq!(hay.find(&data.bits::<Msb0>()[3 .. 71]), None);
+	assert_eq!(hay.rfind(&data.bits::<Msb0>()[2 .. 70]), None);
+	assert_eq!(hay.find(hay), Some(0));
+	assert_eq!(hay.rfind(hay), Some(0));
+
+	//  Empty needles and haystacks.
+	let empty = BitSlice::<Lsb0, u8>::empty();
+	assert_eq!(hay.find(empty), Some(0));
+	assert_eq!(hay.rfind(empty), Some(67));
+	assert_eq!(empty.find(empty), Some(0));
+	assert_eq!(empty.find(&hay[.. 1]), None);
+}
+
+#[test]
+fn starts_ends_with() {
+	use crate::order::Lsb0;
+
+	let data = [0xA5u8, 0x3C, 0x0F];
+	let bits = &data.bits::<Msb0>()[3 .. 21];
+
+	//  Affixes with other cursors and storage types.
+	let lsb = 0xF314u16;
+	let prefix = &lsb.bits::<Lsb0>()[.. 7];
+	assert_eq!(prefix, &bits[.. 7]);
+	assert!(bits.starts_with(prefix));
+	assert!(!bits[1 ..].starts_with(prefix));
+	let msb = 0x607F_FFFFu32;
+	let suffix = &msb.bits::<Msb0>()[1 .. 10];
+	assert_eq!(suffix, &bits[9 ..]);
+	assert!(bits.ends_with(suffix));
+	assert!(!bits[.. 17].ends_with(suffix));
+
+	//  Whole, empty, and overlong affixes.
+	assert!(bits.starts_with(bits));
+	assert!(bits.ends_with(&data.bits::<Msb0>()[3 .. 21]));
+	assert!(bits.starts_with(BitSlice::<Lsb0, u64>::empty()));
+	assert!(bits.ends_with(BitSlice::<Lsb0, u64>::empty()));
+	assert!(!bits.starts_with(&data.bits::<Msb0>()[3 .. 22]));
+	assert!(!bits.ends_with(&data.bits::<Msb0>()[2 .. 21]));
+	assert!(!BitSlice::<Msb0, u8>::empty().ends_with(&bits[.. 1]));
+}
+
+#[test]
+fn starts_ends_with_same_layout() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($($o:ty),+ $(,)?) => { $(
+			let data = [next() as u32, next() as u32, next() as u32];
+			for _ in 0 .. 256 {
+				let head = (next() % 32) as usize;
+				let bits = &data.bits::<$o>()[head .. head + 64];
+				//  Affixes that start at the same position within an element
+				//  as the slice, or at any other.
+				let mut copy = data;
+				let from = if next() & 1 == 0 {
+					head
+				}
+				else {
+					(next() % 32) as usize
+				};
+				let len = (next() % 65) as usize;
+				let affix = &mut copy.bits_mut::<$o>()[from .. from + len];
+				affix.copy_from_slice(&bits[.. len]);
+				if next() & 1 == 0 && len > 0 {
+					let idx = (next() as usize) % len;
+					let bit = affix[idx];
+					affix.set(idx, !bit);
+				}
+				assert_eq!(bits.starts_with(affix), bits[.. len] == *affix);
+
+				affix.copy_from_slice(&bits[64 - len ..]);
+				if next() & 1 == 0 && len > 0 {
+					let idx = (next() as usize) % len;
+					let bit = affix[idx];
+					affix.set(idx, !bit);
+				}
+				assert_eq!(bits.ends_with(affix), bits[64 - len ..] == *affix);
+			}
+		)+ };
+	}
+
+	check!(Msb0, Lsb0);
+}
+
+#[test]
+fn strip_affixes() {
+	use crate::order::Lsb0;
+
+	let mut data = [0xA5u8, 0x3C, 0x0F];
+	let bits = &data.bits::<Msb0>()[3 .. 21];
+	let base = bits.bitptr();
+
+	//  The prefix ends mid-element, so the rest begins at head 2.
+	let rest = bits.strip_prefix(&0x14u16.bits::<Lsb0>()[.. 7]).unwrap();
+	assert_eq!(rest.len(), 11);
+	assert_eq!(*rest.bitptr().head(), 2);
+	assert_eq!(rest.as_ptr(), unsafe { base.pointer().r().add(1) });
+	assert!(bits.strip_prefix(bits![1]).is_none());
+	assert!(bits.strip_prefix(&data.bits::<Msb0>()[3 .. 22]).is_none());
+
+	//  The suffix begins mid-element, so the rest keeps the head and ends
+	//  at 12.
+	let rest = bits.strip_suffix(bits![Lsb0, u8; 1, 1, 0, 0, 0, 0, 0, 0, 1]);
+	let rest = rest.unwrap();
+	assert_eq!(rest.len(), 9);
+	assert_eq!(rest.bitptr().head(), base.head());
+	assert_eq!(rest.as_ptr(), base.pointer().r());
+	assert!(bits.strip_suffix(bits![0]).is_none());
+
+	assert_eq!(bits.strip_prefix(bits).map(BitSlice::len), Some(0));
+	let empty = BitSlice::<Lsb0, u32>::empty();
+	assert_eq!(bits.strip_suffix(empty), Some(bits));
+
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. 21];
+	let rest = bits.strip_prefix_mut(bits![0, 0, 1]).unwrap();
+	rest.set_all(false);
+	assert_eq!(data, [0xA4, 0, 0x07]);
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. 21];
+	bits.strip_suffix_mut(bits![0; 3]).unwrap().set_all(true);
+	assert_eq!(data, [0xBF, 0xFF, 0xC7]);
+}
+
+#[test]
+fn split_pattern() {
+	let data = 0b1100_1110_0110_1101u16;
+	let bits = data.bits::<Msb0>();
+	let sep = bits![Lsb0, u8; 1, 1];
+
+	//  Separators at both ends, and back to back.
+	let mut iter = bits.split_pattern(sep);
+	assert_eq!(iter.next(), Some(&bits[0 .. 0]));
+	assert_eq!(iter.next(), Some(&bits[2 .. 4]));
+	assert_eq!(iter.next(), Some(&bits[6 .. 9]));
+	assert_eq!(iter.next(), Some(&bits[11 .. 12]));
+	assert_eq!(iter.next(), Some(&bits[14 .. 16]));
+	assert!(iter.next().is_none());
+	assert!(iter.next().is_none());
+
+	let bits = bits![1, 1, 1, 1];
+	assert_eq!(bits.split_pattern(sep).count(), 3);
+	assert!(bits.split_pattern(sep).all(BitSlice::is_empty));
+	let bits = bits![1, 1, 1];
+	let mut iter = bits.split_pattern(sep);
+	assert_eq!(iter.next().map(BitSlice::len), Some(0));
+	assert_eq!(iter.next(), Some(&bits[2 ..]));
+	let mut iter = bits.rsplit_pattern(sep);
+	assert_eq!(iter.next().map(BitSlice::len), Some(0));
+	assert_eq!(iter.next(), Some(&bits[.. 1]));
+	assert!(iter.next().is_none());
+
+	//  No separator, and an empty slice.
+	let bits = bits![0, 1, 0, 1];
+	let mut iter = bits.split_pattern(sep);
+	assert_eq!(iter.next(), Some(bits));
+	assert!(iter.next().is_none());
+	let empty = BitSlice::<Msb0, u8>::empty();
+	assert_eq!(empty.split_pattern(sep).count(), 1);
+	assert_eq!(empty.rsplit_pattern(sep).count(), 1);
+
+	//  Limited splits.
+	let bits = data.bits::<Msb0>();
+	let mut iter = bits.splitn_pattern(3, sep);
+	assert_eq!(iter.next(), Some(&bits[0 .. 0]));
+	assert_eq!(iter.next(), Some(&bits[2 .. 4]));
+	assert_eq!(iter.next(), Some(&bits[6 ..]));
+	assert!(iter.next().is_none());
+	assert_eq!(bits.splitn_pattern(0, sep).count(), 0);
+	assert_eq!(bits.splitn_pattern(1, sep).next(), Some(bits));
+}
+
+#[test]
+#[should_panic(expected = "Separator cannot be empty")]
+fn split_pattern_empty() {
+	let bits = bits![0, 1];
+	bits.split_pattern(BitSlice::<Msb0, u8>::empty());
+}
+
+#[test]
+fn match_indices() {
+	let bits = bits![1, 1, 1, 1, 1];
+	let dna = bits![Lsb0, u16; 1, 1, 1];
+	assert!(bits.match_indices(dna).eq([0].iter().copied()));
+	assert!(bits.overlapping_matches(dna).eq([0, 1, 2].iter().copied()));
+
+	//  A needle of another layout is packed once, when the iterator is made.
+	#[cfg(feature = "alloc")]
+	{
+		use crate::slice::iter::Needle;
+		match bits.overlapping_matches(dna).needle {
+			Needle::Packed(packed) => assert_eq!(packed, dna),
+			Needle::Borrowed(..) => panic!("The needle was not packed"),
+		}
+		match bits.match_indices(bits![1, 1]).needle {
+			Needle::Borrowed(needle, _) => assert_eq!(needle, bits![1, 1]),
+			Needle::Packed(_) => panic!("The needle was packed needlessly"),
+		}
+	}
+
+	//  Needles that begin and end on element boundaries.
+	let data = [0xFFu8, 0x00, 0xFF, 0x00, 0xFF];
+	let bits = data.bits::<Msb0>();
+	let needle = &data.bits::<Msb0>()[.. 16];
+	assert!(bits.match_indices(needle).eq([0, 16].iter().copied()));
+	assert!(bits.overlapping_matches(needle).eq([0, 16].iter().copied()));
+	let needle = &data.bits::<Msb0>()[8 .. 24];
+	assert!(bits.match_indices(needle).eq([8, 24].iter().copied()));
+	let needle = &bits[.. 24];
+	assert!(bits.match_indices(needle).eq([0].iter().copied()));
+	assert!(bits.overlapping_matches(needle).eq([0, 16].iter().copied()));
+
+	//  The iterator is lazy and can be restarted from a clone.
+	let mut iter = bits.overlapping_matches(bits![1, 1]);
+	assert_eq!(iter.size_hint(), (0, Some(40)));
+	assert_eq!(iter.nth(6), Some(6));
+	let rest = iter.clone();
+	assert_eq!(iter.next(), Some(16));
+	assert_eq!(iter.size_hint(), (0, Some(23)));
+	assert_eq!(rest.count(), 3 * 7 - 7);
+	assert!(iter.by_ref().last().is_some());
+	assert!(iter.next().is_none());
+	assert_eq!(iter.size_hint(), (0, Some(0)));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn runs() {
+	use crate::{
+		order::{
+			BitOrder,
+			Lsb0,
+		},
+		store::BitStore,
+	};
+	use alloc::vec::Vec;
+
+	/// Collects the runs by comparing neighboring bits.
+	fn naive<O, T>(bits: &BitSlice<O, T>) -> Vec<(bool, usize)>
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let mut out = Vec::<(bool, usize)>::new();
+		for &bit in bits {
+			match out.last_mut() {
+				Some((value, len)) if *value == bit => *len += 1,
+				_ => out.push((bit, 1)),
+			}
+		}
+		out
+	}
+
+	let mut next = xorshift();
+
+	let data = [next(), 0, !0, next() & next(), !0, next() | next()];
+	for _ in 0 .. 128 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 320) as usize;
+		let bits = &data.bits::<Msb0>()[head .. head + len];
+		let runs = naive(bits);
+		assert!(bits.runs().eq(runs.iter().copied()));
+		assert!(bits.runs().rev().eq(runs.iter().rev().copied()));
+		for &value in &[false, true] {
+			let max = runs
+				.iter()
+				.filter(|&&(bit, _)| bit == value)
+				.map(|&(_, len)| len)
+				.max();
+			assert_eq!(bits.max_run(value), max.unwrap_or(0));
+		}
+	}
+
+	assert!(BitSlice::<Lsb0, u8>::empty().runs().next().is_none());
+	assert_eq!(BitSlice::<Lsb0, u8>::empty().max_run(true), 0);
+	let bits = &data.bits::<Lsb0>()[70 .. 190];
+	assert!(bits.runs().eq([(false, 58), (true, 62)].iter().copied()));
+	let bits = &data.bits::<Lsb0>()[130 .. 190];
+	assert!(bits.runs().eq([(true, 60)].iter().copied()));
+	assert_eq!(bits.max_run(true), 60);
+	assert_eq!(bits.max_run(false), 0);
+}
+
+#[test]
+fn chunks() {
+	use crate::order::Lsb0;
+
+	let data = [0x0123_4567u32, 0x89AB_CDEF];
+	let bits = &data.bits::<Msb0>()[5 ..];
+	assert_eq!(bits.len(), 59);
+
+	//  Six-bit symbols, with a shorter final chunk.
+	let chunks = bits.chunks(6);
+	assert_eq!(chunks.len(), 10);
+	for (idx, chunk) in chunks.enumerate() {
+		let start = idx * 6;
+		assert_eq!(chunk, &bits[start .. core::cmp::min(start + 6, 59)]);
+	}
+	let mut chunks = bits.chunks(6);
+	assert_eq!(chunks.next_back(), Some(&bits[54 ..]));
+	assert_eq!(chunks.next_back(), Some(&bits[48 .. 54]));
+	assert_eq!(chunks.len(), 8);
+	assert_eq!(chunks.next(), Some(&bits[.. 6]));
+
+	//  Widths that divide the slice, and that exceed it.
+	let bits = &bits[.. 56];
+	assert!(bits.chunks(8).all(|chunk| chunk.len() == 8));
+	assert_eq!(bits.chunks(8).rev().next(), Some(&bits[48 ..]));
+	assert_eq!(bits.chunks(8).len(), 7);
+	let mut chunks = bits.chunks(100);
+	assert_eq!(chunks.len(), 1);
+	assert_eq!(chunks.next(), Some(bits));
+	assert!(chunks.next().is_none());
+	assert_eq!(BitSlice::<Lsb0, u8>::empty().chunks(3).len(), 0);
+
+	let mut data = [0u16; 3];
+	let bits = &mut data.bits_mut::<Lsb0>()[2 .. 42];
+	assert_eq!(bits.chunks_mut(7).len(), 6);
+	for (idx, chunk) in bits.chunks_mut(7).enumerate() {
+		chunk.set(0, true);
+		chunk.set(chunk.len() - 1, idx % 2 == 0);
+	}
+	let mut chunks = bits.chunks_mut(10);
+	let last = chunks.next_back().unwrap();
+	let first = chunks.next().unwrap();
+	assert_eq!(chunks.len(), 2);
+	last.set_all(false);
+	first.set_all(true);
+	assert_eq!(data, [0x0FFC, 0x40C1, 0x0000]);
+	assert_eq!(data.bits_mut::<Lsb0>().chunks_mut(48).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Chunk width cannot be zero")]
+fn chunks_zero() {
+	BitSlice::<Msb0, u8>::empty().chunks(0);
+}
+
+#[test]
+fn chunks_exact() {
+	use crate::order::Lsb0;
+
+	let data = [0x0123_4567u32, 0x89AB_CDEF];
+	let bits = &data.bits::<Msb0>()[5 ..];
+
+	let mut chunks = bits.chunks_exact(6);
+	assert_eq!(chunks.len(), 9);
+	assert_eq!(chunks.remainder(), &bits[54 ..]);
+	assert_eq!(chunks.next(), Some(&bits[.. 6]));
+	assert_eq!(chunks.next_back(), Some(&bits[48 .. 54]));
+	assert!(chunks.all(|chunk| chunk.len() == 6));
+	let symbols = bits
+		.chunks_exact(6)
+		.map(|chunk| chunk.to_uint::<u8>().unwrap())
+		.take(4);
+	assert!(symbols.eq([0x09, 0x06, 0x22, 0x2C].iter().copied()));
+
+	//  The remainder is empty when the width divides the length.
+	let bits = &bits[.. 54];
+	let chunks = bits.chunks_exact(6);
+	assert_eq!(chunks.len(), 9);
+	assert!(chunks.remainder().is_empty());
+	let chunks = bits.chunks_exact(55);
+	assert_eq!(chunks.len(), 0);
+	assert_eq!(chunks.remainder(), bits);
+
+	let mut data = [0u8; 3];
+	let bits = &mut data.bits_mut::<Lsb0>()[1 .. 23];
+	let mut chunks = bits.chunks_exact_mut(4);
+	assert_eq!(chunks.len(), 5);
+	chunks.next().unwrap().set_all(true);
+	chunks.next_back().unwrap().set(3, true);
+	chunks.into_remainder().set_all(true);
+	assert_eq!(data, [0x1E, 0x00, 0x70]);
+}
+
+#[test]
+fn rchunks() {
+	use crate::order::Lsb0;
+
+	let data = [0x0123_4567u32, 0x89AB_CDEF];
+	let bits = &data.bits::<Msb0>()[5 ..];
+
+	//  The ragged chunk holds the lowest indices, and comes last.
+	let mut chunks = bits.rchunks(6);
+	assert_eq!(chunks.len(), 10);
+	assert_eq!(chunks.next(), Some(&bits[53 ..]));
+	assert_eq!(chunks.next(), Some(&bits[47 .. 53]));
+	assert_eq!(chunks.next_back(), Some(&bits[.. 5]));
+	assert_eq!(chunks.next_back(), Some(&bits[5 .. 11]));
+	assert_eq!(chunks.len(), 6);
+	assert!(bits.rchunks(6).take(9).all(|chunk| chunk.len() == 6));
+
+	let chunks = bits.rchunks_exact(6);
+	assert_eq!(chunks.remainder(), &bits[.. 5]);
+	assert!(chunks.eq(bits.rchunks(6).take(9)));
+
+	//  When the width divides the length, both directions agree.
+	let bits = &bits[.. 54];
+	assert!(bits.rchunks(9).eq(bits.chunks(9).rev()));
+	assert!(bits.rchunks(9).rev().eq(bits.chunks(9)));
+	assert!(bits.rchunks_exact(9).eq(bits.chunks_exact(9).rev()));
+	assert!(bits.rchunks_exact(9).remainder().is_empty());
+
+	let mut data = [0u8; 3];
+	let bits = &mut data.bits_mut::<Lsb0>()[1 .. 23];
+	let mut chunks = bits.rchunks_mut(8);
+	assert_eq!(chunks.len(), 3);
+	chunks.next().unwrap().set(0, true);
+	chunks.next_back().unwrap().set_all(true);
+	let mut chunks = bits.rchunks_exact_mut(8);
+	chunks.next_back().unwrap().set(7, true);
+	chunks.into_remainder().set(0, false);
+	assert_eq!(data, [0x7C, 0xC0, 0x00]);
+}
+
+#[test]
+fn windows() {
+	let data = [0x0123_4567u32, 0x89AB_CDEF];
+	let bits = &data.bits::<Msb0>()[5 .. 50];
+
+	let mut windows = bits.windows(7);
+	assert_eq!(windows.len(), 39);
+	assert_eq!(windows.next(), Some(&bits[.. 7]));
+	assert_eq!(windows.next_back(), Some(&bits[38 ..]));
+	assert_eq!(windows.next_back(), Some(&bits[37 .. 44]));
+	assert_eq!(windows.len(), 36);
+
+	//  `nth` jumps directly to the window, from either state.
+	assert_eq!(windows.nth(10), Some(&bits[11 .. 18]));
+	assert_eq!(windows.len(), 25);
+	assert_eq!(windows.clone().nth(24), Some(&bits[36 .. 43]));
+	assert!(windows.clone().nth(25).is_none());
+	let rest = (12 .. 37).rev().map(|n| &bits[n .. n + 7]);
+	assert!(windows.clone().rev().eq(rest));
+	assert_eq!(windows.clone().last(), Some(&bits[36 .. 43]));
+	assert_eq!(windows.count(), 25);
+
+	//  Windows as wide as, and wider than, the slice.
+	let mut windows = bits.windows(45);
+	assert_eq!(windows.len(), 1);
+	assert_eq!(windows.next_back(), Some(bits));
+	assert!(windows.next().is_none());
+	assert_eq!(bits.windows(46).len(), 0);
+	assert!(bits.windows(46).next_back().is_none());
+	assert!(bits.windows(1).eq(bits.chunks(1)));
+}
+
+#[test]
+#[should_panic(expected = "Window width cannot be zero")]
+fn windows_zero() {
+	BitSlice::<Msb0, u8>::empty().windows(0);
+}
+
+#[test]
+fn split_at_mut() {
+	use crate::order::Lsb0;
+
+	//  The split falls inside the second element, which both halves share.
+	let mut data = [0u16; 3];
+	let bits = &mut data.bits_mut::<Msb0>()[3 .. 45];
+	let (left, right) = bits.split_at_mut(20);
+	assert_eq!((left.len(), right.len()), (20, 22));
+	left.set_all(true);
+	right.set(0, true);
+	right[1 .. 21].set_all(false);
+	right.set(21, true);
+	left.set(19, false);
+	assert_eq!(data, [0x1FFF, 0xFD00, 0x0008]);
+
+	//  Halves may be split again, and their writes still land in place.
+	let mut data = 0u32;
+	let bits = data.bits_mut::<Lsb0>();
+	let (left, right) = bits.split_at_mut(13);
+	let (a, b) = left.split_at_mut(5);
+	let (c, d) = right.split_at_mut(1);
+	a.set_all(true);
+	b.set(7, true);
+	c.set(0, true);
+	d[.. 3].set_all(true);
+	assert_eq!(data, 0x0001_F01F);
+
+	//  The split may be at either end.
+	let mut data = [!0u8; 2];
+	let (left, right) = data.bits_mut::<Msb0>().split_at_mut(16);
+	assert!(right.is_empty());
+	left.set(15, false);
+	let (left, right) = data.bits_mut::<Msb0>().split_at_mut(0);
+	assert!(left.is_empty());
+	right.set(0, false);
+	assert_eq!(data, [0x7F, 0xFE]);
+}
+
+#[test]
+fn split_first_last() {
+	use crate::order::Lsb0;
+
+	let empty = BitSlice::<Msb0, u8>::empty();
+	assert!(empty.first().is_none());
+	assert!(empty.last().is_none());
+	assert!(empty.split_first().is_none());
+	assert!(empty.split_last().is_none());
+
+	let data = 0x81u8;
+	let bits = &data.bits::<Msb0>()[.. 7];
+	assert_eq!(bits.first(), Some(&true));
+	assert_eq!(bits.last(), Some(&false));
+	let (first, rest) = bits.split_first().unwrap();
+	assert_eq!((*first, rest), (true, &bits[1 ..]));
+	let (last, rest) = bits.split_last().unwrap();
+	assert_eq!((*last, rest), (false, &bits[.. 6]));
+
+	//  Writes through the detached proxies land in the original buffer, in
+	//  the element shared with the rest of the slice.
+	let mut data = [0u16; 2];
+	let bits = &mut data.bits_mut::<Lsb0>()[3 .. 29];
+	let (mut first, rest) = bits.split_first_mut().unwrap();
+	*first = true;
+	rest.set(0, true);
+	rest.set(23, true);
+	drop(first);
+	let (mut last, rest) = bits.split_last_mut().unwrap();
+	assert!(!*last);
+	*last = true;
+	rest.set(24, false);
+	drop(last);
+	assert_eq!(data, [0x0018, 0x1000]);
+
+	let mut data = 0u8;
+	let bits = data.bits_mut::<Msb0>();
+	*bits.first_mut().unwrap() = true;
+	*bits.last_mut().unwrap() = true;
+	assert_eq!(data, 0x81);
+	let empty = BitSlice::<Msb0, u8>::empty_mut();
+	assert!(empty.split_first_mut().is_none());
+	assert!(empty.split_last_mut().is_none());
+}
+
+#[test]
+fn iter_mut() {
+	use crate::order::Lsb0;
+
+	let mut data = [0x0Fu8, 0xA5, 0x3C];
+	let bits = &mut data.bits_mut::<Msb0>()[2 .. 21];
+	for mut bit in bits.iter_mut() {
+		*bit = !*bit;
+	}
+	assert_eq!(data, [0x30, 0x5A, 0xC4]);
+
+	let mut data = [0u16; 2];
+	let bits = &mut data.bits_mut::<Lsb0>()[5 .. 27];
+	let mut iter = bits.iter_mut();
+	assert_eq!(iter.len(), 22);
+	*iter.next().unwrap() = true;
+	*iter.next_back().unwrap() = true;
+	assert_eq!(iter.len(), 20);
+	//  Several proxies into one element may be alive at once.
+	let mut a = iter.next().unwrap();
+	let mut b = iter.nth(1).unwrap();
+	let mut c = iter.next_back().unwrap();
+	*a = true;
+	*b = true;
+	*c = true;
+	drop(b);
+	drop(a);
+	drop(c);
+	assert_eq!(iter.len(), 16);
+	for (idx, mut bit) in iter.rev().enumerate() {
+		*bit = idx % 4 == 0;
+	}
+	assert_eq!(data, [0x1160, 0x0711]);
+}
+
+#[test]
+fn get_mut_proxy() {
+	fn flip<O, T>(bit: &mut BitMut<O, T>)
+	where
+		O: BitOrder,
+		T: BitStore,
+	{
+		let old = **bit;
+		assert_eq!(bit.replace(!old), old);
+	}
+
+	let mut data = [0u16; 2];
+	{
+		let bits = data.bits_mut::<Msb0>();
+		assert!(bits.get_mut(32).is_none());
+		assert!(bits[3 ..].get_mut(29).is_none());
+
+		let mut bit = bits.get_mut(17).unwrap();
+		flip(&mut bit);
+		assert!(*bit);
+	}
+	assert_eq!(data, [0, 0x4000]);
+
+	{
+		let bits = data.bits_mut::<Msb0>();
+		let mut bit = bits.get_mut(17).unwrap();
+		flip(&mut bit);
+		flip(&mut bit);
+		drop(bit);
+		bits.get_mut(4).unwrap().commit(true);
+	}
+	assert_eq!(data, [0x0800, 0x4000]);
+}
+
+#[test]
+#[allow(clippy::range_minus_one, clippy::range_plus_one)]
+fn get_range() {
+	let mut data = [0x3Cu8, 0xA5];
+	let bits = &mut data.bits_mut::<Msb0>()[2 ..];
+	let len = bits.len();
+	//  Reversed ranges are rejected rather than resolved as empty.
+	let (start, end) = (5, 2);
+
+	assert_eq!(bits.get(len .. len).map(BitSlice::len), Some(0));
+	assert_eq!(bits.get(len ..).map(BitSlice::len), Some(0));
+	assert!(bits.get(len .. len + 1).is_none());
+	assert!(bits.get(len + 1 ..).is_none());
+	assert!(bits.get(.. len + 1).is_none());
+	assert!(bits.get(..= len).is_none());
+	assert_eq!(bits.get(..= len - 1).map(BitSlice::len), Some(len));
+	assert!(bits.get(start .. end).is_none());
+	assert!(bits.get(0 ..= usize::max_value()).is_none());
+	assert!(bits.get(..= usize::max_value()).is_none());
+
+	let sub = bits.get(0 .. 6).unwrap();
+	assert_eq!(sub, bits![1, 1, 1, 1, 0, 0]);
+
+	assert!(bits.get_mut(3 .. len + 1).is_none());
+	assert!(bits.get_mut(start .. end).is_none());
+	bits.get_mut(4 .. 8).unwrap().set_all(true);
+	assert_eq!(data, [0x3F, 0xE5]);
+}
+
+#[test]
+fn unchecked_access() {
+	let mut next = xorshift();
+
+	let mut checked = [next(), next()];
+	let mut unchecked = checked;
+	let a = &mut checked.bits_mut::<Msb0>()[5 .. 123];
+	let b = &mut unchecked.bits_mut::<Msb0>()[5 .. 123];
+	for idx in 0 .. a.len() {
+		assert_eq!(unsafe { *b.get_unchecked(idx) }, a[idx]);
+	}
+	for _ in 0 .. 500 {
+		let rand = next() as usize;
+		let (idx, value) = (rand % a.len(), rand & 1 << 30 != 0);
+		a.set(idx, value);
+		unsafe { b.set_unchecked(idx, value) };
+		assert_eq!(a, b);
+
+		let (from, to) = ((rand >> 8) % a.len(), (rand >> 24) % a.len());
+		let bit = a[from];
+		a.set(to, bit);
+		unsafe { b.copy_unchecked(from, to) };
+		assert_eq!(a, b);
+	}
+	assert_eq!(checked, unchecked);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "Index out of range: 4 >= 4")]
+fn set_unchecked_debug_check() {
+	let mut data = 0u8;
+	unsafe { data.bits_mut::<Msb0>()[.. 4].set_unchecked(4, true) };
+}
+
+#[test]
+fn fill() {
+	let mut next = xorshift();
+
+	let init = [next(), next(), next(), next()];
+	for &value in [false, true].iter() {
+		check_ranges::<Msb0, _, _>(init, |bits, _, _| {
+			bits.fill(value);
+			assert_eq!(bits.count_ones(), if value { bits.len() } else { 0 });
+		});
+		check_ranges::<Local, _, _>([0xA5u8; 32], |bits, _, _| {
+			bits.fill(value);
+			assert_eq!(bits.count_ones(), if value { bits.len() } else { 0 });
+		});
+	}
+}
+
+#[test]
+fn fill_with() {
+	let mut next = xorshift();
+
+	let init = [next(), next(), next(), next()];
+	let pattern = next();
+	check_ranges::<Msb0, _, _>(init, |bits, _, _| {
+		let mut calls = 0;
+		bits.fill_with(|idx| {
+			assert_eq!(idx, calls);
+			calls += 1;
+			pattern >> (idx % 64) & 1 == 1
+		});
+		assert_eq!(calls, bits.len());
+		for (idx, &bit) in bits.iter().enumerate() {
+			assert_eq!(bit, pattern >> (idx % 64) & 1 == 1);
+		}
+	});
+
+	check_ranges::<Local, _, _>([0x5Au16; 16], |bits, _, _| {
+		let mut count = 0;
+		bits.fill_with(|idx| {
+			count += 1;
+			idx % 3 == 0
+		});
+		assert_eq!(count, bits.len());
+		for (idx, &bit) in bits.iter().enumerate() {
+			assert_eq!(bit, idx % 3 == 0);
+		}
+	});
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn copy_within() {
+	use alloc::vec::Vec;
+
+	let mut next = xorshift();
+
+	let mut data = [0u64; 8];
+	for elem in data.iter_mut() {
+		*elem = next();
+	}
+	let mut bytes = [0u8; 64];
+	for byte in bytes.iter_mut() {
+		*byte = next() as u8;
+	}
+
+	for round in 0 .. 1000 {
+		let rand = next() as usize;
+		let skip = rand % 13;
+		let len = 500 - skip - (rand >> 4) % 13;
+		let start = (rand >> 8) % len;
+		let end = start + (rand >> 20) % (len - start + 1);
+		//  Every eighth move keeps the phase, so the whole-element path runs.
+		let dest = if round % 8 == 0 {
+			let shift = ((rand >> 36) % 4) * 64;
+			if (rand >> 40) & 1 == 0 && start >= shift {
+				start - shift
+			}
+			else if start + shift <= len - (end - start) {
+				start + shift
+			}
+			else {
+				start
+			}
+		}
+		else {
+			(rand >> 44) % (len - (end - start) + 1)
+		};
+
+		let bits = &mut data.bits_mut::<Msb0>()[skip .. skip + len];
+		let mut model = bits.iter().cloned().collect::<Vec<_>>();
+		let moved = model[start .. end].to_vec();
+		model[dest .. dest + moved.len()].copy_from_slice(&moved);
+		bits.copy_within(start .. end, dest);
+		assert!(bits.iter().cloned().eq(model.iter().cloned()));
+
+		let bits = &mut bytes.bits_mut::<Local>()[skip .. skip + len];
+		let mut model = bits.iter().cloned().collect::<Vec<_>>();
+		let moved = model[start .. end].to_vec();
+		model[dest .. dest + moved.len()].copy_from_slice(&moved);
+		bits.copy_within(start .. end, dest);
+		assert!(bits.iter().cloned().eq(model.iter().cloned()));
+	}
+
+	//  The bits outside the slice are untouched.
+	let mut data = [!0u16, 0, !0];
+	data.bits_mut::<Msb0>()[3 .. 45].copy_within(0 .. 20, 19);
+	assert_eq!(data[2] & 0x7, 0x7);
+	assert_eq!(data[0] & 0xE000, 0xE000);
+}
+
+#[test]
+#[should_panic(expected = "Destination 5..9 out of bounds: 8")]
+fn copy_within_dest_out_of_bounds() {
+	let mut data = 0u8;
+	data.bits_mut::<Msb0>().copy_within(0 .. 4, 5);
+}
+
+#[test]
+#[should_panic(expected = "Range 4..9 out of bounds: 8")]
+fn copy_within_src_out_of_bounds() {
+	let mut data = 0u8;
+	data.bits_mut::<Msb0>().copy_within(4 ..= 8, 0);
+}
+
+#[test]
+fn copy_from_bitslice() {
+	let mut next = xorshift();
+
+	let src = [next(), next(), next(), next()];
+	let init = [next(), next(), next(), next()];
+	let mut bytes = [0u8; 32];
+	for byte in bytes.iter_mut() {
+		*byte = next() as u8;
+	}
+	for round in 0 .. 500 {
+		let rand = next() as usize;
+		let len = rand % 193;
+		let from = (rand >> 8) % (256 - len + 1);
+		//  Every fourth copy keeps the phase, so the block copy runs.
+		let to = if round % 4 == 0 {
+			from % 64
+		}
+		else {
+			(rand >> 16) % (256 - len + 1)
+		};
+
+		let mut dst = init;
+		let mut expected = init;
+		dst.bits_mut::<Msb0>()[to .. to + len]
+			.copy_from_bitslice(&src.bits::<Msb0>()[from .. from + len]);
+		for n in 0 .. len {
+			let bit = src.bits::<Msb0>()[from + n];
+			expected.bits_mut::<Msb0>().set(to + n, bit);
+		}
+		assert_eq!(dst, expected);
+
+		let mut dst = [0xA5u8; 32];
+		let mut expected = dst;
+		dst.bits_mut::<Local>()[to .. to + len]
+			.copy_from_slice(&bytes.bits::<Local>()[from .. from + len]);
+		for n in 0 .. len {
+			let bit = bytes.bits::<Local>()[from + n];
+			expected.bits_mut::<Local>().set(to + n, bit);
+		}
+		assert_eq!(dst, expected);
+	}
+}
+
+#[test]
+#[should_panic(expected = "Copying between slices requires equal lengths")]
+fn copy_from_bitslice_len_mismatch() {
+	let mut data = 0u8;
+	data.bits_mut::<Msb0>()[.. 4].copy_from_bitslice(&0u8.bits::<Msb0>()[.. 5]);
+}
+
+#[test]
+fn swap_with_bitslice() {
+	let mut next = xorshift();
+
+	let init = [next(), next(), next(), next(), next(), next()];
+	for round in 0 .. 500 {
+		let rand = next() as usize;
+		let mid = 64 + rand % 257;
+		let len = (rand >> 12) % (core::cmp::min(mid, 384 - mid) + 1);
+		let a = (rand >> 24) % (mid - len + 1);
+		//  Every other swap keeps the phase, which exchanges whole elements
+		//  and makes the halves share an edge element whenever `mid` is not
+		//  on an element boundary.
+		let b = if round % 2 == 0 {
+			a + (mid - a + 63) / 64 * 64 + 64 * ((rand >> 32) % 2)
+		}
+		else {
+			mid + (rand >> 40) % (384 - mid - len + 1)
+		};
+		if b < mid || b + len > 384 {
+			continue;
+		}
+
+		let mut data = init;
+		let mut expected = init;
+		{
+			let (left, right) = data.bits_mut::<Msb0>().split_at_mut(mid);
+			left[a .. a + len]
+				.swap_with_bitslice(&mut right[b - mid .. b - mid + len]);
+		}
+		let model = expected.bits_mut::<Msb0>();
+		for n in 0 .. len {
+			model.swap(a + n, b + n);
+		}
+		assert_eq!(data, expected);
+	}
+
+	//  Different stores fall back to bitwise exchange.
+	let mut a = [0x0123_4567u32, 0x89AB_CDEF];
+	let mut b = [0xFEDC_BA98_7654_3210u64];
+	let (a0, b0) = (a, b);
+	a.bits_mut::<Msb0>()[4 .. 60]
+		.swap_with_bitslice(&mut b.bits_mut::<Msb0>()[4 .. 60]);
+	assert_eq!(a.bits::<Msb0>()[4 .. 60], b0.bits::<Msb0>()[4 .. 60]);
+	assert_eq!(b.bits::<Msb0>()[4 .. 60], a0.bits::<Msb0>()[4 .. 60]);
+	assert_eq!(a[0] >> 28, 0);
+	assert_eq!(b[0] & 0xF, 0);
+}
+
+#[test]
+#[should_panic(expected = "Swapping between slices requires equal lengths")]
+fn swap_with_bitslice_len_mismatch() {
+	let (mut a, mut b) = (0u8, 0u8);
+	a.bits_mut::<Msb0>()[.. 3]
+		.swap_with_bitslice(&mut b.bits_mut::<Msb0>()[.. 4]);
+}
+
+#[test]
+fn invert() {
+	let mut next = xorshift();
+
+	let init = [next(), next(), next(), next()];
+	check_ranges::<Msb0, _, _>(init, |bits, orig, _| {
+		bits.invert();
+		for (&bit, &old) in bits.iter().zip(orig.iter()) {
+			assert_eq!(bit, !old);
+		}
+		bits.invert();
+		assert_eq!(bits[..], orig[..]);
+	});
+	check_ranges::<Local, _, _>([0x5Au8; 32], |bits, orig, _| {
+		bits.invert();
+		bits.invert();
+		assert_eq!(bits[..], orig[..]);
+	});
+
+	let mut byte = 0b1010_0101u8;
+	byte.bits_mut::<Msb0>()[2 .. 5].invert();
+	assert_eq!(byte, 0b1001_1101);
+}
+
+#[test]
+fn for_each_element() {
+	let mut next = xorshift();
+
+	let init = [next(), next(), next(), next()];
+	check_ranges::<Msb0, _, _>(init, |bits, orig, from| {
+		//  Position of the range within its first element.
+		let head = from % 64;
+		let mut outs = [0u64; 4];
+		bits.for_each_element(|idx, elem| {
+			//  The bits outside the slice are hidden from the function,
+			for pos in 0 .. 64 {
+				let at = idx * 64 + pos;
+				let live = head <= at && at < head + orig.len();
+				let bit = elem >> (63 - pos) & 1 == 1;
+				assert_eq!(bit, live && orig[at - head]);
+			}
+			//  And its output outside the slice is discarded.
+			let out = next();
+			outs[idx] = out;
+			out
+		});
+		for (n, &bit) in bits.iter().enumerate() {
+			let at = head + n;
+			assert_eq!(bit, outs[at / 64] >> (63 - at % 64) & 1 == 1);
+		}
+
+		let ones = orig.fold_elements(0, |acc, elem| acc + elem.count_ones());
+		assert_eq!(ones as usize, orig.count_ones());
+	});
+
+	let mut calls = 0;
+	BitSlice::<Local, u8>::empty_mut().for_each_element(|_, elem| {
+		calls += 1;
+		elem
+	});
+	assert_eq!(calls, 0);
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn align_to() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	let mut bytes = [0u8; 64];
+	for byte in bytes.iter_mut() {
+		*byte = next() as u8;
+	}
+	//  Reads the middle element at `addr` the way the host does.
+	let native = |addr: usize| {
+		let mut raw = [0u8; 8];
+		raw.copy_from_slice(&bytes[addr .. addr + 8]);
+		u64::from_ne_bytes(raw)
+	};
+
+	for _ in 0 .. 300 {
+		let rand = next() as usize;
+		let from = rand % 512;
+		let to = from + (rand >> 12) % (512 - from + 1);
+
+		let bits = &bytes.bits::<Msb0>()[from .. to];
+		let (l, c, r) = unsafe { bits.align_to::<u64>() };
+		let len = bits.len();
+		assert_eq!(l.len() + c.len() + r.len(), len);
+		assert_eq!(l, &bits[.. l.len()]);
+		assert_eq!(r, &bits[len - r.len() ..]);
+		//  Three whole `u64`s always fit inside 256 live bits.
+		if len >= 256 {
+			assert!(c.len() >= 192);
+		}
+		let start = c.as_slice().as_ptr() as usize;
+		for (n, chunk) in c.chunks(64).enumerate() {
+			let addr = start - bytes.as_ptr() as usize + 8 * n;
+			assert_eq!(chunk, native(addr).bits::<Msb0>());
+		}
+		if cfg!(target_endian = "big") {
+			assert_eq!(c, &bits[l.len() .. l.len() + c.len()]);
+		}
+
+		let bits = &bytes.bits::<Lsb0>()[from .. to];
+		let (l, c, r) = unsafe { bits.align_to::<u64>() };
+		assert_eq!(l.len() + c.len() + r.len(), len);
+		assert_eq!(l, &bits[.. l.len()]);
+		assert_eq!(r, &bits[len - r.len() ..]);
+		let start = c.as_slice().as_ptr() as usize;
+		for (n, chunk) in c.chunks(64).enumerate() {
+			let addr = start - bytes.as_ptr() as usize + 8 * n;
+			assert_eq!(chunk, native(addr).bits::<Lsb0>());
+		}
+		if cfg!(target_endian = "little") {
+			assert_eq!(c, &bits[l.len() .. l.len() + c.len()]);
+		}
+	}
+
+	//  Narrower elements never split the wider ones at the edges.
+	let words = [next(), next(), next()];
+	let bits = &words.bits::<Msb0>()[3 .. 150];
+	let (l, c, r) = unsafe { bits.align_to::<u8>() };
+	assert_eq!((l.len(), c.len(), r.len()), (61, 64, 22));
+}
+
+#[test]
+fn from_raw_parts() {
+	use crate::order::Lsb0;
+
+	let data = [0xA5u8, 0x3C, 0xFF, 0x00];
+	let ptr = data.as_ptr();
+	for head in 0 .. 8 {
+		for bits in 0 .. 32 - head as usize {
+			let raw =
+				unsafe { BitSlice::<Msb0, u8>::from_raw_parts(ptr, head, bits) };
+			let head = head as usize;
+			assert_eq!(raw, &data.bits::<Msb0>()[head .. head + bits]);
+		}
+	}
+
+	let mut words = [0u16; 3];
+	{
+		let raw = unsafe {
+			BitSlice::<Lsb0, u16>::from_raw_parts_mut(words.as_mut_ptr(), 12, 24)
+		};
+		assert_eq!(raw.len(), 24);
+		raw.set_all(true);
+		raw.set(23, false);
+	}
+	assert_eq!(words, [0xF000, 0xFFFF, 0x0007]);
+}
+
+#[test]
+#[should_panic(expected = "Head index 8 cannot exceed type width 8")]
+fn from_raw_parts_bad_head() {
+	let data = 0u8;
+	unsafe { BitSlice::<Msb0, u8>::from_raw_parts(&data, 8, 0) };
+}
+
+#[test]
+fn domain_shapes() {
+	use crate::{
+		access::BitAccess,
+		domain::{
+			Domain,
+			DomainMut,
+		},
+	};
+
+	let data = [0x3Cu8, 0xFF, 0xA5, 0x5A];
+	let bits = data.bits::<Msb0>();
+
+	//  Empty slices have no elements at all.
+	match bits[3 .. 3].domain() {
+		Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} => assert!(body.is_empty()),
+		_ => panic!("Empty slice must produce an empty region"),
+	}
+	assert_eq!(bits[3 .. 3].domain().iter().count(), 0);
+
+	//  Slices that touch neither edge of a single element are enclaves.
+	match bits[2 .. 6].domain() {
+		Domain::Enclave { head, elem, tail } => {
+			assert_eq!(*head, 2);
+			assert_eq!(elem.get_elem(), 0x3C);
+			assert_eq!(*tail, 6);
+		},
+		_ => panic!("Interior slice must produce an enclave"),
+	}
+
+	//  Slices that straddle one boundary have two partial edges, no body.
+	match bits[4 .. 12].domain() {
+		Domain::Region {
+			head: Some((head, first)),
+			body,
+			tail: Some((last, tail)),
+		} => {
+			assert_eq!(*head, 4);
+			assert_eq!(first.get_elem(), 0x3C);
+			assert!(body.is_empty());
+			assert_eq!(last.get_elem(), 0xFF);
+			assert_eq!(*tail, 4);
+		},
+		_ => panic!("Straddling slice must produce two partial edges"),
+	}
+
+	//  Partial edges surround any fully-spanned elements.
+	match bits[4 .. 28].domain() {
+		Domain::Region {
+			head: Some((head, _)),
+			body,
+			tail: Some((_, tail)),
+		} => {
+			assert_eq!(*head, 4);
+			assert_eq!(body, &[0xFF, 0xA5]);
+			assert_eq!(*tail, 4);
+		},
+		_ => panic!("Unaligned slice must produce two partial edges"),
+	}
+
+	//  A slice ending on an element boundary has no tail.
+	match bits[4 .. 16].domain() {
+		Domain::Region {
+			head: Some((head, _)),
+			body,
+			tail: None,
+		} => {
+			assert_eq!(*head, 4);
+			assert_eq!(body, &[0xFF]);
+		},
+		_ => panic!("Tail-aligned slice must produce only a partial head"),
+	}
+
+	//  Fully-aligned slices are all body.
+	match bits.domain() {
+		Domain::Region {
+			head: None,
+			body,
+			tail: None,
+		} => assert_eq!(body, &data),
+		_ => panic!("Aligned slice must produce only a body"),
+	}
+	assert!(bits[8 .. 24].domain().is_spanning());
+	assert!(bits[4 .. 28].domain().iter().eq(data.iter().copied()));
+
+	//  The body of a mutable domain is exclusive, and the edges must be
+	//  written through masks.
+	let mut data = [0u8; 4];
+	let bits = data.bits_mut::<Msb0>();
+	match bits[4 .. 28].domain_mut() {
+		DomainMut::Region {
+			head: Some((head, first)),
+			body,
+			tail: Some((last, tail)),
+		} => {
+			for elem in body.iter_mut() {
+				*elem = !0;
+			}
+			first.set_bits(Msb0::mask(head, None));
+			last.set_bits(Msb0::mask(None, tail));
+		},
+		_ => panic!("Unaligned slice must produce two partial edges"),
+	}
+	assert_eq!(data, [0x0F, !0, !0, 0xF0]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn recast() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	//  Checks one source slice against every destination order and store.
+	macro_rules! dest {
+		($bits:expr; $($o:ty, $t:ty);+ $(;)?) => { $(
+			let bv = $bits.recast::<$o, $t>();
+			assert!($bits.iter().eq(bv.iter()));
+			//  The unused bits of the last element are zero.
+			assert_eq!(
+				bv.as_slice()
+					.iter()
+					.map(|elem| elem.count_ones() as usize)
+					.sum::<usize>(),
+				$bits.count_ones(),
+			);
+		)+ };
+	}
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let mut data = [0 as $t; 6];
+			for elem in data.iter_mut() {
+				*elem = next() as $t;
+			}
+			let bits = data.bits::<$o>();
+			for _ in 0 .. 32 {
+				let head = (next() as usize) % (bits.len() + 1);
+				let len = (next() as usize) % (bits.len() - head + 1);
+				let bits = &bits[head ..][.. len];
+				dest!(
+					bits;
+					Lsb0, u8; Msb0, u8;
+					Lsb0, u16; Msb0, u16;
+					Lsb0, u32; Msb0, u32;
+				);
+				#[cfg(target_pointer_width = "64")]
+				{
+					dest!(bits; Lsb0, u64; Msb0, u64);
+				}
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+
+	let bits = &0x3Cu8.bits::<Msb0>()[2 .. 6];
+	assert_eq!(bits.recast::<Lsb0, u32>().as_slice(), &[0x0F]);
+	assert!(bits[.. 0].recast::<Lsb0, u32>().as_slice().is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn reverse() {
+	use crate::order::Lsb0;
+
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let mut data = [0 as $t; 8];
+			for elem in data.iter_mut() {
+				*elem = next() as $t;
+			}
+			let total = data.bits::<$o>().len();
+			for _ in 0 .. 64 {
+				let head = (next() as usize) % (total + 1);
+				let len = (next() as usize) % (total - head + 1);
+				let orig = data;
+				let mut expected = orig.bits::<$o>().to_bool_vec();
+				expected[head .. head + len].reverse();
+
+				data.bits_mut::<$o>()[head .. head + len].reverse();
+				//  The bits outside the slice are untouched.
+				assert!(data.bits::<$o>().iter().copied().eq(expected));
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8; Local, u8;
+		Lsb0, u16; Msb0, u16; Local, u16;
+		Lsb0, u32; Msb0, u32; Local, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64; Local, u64);
+}
@@ -0,0 +1,97 @@
+/*! Shared support for the unit tests.
+!*/
+
+use crate::{
+	order::BitOrder,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+};
+
+/** Produces a fixed stream of pseudo-random numbers.
+
+The randomized tests draw their inputs from an xorshift generator with a
+constant seed, so that every run exercises the same cases, and a failure can be
+reproduced.
+
+# Returns
+
+A closure that yields the next number in the stream each time it is called.
+**/
+pub(crate) fn xorshift() -> impl FnMut() -> u64 {
+	let mut state = 0x2545_F491_4F6C_DD1Du64;
+	move || {
+		state ^= state << 13;
+		state ^= state >> 7;
+		state ^= state << 17;
+		state
+	}
+}
+
+/** Runs a mutation over a table of ranges in a 256-bit buffer.
+
+The ranges cover an empty span, a span inside one element, a span ending at an
+element edge, and spans covering whole elements with partial edges on either
+side. Each run starts from a fresh copy of `init`, and after the mutation the
+bits outside the range are checked to be untouched.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering used to view the buffer.
+- `A`: A buffer of at least 256 bits.
+- `F`: The mutation under test. It receives the range of the buffer being
+  mutated, the same range of the original buffer, and the index of the start of
+  the range.
+**/
+pub(crate) fn check_ranges<O, A, F>(init: A, mut mutate: F)
+where
+	O: BitOrder,
+	A: AsBits + Copy,
+	F: FnMut(&mut BitSlice<O, A::Store>, &BitSlice<O, A::Store>, usize),
+{
+	let ranges = [(0, 0), (3, 9), (17, 64), (0, 64), (5, 200), (64, 256)];
+	for &(from, to) in ranges.iter() {
+		let mut data = init;
+		mutate(
+			&mut data.bits_mut::<O>()[from .. to],
+			&init.bits::<O>()[from .. to],
+			from,
+		);
+		let (bits, orig) = (data.bits::<O>(), init.bits::<O>());
+		assert_eq!(bits[.. from], orig[.. from]);
+		assert_eq!(bits[to ..], orig[to ..]);
+	}
+}
+
+/// An irregular bit-pattern, three `u16` elements long, for the `BitVec` tests.
+static PATTERN: [u16; 3] = [0x96A5, 0x3C69, 0xF00F];
+
+/** Views the shared test pattern as a bit-slice.
+
+# Type Parameters
+
+- `O: 'static + BitOrder`: The ordering used to view the pattern.
+**/
+pub(crate) fn pattern<O>() -> &'static BitSlice<O, u16>
+where O: 'static + BitOrder {
+	PATTERN.bits::<O>()
+}
+
+/** Sweeps every head index through the shared test pattern.
+
+# Type Parameters
+
+- `O: 'static + BitOrder`: The ordering used to view the pattern.
+
+# Returns
+
+An iterator of each head index in a `u16`, paired with the pattern starting at
+that index.
+**/
+pub(crate) fn heads<O>()
+-> impl Iterator<Item = (usize, &'static BitSlice<O, u16>)>
+where O: 'static + BitOrder {
+	let bits = pattern::<O>();
+	(0 .. 16).map(move |head| (head, &bits[head ..]))
+}
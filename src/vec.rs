@@ -12,27 +12,58 @@ slice and vector types.
 use crate::{
 	access::BitAccess,
 	boxed::BitBox,
-	index::Indexable,
-	mem::BitMemory,
+	domain::{
+		Domain,
+		DomainMut,
+	},
+	index::{
+		BitIdx,
+		BitMask,
+		Indexable,
+	},
+	mem::{
+		and_not,
+		BitMemory,
+	},
 	order::{
 		BitOrder,
 		Local,
+		Msb0,
 	},
 	pointer::BitPtr,
-	slice::BitSlice,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
 	store::BitStore,
 };
 
-use alloc::vec::Vec;
+use alloc::{
+	borrow::Borrow,
+	vec::Vec,
+};
 
 use core::{
 	marker::PhantomData,
 	mem,
+	ops::{
+		BitAnd,
+		BitOr,
+		BitXor,
+	},
 	ptr,
+	slice,
+	sync::atomic,
 };
 
 use funty::IsInteger;
 
+#[cfg(feature = "std")]
+use std::io::{
+	self,
+	Read,
+};
+
 /** A compact [`Vec`] of bits, whose order and storage type can be customized.
 
 `BitVec` is a newtype wrapper over `Vec`, and as such is exactly three words in
@@ -434,14 +465,39 @@ where
 	/// [`::force_align`]: #method.force_align
 	pub fn from_bitslice(slice: &BitSlice<O, T>) -> Self {
 		let mut pointer = slice.bitptr();
-		let source = pointer.as_access_slice();
+		let elts = pointer.elements();
 
 		//  Create a blank buffer into which the source will be copied.
-		let mut v = Vec::with_capacity(source.len());
+		let mut v = Vec::<T::Mem>::with_capacity(elts);
 
-		//  Copy the source into the buffer. This must be done per-element, so
-		//  that atomic systems will correctly synchronize.
-		source.iter().for_each(|elt| v.push(elt.load()));
+		/* Copy the source into the buffer. The edge elements may be aliased,
+		and must be read with a single synchronized load each; the interior
+		elements are unaliased, and can be copied as a block.
+		*/
+		if elts > 0 {
+			unsafe {
+				let mut dst = v.as_mut_ptr();
+				match slice.domain() {
+					Domain::Enclave { elem, .. } => dst.write(elem.load()),
+					Domain::Region { head, body, tail } => {
+						if let Some((_, elem)) = head {
+							dst.write(elem.load());
+							dst = dst.add(1);
+						}
+						ptr::copy_nonoverlapping(
+							body.as_ptr() as *const T::Mem,
+							dst,
+							body.len(),
+						);
+						dst = dst.add(body.len());
+						if let Some((elem, _)) = tail {
+							dst.write(elem.load());
+						}
+					},
+				}
+				v.set_len(elts);
+			}
+		}
 
 		/* Target the copied pointer to the buffer’s region, preserving its
 		length and offset information. This enables `BitVec` to efficiently
@@ -460,6 +516,132 @@ where
 		}
 	}
 
+	/// Constructs a vector from the low `width` bits of an unsigned integer,
+	/// with the first bit most significant.
+	///
+	/// This is the inverse of [`BitSlice::to_uint`]. The bit at index `0` is
+	/// bit `width - 1` of `value`, and the last bit is its least significant
+	/// bit. A `width` of zero produces an empty vector. A `width` greater than
+	/// the bit width of `U` zero-extends `value`, so the excess leading bits
+	/// are all clear.
+	///
+	/// # Parameters
+	///
+	/// - `value`: The integer to read.
+	/// - `width`: The number of bits in the produced vector.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any fundamental unsigned integer. It need not be the same type as
+	///   the vector’s storage element.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// assert_eq!(BitVec::<Msb0, u8>::from_uint(0b1011u8, 4), bits![1, 0, 1, 1]);
+	/// assert_eq!(
+	///   BitVec::<Lsb0, u16>::from_uint(0b11u8, 10),
+	///   bits![0, 0, 0, 0, 0, 0, 0, 0, 1, 1],
+	/// );
+	/// assert!(BitVec::<Msb0, u8>::from_uint(!0u8, 0).is_empty());
+	/// ```
+	///
+	/// [`BitSlice::to_uint`]: ../slice/struct.BitSlice.html#method.to_uint
+	pub fn from_uint<U>(value: U, width: usize) -> Self
+	where U: BitMemory {
+		let mut out = Self::repeat(false, width);
+		out.set_uint(value);
+		out
+	}
+
+	/// Decodes a vector from `(value, length)` runs, as produced by
+	/// [`BitSlice::to_runs`].
+	///
+	/// Each run is appended with [`resize`], which fills whole elements at a
+	/// time. Runs may be empty, and adjacent runs may have the same value.
+	///
+	/// # Parameters
+	///
+	/// - `runs`: A sequence of bit values and the number of times to repeat
+	///   each one.
+	///
+	/// # Returns
+	///
+	/// A vector holding each run in order.
+	///
+	/// # Panics
+	///
+	/// This panics if the total length of the runs exceeds
+	/// `BitPtr::<T>::MAX_BITS`. The runs are not truncated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let runs = vec![(true, 2), (false, 3), (true, 1)];
+	/// let bv = BitVec::<Msb0, u8>::from_runs(runs.iter().copied());
+	/// assert_eq!(bv, bits![1, 1, 0, 0, 0, 1]);
+	/// assert_eq!(bv.to_runs(), runs);
+	/// ```
+	///
+	/// [`BitSlice::to_runs`]: ../slice/struct.BitSlice.html#method.to_runs
+	/// [`resize`]: #method.resize
+	pub fn from_runs<I>(runs: I) -> Self
+	where I: IntoIterator<Item = (bool, usize)> {
+		let mut out = Self::new();
+		for (value, count) in runs {
+			let len = out.len();
+			assert!(
+				count <= BitPtr::<T>::MAX_BITS - len,
+				"Capacity overflow: {} + {} exceeds {}",
+				len,
+				count,
+				BitPtr::<T>::MAX_BITS,
+			);
+			out.resize(len + count, value);
+		}
+		out
+	}
+
+	/// Deserializes a vector from bytes produced by [`BitSlice::to_bytes`].
+	///
+	/// The first `len` bits of `bytes` are read in sequence, most significant
+	/// bit first, and stored under the vector’s own order and storage types.
+	/// Any bits after the first `len` are ignored.
+	///
+	/// # Parameters
+	///
+	/// - `bytes`: A packed, most significant bit first, sequence of bits.
+	/// - `len`: The number of bits to read from `bytes`.
+	///
+	/// # Returns
+	///
+	/// A vector of `len` bits equal to the front of `bytes`, or `None` if
+	/// `bytes` holds fewer than `len` bits.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = BitVec::<Lsb0, u16>::from_bytes(&[0xA5, 0xC0], 10).unwrap();
+	/// assert_eq!(bv, bits![1, 0, 1, 0, 0, 1, 0, 1, 1, 1]);
+	/// assert_eq!(bv.to_bytes(), vec![0xA5, 0xC0]);
+	///
+	/// assert!(BitVec::<Lsb0, u16>::from_bytes(&[0xA5], 10).is_none());
+	/// ```
+	///
+	/// [`BitSlice::to_bytes`]: ../slice/struct.BitSlice.html#method.to_bytes
+	pub fn from_bytes(bytes: &[u8], len: usize) -> Option<Self> {
+		let bits = bytes.bits::<Msb0>();
+		if len > bits.len() {
+			return None;
+		}
+		let mut out = Self::with_capacity(len);
+		out.extend(bits[.. len].iter().copied());
+		Some(out)
+	}
+
 	/// Converts a frozen `BitBox` allocation into a growable `BitVec`.
 	///
 	/// This does not copy or reallocate.
@@ -484,31 +666,49 @@ where
 	pub fn from_boxed_bitslice(slice: BitBox<O, T>) -> Self {
 		let bitptr = slice.bitptr();
 		mem::forget(slice);
-		unsafe { Self::from_raw_parts(bitptr, bitptr.elements()) }
+		Self {
+			_order: PhantomData,
+			pointer: bitptr,
+			capacity: bitptr.elements(),
+		}
 	}
 
 	/// Creates a new `BitVec<O, T>` directly from the raw parts of another.
 	///
+	/// This is the inverse of [`into_raw_parts`].
+	///
 	/// # Parameters
 	///
-	/// - `pointer`: The `BitPtr<T>` to use.
+	/// - `pointer`: The address of the first element in the allocation.
+	/// - `head`: The index, according to `O`, of the first live bit in the
+	///   element under `*pointer`.
+	/// - `bits`: The number of live bits in the vector.
 	/// - `capacity`: The number of `T` elements *allocated* in that slab.
 	///
 	/// # Returns
 	///
 	/// A `BitVec` over the given slab of memory.
 	///
+	/// # Panics
+	///
+	/// In debug builds, this panics if `pointer` is null or misaligned, if
+	/// `head` is not a valid index into a `T` element, or if `head` and `bits`
+	/// describe more elements than `capacity`. Release builds do not check
+	/// these conditions.
+	///
 	/// # Safety
 	///
 	/// This is ***highly*** unsafe, due to the number of invariants that aren’t
 	/// checked:
 	///
-	/// - `pointer` needs to have been previously allocated by some allocating
-	///   type.
-	/// - `pointer`’s `T` needs to have the same size ***and alignment*** as it
-	///   was initially allocated.
-	/// - `pointer`’s element count needs to be less than or equal to the
-	///   original allocation capacity.
+	/// - `pointer` needs to have been previously allocated by `Vec<T>` or
+	///   `BitVec<_, T>`.
+	/// - `T` needs to have the same size ***and alignment*** as it was
+	///   initially allocated.
+	/// - `head` must be less than the bit width of `T`.
+	/// - The number of elements spanned by `head` and `bits` needs to be less
+	///   than or equal to `capacity`, and every one of them must be
+	///   initialized.
 	/// - `capacity` needs to be the original allocation capacity for the
 	///   vector. This is *not* the value produced by `.capacity()`.
 	///
@@ -522,8 +722,52 @@ where
 	/// `BitVec<O, T>` which may then deallocate, reallocate, or modify the
 	/// contents of the referent slice at will. Ensure that nothing else uses
 	/// the pointer after calling this function.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = BitVec::from_bitslice(&0x3Cu8.bits::<Msb0>()[2 ..]);
+	/// let (pointer, head, bits, capacity) = bv.into_raw_parts();
+	/// assert_eq!((head, bits), (2, 6));
+	///
+	/// let bv = unsafe {
+	///   BitVec::<Msb0, u8>::from_raw_parts(pointer, head, bits, capacity)
+	/// };
+	/// assert_eq!(bv, 0x3Cu8.bits::<Msb0>()[2 ..]);
+	/// ```
+	///
+	/// [`into_raw_parts`]: #method.into_raw_parts
 	#[inline]
-	pub unsafe fn from_raw_parts(pointer: BitPtr<T>, capacity: usize) -> Self {
+	pub unsafe fn from_raw_parts(
+		pointer: *mut T,
+		head: usize,
+		bits: usize,
+		capacity: usize,
+	) -> Self
+	{
+		debug_assert!(!pointer.is_null(), "BitVec pointer cannot be null");
+		debug_assert!(
+			head < T::Mem::BITS as usize,
+			"Head index {} out of bounds: {}",
+			head,
+			T::Mem::BITS,
+		);
+		let head = (head as u8).idx();
+		debug_assert!(
+			head.span(bits).0 <= capacity,
+			"Capacity overflow: {} bits from head {} exceed {} elements",
+			bits,
+			*head,
+			capacity,
+		);
+		let pointer = if cfg!(debug_assertions) {
+			BitPtr::new(pointer, head, bits)
+		}
+		else {
+			BitPtr::new_unchecked(pointer, head, bits)
+		};
 		Self {
 			_order: PhantomData,
 			pointer,
@@ -531,6 +775,56 @@ where
 		}
 	}
 
+	/// Decomposes a `BitVec<O, T>` into its raw components.
+	///
+	/// Returns the raw pointer to the underlying allocation, the index of the
+	/// first live bit in the first element, the length of the vector in bits,
+	/// and the allocated capacity of the vector in *elements*. These are the
+	/// same arguments in the same order as the arguments to
+	/// [`from_raw_parts`].
+	///
+	/// After calling this function, the caller is responsible for the memory
+	/// previously managed by the `BitVec`. The only way to do this is to
+	/// convert the raw pointer, head, length, and capacity back into a `BitVec`
+	/// with the [`from_raw_parts`] function, allowing the destructor to
+	/// perform the cleanup.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// - `.0`: The address of the first element in the allocation. This is
+	///   never null.
+	/// - `.1`: The index, according to `O`, of the first live bit.
+	/// - `.2`: The number of live bits.
+	/// - `.3`: The number of `T` elements allocated.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![Lsb0, u16; 1; 20];
+	/// let (pointer, head, bits, capacity) = bv.into_raw_parts();
+	/// assert_eq!((head, bits), (0, 20));
+	/// assert!(capacity >= 2);
+	///
+	/// let bv = unsafe {
+	///   BitVec::<Lsb0, u16>::from_raw_parts(pointer, head, bits, capacity)
+	/// };
+	/// assert!(bv.all());
+	/// ```
+	///
+	/// [`from_raw_parts`]: #method.from_raw_parts
+	pub fn into_raw_parts(self) -> (*mut T, usize, usize, usize) {
+		let (addr, head, bits) = self.pointer.raw_parts();
+		let out = (addr.w(), *head as usize, bits, self.capacity);
+		mem::forget(self);
+		out
+	}
+
 	/// Produces a `BitSlice` containing the entire vector.
 	///
 	/// Equivalent to `&s[..]`.
@@ -581,6 +875,64 @@ where
 		self.pointer.into_bitslice_mut()
 	}
 
+	/// Views the vector’s live elements as raw bytes.
+	///
+	/// This is [`as_slice`], reinterpreted as `u8`: it includes the partial
+	/// edge elements, and its length is the number of live elements times the
+	/// byte width of `T`. The bytes of each element appear in the target’s
+	/// native byte order, so a `u16`, `u32`, or `u64` store produces different
+	/// byte sequences on little- and big-endian targets. Only a `u8` store has
+	/// the same view on every target.
+	///
+	/// Dead bits in the edge elements are included, and have unspecified
+	/// values.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = BitVec::<Msb0, u16>::from_slice(&[0x1234]);
+	/// assert_eq!(bv.as_raw_bytes(), &0x1234u16.to_ne_bytes());
+	/// ```
+	///
+	/// [`as_slice`]: #method.as_slice
+	pub fn as_raw_bytes(&self) -> &[u8] {
+		let elts = self.as_slice();
+		unsafe {
+			slice::from_raw_parts(
+				elts.as_ptr() as *const u8,
+				elts.len() * mem::size_of::<T>(),
+			)
+		}
+	}
+
+	/// Views the vector’s live elements as mutable raw bytes.
+	///
+	/// See [`as_raw_bytes`] for the layout of the view. Writes to the dead bits
+	/// of the edge elements are permitted, and are not observed by the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u32; 0; 40];
+	/// for byte in bv.as_raw_bytes_mut() {
+	///     *byte = !0;
+	/// }
+	/// assert!(bv.all());
+	/// ```
+	///
+	/// [`as_raw_bytes`]: #method.as_raw_bytes
+	pub fn as_raw_bytes_mut(&mut self) -> &mut [u8] {
+		let elts = self.as_mut_slice();
+		unsafe {
+			slice::from_raw_parts_mut(
+				elts.as_mut_ptr() as *mut u8,
+				elts.len() * mem::size_of::<T>(),
+			)
+		}
+	}
+
 	/// Sets the backing storage to the provided element.
 	///
 	/// This unconditionally sets each live element in the backing buffer to the
@@ -618,6 +970,197 @@ where
 			.for_each(|elt| *elt = unsafe { ptr::read(&element) });
 	}
 
+	/// Sets every dead bit in the allocation to a value, leaving all live bits
+	/// unchanged.
+	///
+	/// The dead bits are those in the first element before the head index, in
+	/// the last element after the tail index, and in every element between the
+	/// end of the live region and the capacity. Their values are otherwise
+	/// unspecified, so this produces a deterministic view of the raw memory,
+	/// such as for hashing or checksumming [`as_slice`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit value to which every dead bit will be set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1; 10];
+	/// bv.set_uninitialized(false);
+	/// assert_eq!(bv.as_slice(), &[0xFF, 0xC0]);
+	/// bv.set_uninitialized(true);
+	/// assert_eq!(bv.as_slice(), &[0xFF, 0xFF]);
+	/// assert!(bv.all());
+	/// ```
+	///
+	/// [`as_slice`]: #method.as_slice
+	pub fn set_uninitialized(&mut self, value: bool) {
+		let fill = if value { T::Mem::ALL } else { T::Mem::ZERO };
+		let head = self.pointer.head();
+		let tail = self.pointer.tail();
+		let elts = self.pointer.elements();
+
+		//  Merge `fill` into the dead bits of the edge elements,
+		{
+			let slice = self.as_mut_slice();
+			let merge = |elem: &mut T, live: BitMask<T::Mem>| {
+				elem.set_elem(*(live & elem.get_elem() | *(!live & fill)));
+			};
+			match elts {
+				0 => {},
+				1 => merge(&mut slice[0], O::mask(head, tail)),
+				n => {
+					merge(&mut slice[0], O::mask(head, None));
+					merge(&mut slice[n - 1], O::mask(None, tail));
+				},
+			}
+		}
+
+		//  And fill the wholly-dead elements in the spare capacity.
+		let base = self.pointer.pointer().w();
+		for n in elts .. self.capacity {
+			unsafe {
+				base.add(n).write(fill.into());
+			}
+		}
+	}
+
+	/// Shortens the vector to `len` bits, overwriting the removed bits with
+	/// zero.
+	///
+	/// [`truncate`] only changes the length of the vector, and leaves the
+	/// removed bits in memory. This method clears each removed bit with
+	/// volatile writes, which the optimizer cannot elide, before shortening
+	/// the vector. Live bits that share an element with removed bits are not
+	/// disturbed.
+	///
+	/// If `len` is greater than or equal to the vector’s current length, this
+	/// has no effect.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `len`: The new length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1; 12];
+	/// bv.truncate_zeroed(6);
+	/// assert_eq!(bv, bitvec![1; 6]);
+	/// assert_eq!(bv.as_slice(), &[0xFC]);
+	/// ```
+	///
+	/// [`truncate`]: #method.truncate
+	pub fn truncate_zeroed(&mut self, len: usize) {
+		if len < self.len() {
+			self.erase_from(len);
+			unsafe { self.set_len(len) }
+		}
+	}
+
+	/// Clears the vector, overwriting every removed bit with zero.
+	///
+	/// This is [`clear`], with the erasure behavior of [`truncate_zeroed`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1; 12];
+	/// let ptr = bv.as_ptr();
+	/// bv.clear_zeroed();
+	/// assert!(bv.is_empty());
+	/// assert_eq!(unsafe { *ptr }, 0);
+	/// ```
+	///
+	/// [`clear`]: #method.clear
+	/// [`truncate_zeroed`]: #method.truncate_zeroed
+	pub fn clear_zeroed(&mut self) {
+		self.truncate_zeroed(0);
+	}
+
+	/// Removes the last bit from the vector and returns it, or `None` if it is
+	/// empty, overwriting the removed bit with zero.
+	///
+	/// This is [`pop`], with the erasure behavior of [`truncate_zeroed`].
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1; 8];
+	/// assert_eq!(bv.pop_zeroed(), Some(true));
+	/// assert_eq!(bv.as_slice(), &[0xFE]);
+	/// ```
+	///
+	/// [`pop`]: #method.pop
+	/// [`truncate_zeroed`]: #method.truncate_zeroed
+	pub fn pop_zeroed(&mut self) -> Option<bool> {
+		let len = self.len();
+		if len == 0 {
+			return None;
+		}
+		let bit = self[len - 1];
+		self.truncate_zeroed(len - 1);
+		Some(bit)
+	}
+
+	/// Clears all live bits from `from` to the end of the vector, using
+	/// volatile writes.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `from`: The index of the first bit to clear.
+	fn erase_from(&mut self, from: usize) {
+		/* The vector has exclusive ownership of its buffer, so the edge
+		elements can be accessed through raw pointers despite the alias marker.
+		*/
+		let erase = |elem: *mut T::Mem, mask: BitMask<T::Mem>| unsafe {
+			let val = ptr::read_volatile(elem);
+			ptr::write_volatile(elem, *(!mask & val));
+		};
+		match self[from ..].domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => erase(
+				elem as *const T::Alias as *mut T::Mem,
+				O::mask(head, tail),
+			),
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					erase(
+						elem as *const T::Alias as *mut T::Mem,
+						O::mask(head, None),
+					);
+				}
+				for elem in body {
+					unsafe {
+						ptr::write_volatile(
+							elem as *mut T::NoAlias as *mut T::Mem,
+							T::Mem::ZERO,
+						);
+					}
+				}
+				if let Some((elem, tail)) = tail {
+					erase(
+						elem as *const T::Alias as *mut T::Mem,
+						O::mask(None, tail),
+					);
+				}
+			},
+		}
+		//  Keep the stores from being reördered past the length change.
+		atomic::compiler_fence(atomic::Ordering::SeqCst);
+	}
+
 	/// Changes the order type on the vector handle, without changing its
 	/// contents.
 	///
@@ -630,13 +1173,74 @@ where
 	/// An equivalent vector handle with a new order type. The contents of the
 	/// backing storage are unchanged.
 	///
-	/// To reorder the bits in memory, drain this vector into a new handle with
-	/// the desired order type.
+	/// Because the memory is reinterpreted under the new order, every index in
+	/// the new vector may observe a different bit than it did in `self`. To
+	/// keep the sequence of bits and rewrite the memory instead, use
+	/// [`change_order_reordered`].
+	///
+	/// [`change_order_reordered`]: #method.change_order_reordered
 	pub fn change_order<P>(self) -> BitVec<P, T>
 	where P: BitOrder {
-		let (bp, cap) = (self.pointer, self.capacity);
+		let (pointer, capacity) = (self.pointer, self.capacity);
 		mem::forget(self);
-		unsafe { BitVec::from_raw_parts(bp, cap) }
+		BitVec {
+			_order: PhantomData,
+			pointer,
+			capacity,
+		}
+	}
+
+	/// Changes the order type on the vector handle, and rewrites the backing
+	/// storage so that the sequence of bits is unchanged.
+	///
+	/// Where [`change_order`] keeps the memory and changes the bits observed at
+	/// each index, this keeps the bit observed at each index and changes the
+	/// memory. Each live element is read once, has its bits moved from their
+	/// positions under `O` to their positions under `P`, and is written back in
+	/// place. The head index, length, and allocation are unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	///
+	/// # Returns
+	///
+	/// A vector handle with a new order type, whose bits are equal to those of
+	/// `self` at every index.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = bitvec![Msb0, u8; 1, 1, 0, 0, 1];
+	/// assert_eq!(bv.as_slice()[0] & 0xF8, 0b1100_1000);
+	///
+	/// let le = bv.clone().change_order_reordered::<Lsb0>();
+	/// assert_eq!(le, bv);
+	/// assert_eq!(le.as_slice()[0] & 0x1F, 0b0001_0011);
+	/// ```
+	///
+	/// [`change_order`]: #method.change_order
+	pub fn change_order_reordered<P>(self) -> BitVec<P, T>
+	where P: BitOrder {
+		let width = T::Mem::BITS;
+		//  Orderings that agree on every position need no rewrite.
+		let differs = (0 .. width)
+			.any(|n| *O::at::<T::Mem>(n.idx()) != *P::at::<T::Mem>(n.idx()));
+		if differs {
+			let base = self.pointer.pointer().w() as *mut T::Mem;
+			for n in 0 .. self.pointer.elements() {
+				unsafe {
+					let old = base.add(n).read();
+					let new = (0 .. width).fold(T::Mem::ZERO, |mut new, idx| {
+						new.set::<P>(idx.idx(), old.get::<O>(idx.idx()));
+						new
+					});
+					base.add(n).write(new);
+				}
+			}
+		}
+		self.change_order()
 	}
 
 	/// Degrades a `BitVec` to a `BitBox`, freezing its size.
@@ -683,16 +1287,15 @@ where
 	///
 	/// # Notes
 	///
-	/// This method is currently implemented as a linear traversal that moves
-	/// each bit individually from its original index to its final position.
-	/// This is `O(n)` in the bit length of the vector.
-	///
-	/// It is possible to create an optimized rotation behavior that only moves
-	/// a few bits individually, then moves elements in a gallop. The speed
-	/// difference is proportional to the width of the element type.
+	/// The distance moved is always less than one element, so under the
+	/// [`Lsb0`] and [`Msb0`] orderings, each element is rebuilt from itself and
+	/// its successor with a pair of shifts. This is `O(n)` in the element
+	/// length of the vector.
 	///
-	/// When this behavior is implemented, `force_align` will be rewritten to
-	/// take advantage of it. For now, it remains slow.
+	/// Other orderings do not guarantee that adjacent indices are adjacent
+	/// positions within an element, so they fall back to a linear traversal
+	/// that moves each bit individually from its original index to its final
+	/// position.
 	///
 	/// # Examples
 	///
@@ -707,12 +1310,48 @@ where
 	/// bv.force_align();
 	/// assert_eq!(bv.as_slice()[0], 0x6E);
 	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
 	pub fn force_align(&mut self) {
-		let (_, head, bits) = self.pointer.raw_parts();
-		let head = *head as usize;
-		if head == 0 {
+		let (addr, head, bits) = self.pointer.raw_parts();
+		if *head == 0 {
 			return;
 		}
+		if let Some(lsb0) = BitSlice::<O, T>::linear_order() {
+			let elts = self.pointer.elements();
+			let width = T::Mem::BITS as usize;
+			let base = addr.w() as *mut T::Mem;
+			unsafe {
+				for n in 0 .. elts {
+					let live = bits.saturating_sub(n * width);
+					if live == 0 {
+						break;
+					}
+					let cur = base.add(n).read();
+					//  The element after the last is never read: its bits are
+					//  dead, and zeroes are shifted in instead.
+					let next = if n + 1 < elts {
+						base.add(n + 1).read()
+					}
+					else {
+						T::Mem::ZERO
+					};
+					let new = crate::mem::funnel(cur, next, *head, lsb0);
+					//  Dead bits after the live region keep their old values.
+					if live < width {
+						let mask = O::mask(None, (live as u8).tail());
+						base.add(n).write(*(mask & new | *(!mask & cur)));
+					}
+					else {
+						base.add(n).write(new);
+					}
+				}
+				self.pointer.set_head(BitIdx::ZERO);
+			}
+			return;
+		}
+		let head = *head as usize;
 		let tail = head + bits;
 		unsafe {
 			self.pointer.set_head(0.idx());
@@ -724,6 +1363,236 @@ where
 		}
 	}
 
+	/// Subtracts a stream of bits from the vector, as unsigned integers with
+	/// the first bit least significant.
+	///
+	/// This is the consuming form of [`BitSlice::sub_assign_reverse`], and
+	/// follows the same wrapping policy: the difference keeps the vector’s
+	/// length, and the final borrow is returned alongside it.
+	///
+	/// # Parameters
+	///
+	/// - `self`
+	/// - `subtrahend`: A stream of bits, least significant first.
+	///
+	/// # Returns
+	///
+	/// The difference, and whether the subtraction underflowed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![Lsb0, u8; 0, 0, 1];
+	/// let (diff, borrow) = bv.sub_reverse(bits![1].iter().copied());
+	/// assert_eq!(diff, bits![1, 1, 0]);
+	/// assert!(!borrow);
+	/// ```
+	///
+	/// [`BitSlice::sub_assign_reverse`]:
+	/// ../slice/struct.BitSlice.html#method.sub_assign_reverse
+	pub fn sub_reverse<I>(mut self, subtrahend: I) -> (Self, bool)
+	where I: IntoIterator<Item = bool> {
+		let borrow = self.sub_assign_reverse(subtrahend);
+		(self, borrow)
+	}
+
+	/// Negates the vector as a two’s-complement integer with the first bit
+	/// least significant.
+	///
+	/// This is the consuming form of [`BitSlice::negate_in_place`], and wraps
+	/// within the vector’s length in the same way.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let bv = bitvec![Lsb0, u8; 1, 1, 0, 0];
+	/// assert_eq!(bv.negate(), bits![1, 0, 1, 1]);
+	/// ```
+	///
+	/// [`BitSlice::negate_in_place`]:
+	/// ../slice/struct.BitSlice.html#method.negate_in_place
+	pub fn negate(mut self) -> Self {
+		self.negate_in_place();
+		self
+	}
+
+	/// `AND`s another slice into the vector, truncating the vector to the
+	/// shorter of the two lengths.
+	///
+	/// This is the behavior of `&=` on a vector: the bits of `self` past the
+	/// end of `other` are removed, rather than cleared.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The slice to `AND` into `self`. It may have any order and
+	///   storage types.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 1, 0, 1, 1];
+	/// bv.and_assign_truncate(bits![Lsb0, u16; 0, 1, 1]);
+	/// assert_eq!(bv, bits![0, 1, 0]);
+	/// ```
+	pub fn and_assign_truncate<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.truncate(other.len());
+		self.combine_with(other, BitAnd::bitand);
+	}
+
+	/// `OR`s another slice into the vector, extending the vector to the longer
+	/// of the two lengths.
+	///
+	/// If `other` is longer, the vector is first grown with zeros, so that it
+	/// receives a copy of the excess bits of `other`. If `other` is shorter,
+	/// the bits of `self` past its end are unchanged.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: The slice to `OR` into `self`. It may have any order and
+	///   storage types.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut bv = bitvec![Msb0, u8; 1, 0];
+	/// bv.or_assign_extend(bits![Lsb0, u16; 0, 1, 0, 1]);
+	/// assert_eq!(bv, bits![1, 1, 0, 1]);
+	/// ```
+	pub fn or_assign_extend<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.set_op_with(other, BitOr::bitor);
+	}
+
+	/// Replaces the vector with its union with another set.
+	///
+	/// This is the in-place form of [`BitSlice::union`]. The vector grows with
+	/// zeros to the length of `other`, if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set = bitvec![Msb0, u8; 0, 1];
+	/// set.union_with(bits![0, 0, 0, 1]);
+	/// assert_eq!(set, bits![0, 1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::union`]: ../slice/struct.BitSlice.html#method.union
+	pub fn union_with<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.set_op_with(other, BitOr::bitor);
+	}
+
+	/// Replaces the vector with its intersection with another set.
+	///
+	/// This is the in-place form of [`BitSlice::intersection`]. The vector
+	/// grows with zeros to the length of `other`, if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set = bitvec![Msb0, u8; 0, 1, 1];
+	/// set.intersection_with(bits![1, 1]);
+	/// assert_eq!(set, bits![0, 1, 0]);
+	/// ```
+	///
+	/// [`BitSlice::intersection`]:
+	/// ../slice/struct.BitSlice.html#method.intersection
+	pub fn intersection_with<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.set_op_with(other, BitAnd::bitand);
+	}
+
+	/// Removes the members of another set from the vector.
+	///
+	/// This is the in-place form of [`BitSlice::difference`]. The vector grows
+	/// with zeros to the length of `other`, if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set = bitvec![Msb0, u8; 1, 1, 1];
+	/// set.difference_with(bits![0, 1]);
+	/// assert_eq!(set, bits![1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::difference`]:
+	/// ../slice/struct.BitSlice.html#method.difference
+	pub fn difference_with<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.set_op_with(other, and_not);
+	}
+
+	/// Replaces the vector with its symmetric difference with another set.
+	///
+	/// This is the in-place form of [`BitSlice::symmetric_difference`]. The
+	/// vector grows with zeros to the length of `other`, if it is shorter.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let mut set = bitvec![Msb0, u8; 1, 1];
+	/// set.symmetric_difference_with(bits![0, 1, 1]);
+	/// assert_eq!(set, bits![1, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::symmetric_difference`]:
+	/// ../slice/struct.BitSlice.html#method.symmetric_difference
+	pub fn symmetric_difference_with<D, U>(&mut self, other: &BitSlice<D, U>)
+	where
+		D: BitOrder,
+		U: BitStore,
+	{
+		self.set_op_with(other, BitXor::bitxor);
+	}
+
+	/// Grows the vector to at least the length of `other`, then combines each
+	/// of its elements with the bits of `other`.
+	fn set_op_with<D, U, F>(&mut self, other: &BitSlice<D, U>, func: F)
+	where
+		D: BitOrder,
+		U: BitStore,
+		F: Fn(T::Mem, T::Mem) -> T::Mem,
+	{
+		if other.len() > self.len() {
+			self.resize(other.len(), false);
+		}
+		self.combine_with(other, func);
+	}
+
 	/// Permits a function to modify the `Vec<T>` underneath a `BitVec<_, T>`.
 	///
 	/// This produces a `Vec<T>` structure referring to the same data region as
@@ -762,6 +1631,253 @@ where
 	}
 }
 
+#[cfg(feature = "std")]
+impl<O> BitVec<O, u8>
+where O: BitOrder
+{
+	/// Reads all bytes from a reader into a new vector.
+	///
+	/// This is equivalent to [`read_from`] on an empty vector.
+	///
+	/// # Parameters
+	///
+	/// - `reader`: A source of bytes, which is read until it is exhausted.
+	///
+	/// # Returns
+	///
+	/// A vector holding every byte produced by `reader`, eight bits at a time,
+	/// or the first error that `reader` reported.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = BitVec::<Msb0, u8>::from_reader(&[0xA5u8, 0x0F][..]).unwrap();
+	/// assert_eq!(bv.as_slice(), &[0xA5, 0x0F]);
+	/// ```
+	///
+	/// [`read_from`]: #method.read_from
+	pub fn from_reader<R>(reader: R) -> io::Result<Self>
+	where R: Read {
+		let mut out = Self::new();
+		out.read_from(reader)?;
+		Ok(out)
+	}
+
+	/// Reads all bytes from a reader, appending them to the vector.
+	///
+	/// When the vector ends at an element boundary, the reader writes directly
+	/// into the vector’s spare capacity, which grows as needed, so the bytes
+	/// are never held in a second buffer. When it does not, each chunk is read
+	/// into a small stack buffer and then appended as by `io::Write`.
+	///
+	/// Like `Read::read_to_end`, this retries reads that fail with
+	/// `io::ErrorKind::Interrupted`, and stops at the first read that returns
+	/// no bytes. Any other error is returned, and the bytes read before it
+	/// remain in the vector.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `reader`: A source of bytes, which is read until it is exhausted.
+	///
+	/// # Returns
+	///
+	/// The number of bytes appended to the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u8; 1, 1];
+	/// assert_eq!(bv.read_from(&[0x00u8][..]).unwrap(), 1);
+	/// assert_eq!(bv, bits![1, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+	/// ```
+	pub fn read_from<R>(&mut self, mut reader: R) -> io::Result<usize>
+	where R: Read {
+		let start = self.len();
+		//  Whole bytes never change whether the vector ends on a boundary.
+		let aligned = (*self.pointer.head() as usize + start) % 8 == 0;
+		//  The number of spare elements, after the live ones, that are zeroed.
+		let mut zeroed = 0;
+		let mut stack = [0u8; 64];
+		loop {
+			let read = if aligned {
+				let elts = self.pointer.elements();
+				if elts == self.capacity {
+					self.try_reserve(32 * 8).map_err(|err| {
+						io::Error::new(io::ErrorKind::Other, err)
+					})?;
+				}
+				let spare = self.capacity - self.pointer.elements();
+				let buf = unsafe {
+					let base = self.pointer.pointer().w().add(elts);
+					if zeroed < spare {
+						ptr::write_bytes(base.add(zeroed), 0, spare - zeroed);
+						zeroed = spare;
+					}
+					slice::from_raw_parts_mut(base, spare)
+				};
+				reader.read(buf)
+			}
+			else {
+				reader.read(&mut stack)
+			};
+			match read {
+				Ok(0) => break,
+				Ok(n) if aligned => unsafe {
+					zeroed -= n;
+					let len = self.len();
+					self.set_len(len + n * 8);
+				},
+				Ok(n) => {
+					self.try_reserve(n * 8).map_err(|err| {
+						io::Error::new(io::ErrorKind::Other, err)
+					})?;
+					self.extend_from_bitslice(stack[.. n].bits());
+				},
+				Err(ref err) if err.kind() == io::ErrorKind::Interrupted => {},
+				Err(err) => return Err(err),
+			}
+		}
+		Ok((self.len() - start) / 8)
+	}
+}
+
+/** Flattens a sequence of bit-slices into a single `BitVec`.
+
+This is the `bitvec` equivalent of the `[T]::concat` and `[T]::join` methods on
+slices of slices. It is implemented for slices of anything that borrows as a
+`BitSlice`, such as `[&BitSlice]` and `[BitVec]`.
+
+Both methods measure the total length of the output before writing into it, so
+the produced vector is allocated exactly once, and each piece is copied into it
+in bulk.
+
+# Type Parameters
+
+- `O`: The ordering type of the pieces and the produced vector.
+- `T`: The storage type of the pieces and the produced vector.
+**/
+pub trait BitConcat<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	/// Flattens a sequence of bit-slices into a single `BitVec`.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	///
+	/// # Returns
+	///
+	/// A vector holding each piece in `self`, in order.
+	///
+	/// # Panics
+	///
+	/// This panics if the total length of the pieces exceeds
+	/// `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let data = 0b1011_0000u8;
+	/// let bits = data.bits::<Msb0>();
+	/// let pieces = [&bits[.. 2], &bits[4 ..], &bits[2 .. 4]];
+	/// assert_eq!(pieces.concat(), bits![1, 0, 0, 0, 0, 0, 1, 1]);
+	/// ```
+	fn concat(&self) -> BitVec<O, T>;
+
+	/// Flattens a sequence of bit-slices into a single `BitVec`, placing a
+	/// separator between each piece.
+	///
+	/// # Parameters
+	///
+	/// - `&self`
+	/// - `sep`: A bit-slice to copy between each adjacent pair of pieces.
+	///
+	/// # Returns
+	///
+	/// A vector holding each piece in `self`, in order, with a copy of `sep`
+	/// between each of them.
+	///
+	/// # Panics
+	///
+	/// This panics if the total length of the pieces and separators exceeds
+	/// `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use bitvec::prelude::*;
+	///
+	/// let pieces = [bitvec![Msb0, u8; 1, 1], bitvec![Msb0, u8; 1]];
+	/// let sep = bits![Msb0, u8; 0, 0];
+	/// assert_eq!(pieces.join(sep), bits![1, 1, 0, 0, 1]);
+	/// ```
+	fn join(&self, sep: &BitSlice<O, T>) -> BitVec<O, T>;
+}
+
+impl<O, T, S> BitConcat<O, T> for [S]
+where
+	O: BitOrder,
+	T: BitStore,
+	S: Borrow<BitSlice<O, T>>,
+{
+	fn concat(&self) -> BitVec<O, T> {
+		let total =
+			concat_len::<T, _>(self.iter().map(|piece| piece.borrow().len()));
+		let mut out = BitVec::with_capacity(total);
+		for piece in self {
+			out.extend_from_bitslice(piece.borrow());
+		}
+		out
+	}
+
+	fn join(&self, sep: &BitSlice<O, T>) -> BitVec<O, T> {
+		let seps = self.len().saturating_sub(1);
+		let total = concat_len::<T, _>(
+			self.iter()
+				.map(|piece| piece.borrow().len())
+				.chain((0 .. seps).map(|_| sep.len())),
+		);
+		let mut out = BitVec::with_capacity(total);
+		let mut pieces = self.iter();
+		if let Some(first) = pieces.next() {
+			out.extend_from_bitslice(first.borrow());
+		}
+		for piece in pieces {
+			out.extend_from_bitslice(sep);
+			out.extend_from_bitslice(piece.borrow());
+		}
+		out
+	}
+}
+
+/// Sums the lengths of the pieces of a concatenation, panicking if the total
+/// does not fit in a `BitVec<_, T>`.
+fn concat_len<T, I>(lens: I) -> usize
+where
+	T: BitStore,
+	I: IntoIterator<Item = usize>,
+{
+	lens.into_iter()
+		.try_fold(0usize, |total, len| {
+			total
+				.checked_add(len)
+				.filter(|&total| total <= BitPtr::<T>::MAX_BITS)
+		})
+		.unwrap_or_else(|| {
+			panic!(
+				"Capacity overflow: concatenation exceeds {}",
+				BitPtr::<T>::MAX_BITS,
+			)
+		})
+}
+
 mod api;
 mod iter;
 mod ops;
@@ -769,3 +1885,7 @@ mod traits;
 
 pub use api::*;
 pub use iter::*;
+
+#[cfg(test)]
+#[allow(clippy::cognitive_complexity)] // Permit large test functions
+mod tests;
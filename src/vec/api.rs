@@ -1,7 +1,23 @@
 //! Reimplementation of the standard library’s `Vec` inherent method API.
 
 use crate::{
-	mem::BitMemory,
+	access::BitAccess,
+	domain::{
+		Domain,
+		DomainMut,
+	},
+	index::{
+		BitIdx,
+		BitMask,
+		Indexable,
+	},
+	mem::{
+		funnel,
+		gather,
+		place,
+		shift_back,
+		BitMemory,
+	},
 	order::BitOrder,
 	pointer::BitPtr,
 	slice::BitSlice,
@@ -9,6 +25,7 @@ use crate::{
 	vec::{
 		iter::{
 			Drain,
+			ExtractIf,
 			Splice,
 		},
 		BitVec,
@@ -16,6 +33,7 @@ use crate::{
 };
 
 use alloc::{
+	alloc::Layout,
 	borrow::ToOwned,
 	boxed::Box,
 	vec::Vec,
@@ -23,11 +41,26 @@ use alloc::{
 
 use core::{
 	cmp,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
 	hint::unreachable_unchecked,
 	marker::PhantomData,
-	mem,
-	ops::RangeBounds,
-	ptr::NonNull,
+	mem::{
+		self,
+		MaybeUninit,
+	},
+	ops::{
+		Range,
+		RangeBounds,
+	},
+	ptr::{
+		self,
+		NonNull,
+	},
+	slice,
 };
 
 use funty::IsInteger;
@@ -79,17 +112,31 @@ where
 
 	/// Returns the number of bits the vector can hold without reallocating.
 	///
+	/// This is counted from the vector’s head index: the dead bits before the
+	/// first live bit in the first element cannot be filled by pushing, and are
+	/// not included.
+	///
 	/// # Examples
 	///
 	/// ```rust
 	/// # use bitvec::prelude::*;
 	/// let bv: BitVec<Local, usize> = BitVec::with_capacity(100);
 	/// assert!(bv.capacity() >= 100);
+	/// ```
+	///
+	/// A vector that begins partway through its first element:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = BitVec::from_bitslice(&0u8.bits::<Msb0>()[3 ..]);
+	/// assert_eq!(bv.capacity(), 5);
+	/// ```
 	#[inline]
 	pub fn capacity(&self) -> usize {
 		self.capacity
 			.checked_mul(T::Mem::BITS as usize)
 			.expect("Vector capacity overflow")
+			.saturating_sub(*self.pointer.head() as usize)
 	}
 
 	/// Reserves capacity for at least `additional` more bits to be inserted in
@@ -154,8 +201,163 @@ where
 			BitPtr::<T>::MAX_BITS,
 		);
 		let (total_elts, _) = self.pointer.head().span(newlen);
-		let extra = total_elts - self.capacity;
-		self.with_vec(|v| v.reserve_exact(extra));
+		if let Some(extra) = total_elts.checked_sub(self.pointer.elements()) {
+			self.with_vec(|v| v.reserve_exact(extra));
+		}
+	}
+
+	/// Tries to reserve capacity for at least `additional` more bits to be
+	/// inserted in the given `BitVec<O, T>`. The collection may reserve more
+	/// space to avoid frequent reallocations. After calling `try_reserve`,
+	/// capacity will be greater than or equal to `self.len() + additional`.
+	/// Does nothing if capacity is already sufficient.
+	///
+	/// # Errors
+	///
+	/// If the new length would exceed `BitPtr::<T>::MAX_BITS`, or the
+	/// allocator reports a failure, then an error is returned and the vector
+	/// is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::vec::BitReserveError;
+	///
+	/// let mut bv = bitvec![1];
+	/// assert!(bv.try_reserve(10).is_ok());
+	/// assert!(bv.capacity() >= 11);
+	///
+	/// assert_eq!(
+	///   bv.try_reserve(!0),
+	///   Err(BitReserveError::CapacityOverflow),
+	/// );
+	/// ```
+	pub fn try_reserve(
+		&mut self,
+		additional: usize,
+	) -> Result<(), BitReserveError>
+	{
+		self.try_grow(additional, false)
+	}
+
+	/// Tries to reserve the minimum capacity for exactly `additional` more bits
+	/// to be inserted in the given `BitVec<O, T>`. After calling
+	/// `try_reserve_exact`, capacity will be greater than or equal to
+	/// `self.len() + additional`. Does nothing if the capacity is already
+	/// sufficient.
+	///
+	/// Note that the allocator may give the collection more space than it
+	/// requests. Therefore, capacity can not be relied upon to be precisely
+	/// minimal. Prefer `try_reserve` if future insertions are expected.
+	///
+	/// # Errors
+	///
+	/// If the new length would exceed `BitPtr::<T>::MAX_BITS`, or the
+	/// allocator reports a failure, then an error is returned and the vector
+	/// is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![1];
+	/// assert!(bv.try_reserve_exact(10).is_ok());
+	/// assert!(bv.capacity() >= 11);
+	/// ```
+	pub fn try_reserve_exact(
+		&mut self,
+		additional: usize,
+	) -> Result<(), BitReserveError>
+	{
+		self.try_grow(additional, true)
+	}
+
+	/// Reallocates the underlying buffer without panicking or aborting.
+	///
+	/// `Vec` does not expose fallible allocation at this crate’s minimum
+	/// supported compiler version, so this performs the reallocation directly
+	/// against the global allocator, using the same layout that `Vec<T>` uses.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `additional`: The number of bits for which to make room.
+	/// - `exact`: If this is `false`, then the buffer is grown to at least
+	///   double its current capacity, to amortize repeated growth.
+	fn try_grow(
+		&mut self,
+		additional: usize,
+		exact: bool,
+	) -> Result<(), BitReserveError>
+	{
+		let newlen = self
+			.grown_len(additional)
+			.ok_or(BitReserveError::CapacityOverflow)?;
+		let (total_elts, _) = self.pointer.head().span(newlen);
+		if total_elts <= self.capacity {
+			return Ok(());
+		}
+		let new_cap = if exact {
+			total_elts
+		}
+		else {
+			cmp::max(total_elts, self.capacity.saturating_mul(2))
+		};
+		self.try_realloc(new_cap)
+	}
+
+	/// Moves the underlying buffer into an allocation of exactly `new_cap`
+	/// elements, using the same layout that `Vec<T>` uses.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `new_cap`: The number of elements in the new allocation. This must not
+	///   be zero, and must cover every element that the vector touches.
+	fn try_realloc(&mut self, new_cap: usize) -> Result<(), BitReserveError> {
+		let align = mem::align_of::<T>();
+		let layout = mem::size_of::<T>()
+			.checked_mul(new_cap)
+			.filter(|&size| size <= isize::max_value() as usize)
+			.and_then(|size| Layout::from_size_align(size, align).ok())
+			.ok_or(BitReserveError::CapacityOverflow)?;
+		let ptr = unsafe {
+			if self.capacity == 0 {
+				alloc::alloc::alloc(layout)
+			}
+			else {
+				let old = Layout::from_size_align_unchecked(
+					mem::size_of::<T>() * self.capacity,
+					align,
+				);
+				alloc::alloc::realloc(
+					self.pointer.pointer().w() as *mut u8,
+					old,
+					layout.size(),
+				)
+			}
+		};
+		if ptr.is_null() {
+			return Err(BitReserveError::AllocError);
+		}
+
+		unsafe {
+			self.pointer.set_pointer(ptr as *const T);
+		}
+		self.capacity = new_cap;
+		Ok(())
+	}
+
+	/// Computes the length of the vector after `additional` more bits, if it
+	/// does not exceed `BitPtr::<T>::MAX_BITS`.
+	///
+	/// The panicking and fallible growth methods both check their limit here,
+	/// so that they agree on which lengths are reachable.
+	fn grown_len(&self, additional: usize) -> Option<usize> {
+		self.len()
+			.checked_add(additional)
+			.filter(|&n| n <= BitPtr::<T>::MAX_BITS)
 	}
 
 	/// Shrinks the capacity of the vector as much as possible.
@@ -178,6 +380,56 @@ where
 		self.with_vec(Vec::shrink_to_fit);
 	}
 
+	/// Shrinks the capacity of the vector with a lower bound.
+	///
+	/// The capacity will remain at least as large as both the length and the
+	/// supplied value, counted from the vector’s starting bit within its first
+	/// element. Live bits are never discarded.
+	///
+	/// If the current capacity is less than or equal to the lower limit, this
+	/// is a no-op.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `min_capacity`: The minimum number of bits that the vector must be
+	///   able to hold after shrinking.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv: BitVec<Local, u8> = BitVec::with_capacity(100);
+	/// bv.extend([true, false, true].iter().copied());
+	/// assert!(bv.capacity() >= 100);
+	/// bv.shrink_to(20);
+	/// assert!(bv.capacity() >= 20);
+	/// bv.shrink_to(0);
+	/// assert!(bv.capacity() >= 3);
+	/// ```
+	pub fn shrink_to(&mut self, min_capacity: usize) {
+		let bits = cmp::max(min_capacity, self.len());
+		let (elts, _) = self.pointer.head().span(bits);
+		if elts >= self.capacity {
+			return;
+		}
+		if elts == self.pointer.elements() {
+			self.shrink_to_fit();
+			return;
+		}
+		//  `Vec::shrink_to` is not available at this crate’s minimum supported
+		//  compiler version, so a lower bound above the live elements is kept
+		//  by reallocating the buffer directly, as `try_reserve` does.
+		if self.try_realloc(elts).is_err() {
+			alloc::alloc::handle_alloc_error(unsafe {
+				Layout::from_size_align_unchecked(
+					mem::size_of::<T>() * elts,
+					mem::align_of::<T>(),
+				)
+			});
+		}
+	}
+
 	/// Converts the bit-vector into [`Box<[T]>`].
 	///
 	/// Note that this will drop any excess capacity.
@@ -216,6 +468,50 @@ where
 		self.into_vec().into_boxed_slice()
 	}
 
+	/// Consumes and leaks the `BitVec`, returning a mutable reference to the
+	/// contents, `&'a mut BitSlice<O, T>`. Note that the memory region `[T]`
+	/// must outlive the chosen lifetime `'a`. If the lifetime is `'static`,
+	/// then the slice will live for the remainder of the program’s life.
+	///
+	/// Any excess capacity is released before the vector is leaked, exactly as
+	/// in [`into_boxed_bitslice`], so the leaked region is the same allocation
+	/// that a [`BitBox`] would own. Dropping the returned reference will cause
+	/// a memory leak. If this is not acceptable, the reference can be given to
+	/// [`BitBox::from_raw`], producing a `BitBox` that will properly
+	/// deallocate the memory when dropped.
+	///
+	/// This is useful for storing a computed bit-slice in a global that
+	/// requires a `'static` reference, such as the value of a lazily
+	/// initialized `static`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let bv = bitvec![0, 1, 1];
+	/// let static_ref: &'static mut BitSlice = bv.leak();
+	/// static_ref.set(0, true);
+	/// assert_eq!(static_ref, bits![1, 1, 1]);
+	/// ```
+	///
+	/// The leaked memory can be reclaimed later:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = BitVec::<Msb0, u8>::with_capacity(100);
+	/// bv.extend_from_bitslice(&0xA5u8.bits::<Msb0>()[2 ..]);
+	/// let leaked = bv.leak();
+	/// let boxed = unsafe { BitBox::from_raw(leaked as *mut BitSlice<_, _>) };
+	/// assert_eq!(boxed, 0xA5u8.bits::<Msb0>()[2 ..]);
+	/// ```
+	///
+	/// [`BitBox`]: ../boxed/struct.BitBox.html
+	/// [`BitBox::from_raw`]: ../boxed/struct.BitBox.html#method.from_raw
+	/// [`into_boxed_bitslice`]: #method.into_boxed_bitslice
+	pub fn leak<'a>(self) -> &'a mut BitSlice<O, T> {
+		self.into_boxed_bitslice().leak()
+	}
+
 	/// Shortens the vector, keeping the first `len` bits and dropping the rest.
 	///
 	/// If `len` is greater than the vector’s current length, this has no
@@ -266,6 +562,61 @@ where
 		}
 	}
 
+	/// Shortens the vector, removing the first `n` bits and keeping the rest.
+	///
+	/// If `n` is greater than or equal to the vector’s current length, this
+	/// clears the vector.
+	///
+	/// This does not move any bits within their elements. Removing fewer bits
+	/// than remain in the first element only advances the vector’s starting
+	/// index, and takes constant time. Removing more bits than that discards
+	/// the fully-removed elements from the front of the buffer, which moves the
+	/// remaining elements down as a single `memmove`. The allocation itself is
+	/// untouched.
+	///
+	/// Because the vector may begin partway into its first element after this
+	/// call, use [`force_align`] if the bits must begin at the front edge of
+	/// the buffer.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `n`: The number of bits to remove from the front of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u8; 0, 0, 1, 0, 1, 1, 0, 1, 0, 0];
+	/// bv.truncate_front(2);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 1, 0, 1, 0, 0]);
+	///
+	/// bv.truncate_front(20);
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`force_align`]: #method.force_align
+	pub fn truncate_front(&mut self, n: usize) {
+		let (addr, head, len) = self.pointer.raw_parts();
+		if n >= len {
+			self.clear();
+			unsafe {
+				self.pointer.set_head(BitIdx::ZERO);
+			}
+			return;
+		}
+		let (elts, new_head) = head.offset(n as isize);
+		let elts = elts as usize;
+		unsafe {
+			if elts > 0 {
+				let base = addr.w();
+				ptr::copy(base.add(elts), base, self.pointer.elements() - elts);
+			}
+			self.pointer.set_head(new_head);
+			self.pointer.set_len(len - n);
+		}
+	}
+
 	/// Extracts an element slice containing the entire vector.
 	///
 	/// Unlike [`BitSlice::as_slice`], this will produce partial edge elements,
@@ -312,6 +663,54 @@ where
 		self.pointer.as_mut_slice()
 	}
 
+	/// Returns the remaining spare capacity of the vector as a slice of
+	/// `MaybeUninit<T>`.
+	///
+	/// The returned slice can be used to fill the vector with data (e.g. by
+	/// reading from a file) before marking the data as initialized using the
+	/// [`set_len`] method.
+	///
+	/// The slice contains only the allocated elements that hold *no* live bits.
+	/// If the vector’s last element is only partially filled, that element is
+	/// not part of the spare capacity, and its dead bits remain reachable only
+	/// through [`as_mut_slice`]. As such, element `i` of the returned slice
+	/// stores the vector bits beginning at index
+	/// `(self.as_slice().len() + i) * T::BITS - head`, where `head` is the
+	/// index of the vector’s first live bit within its first element. Vectors
+	/// that are empty, or whose bits end on an element boundary, have no gap
+	/// between their live bits and the spare capacity.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use core::mem::MaybeUninit;
+	///
+	/// let mut bv = BitVec::<Msb0, u8>::with_capacity(32);
+	/// bv.push_uint(0x5Au8, 8);
+	///
+	/// let spare = bv.spare_capacity_mut();
+	/// assert!(spare.len() >= 3);
+	/// for (slot, byte) in spare.iter_mut().zip([0x12u8, 0x34, 0x56].iter()) {
+	///   *slot = MaybeUninit::new(*byte);
+	/// }
+	/// unsafe { bv.set_len(32); }
+	///
+	/// assert_eq!(bv.as_slice(), &[0x5A, 0x12, 0x34, 0x56]);
+	/// ```
+	///
+	/// [`as_mut_slice`]: #method.as_mut_slice
+	/// [`set_len`]: #method.set_len
+	pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+		let elts = self.pointer.elements();
+		unsafe {
+			slice::from_raw_parts_mut(
+				self.pointer.pointer().w().add(elts) as *mut MaybeUninit<T>,
+				self.capacity - elts,
+			)
+		}
+	}
+
 	/// Forces the length of the vector to `new_len`.
 	///
 	/// This is a low-level operation that maintains none of the normal
@@ -415,7 +814,105 @@ where
 		let len = self.len();
 		assert!(index <= len, "Index {} is out of bounds: {}", index, len);
 		self.push(value);
-		unsafe { self.get_unchecked_mut(index ..) }.rotate_right(1);
+		self.copy_within(index .. len, index + 1);
+		unsafe { self.set_unchecked(index, value) };
+	}
+
+	/// Inserts a bit at position `index` within the vector, shifting all bits
+	/// after it to the right, or returns an error instead of panicking.
+	///
+	/// This is the fallible counterpart of [`insert`], and uses the same
+	/// length limit.
+	///
+	/// # Errors
+	///
+	/// If `index > len`, then `BitInsertError::OutOfBounds` is returned. If
+	/// the vector already holds `BitPtr::<T>::MAX_BITS` bits, or the allocator
+	/// reports a failure, then the error from [`try_reserve`] is returned in
+	/// `BitInsertError::Reserve`. In every case, the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// use bitvec::vec::BitInsertError;
+	///
+	/// let mut bv = bitvec![0, 0];
+	/// bv.try_insert(1, true).expect("out of memory");
+	/// assert_eq!(bv, bitvec![0, 1, 0]);
+	///
+	/// assert_eq!(
+	///   bv.try_insert(4, true),
+	///   Err(BitInsertError::OutOfBounds { index: 4, len: 3 }),
+	/// );
+	/// ```
+	///
+	/// [`insert`]: #method.insert
+	/// [`try_reserve`]: #method.try_reserve
+	pub fn try_insert(
+		&mut self,
+		index: usize,
+		value: bool,
+	) -> Result<(), BitInsertError>
+	{
+		let len = self.len();
+		if index > len {
+			return Err(BitInsertError::OutOfBounds { index, len });
+		}
+		self.try_reserve(1)?;
+		self.insert(index, value);
+		Ok(())
+	}
+
+	/// Inserts a run of bits at position `index` within the vector, shifting
+	/// all bits after it to the right.
+	///
+	/// The vector grows once, the bits after `index` move right by
+	/// `src.len()` in a single pass, and then `src` is copied into the gap.
+	/// This is much faster than calling [`insert`] once per bit, which moves
+	/// the whole tail for every inserted bit.
+	///
+	/// `src` cannot alias `self`, as the borrow checker forbids holding it
+	/// while `self` is mutably borrowed. To insert a copy of part of the
+	/// vector into itself, copy that part out first with [`to_vec`].
+	///
+	/// # Panics
+	///
+	/// Panics if `index > len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 0, 0, 0];
+	/// bv.insert_bitslice(2, bits![1, 1, 1]);
+	/// assert_eq!(bv, bitvec![0, 0, 1, 1, 1, 0, 0]);
+	/// bv.insert_bitslice(7, bits![1]);
+	/// assert_eq!(bv, bitvec![0, 0, 1, 1, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`insert`]: #method.insert
+	/// [`to_vec`]: ../slice/struct.BitSlice.html#method.to_vec
+	pub fn insert_bitslice(&mut self, index: usize, src: &BitSlice<O, T>) {
+		let len = self.len();
+		assert!(index <= len, "Index {} is out of bounds: {}", index, len);
+		if index == len {
+			self.extend_from_bitslice(src);
+			return;
+		}
+		let count = src.len();
+		if count == 0 {
+			return;
+		}
+		self.reserve(count);
+		unsafe {
+			self.set_len(len + count);
+			//  Move the tail up,
+			self.copy_within(index .. len, index + count);
+			//  And fill the gap from the source.
+			self.get_unchecked_mut(index .. index + count)
+				.copy_from_slice(src);
+		}
 	}
 
 	/// Removes and returns the bit at position `index` within the vector,
@@ -437,17 +934,18 @@ where
 		let len = self.len();
 		assert!(len != 0, "Empty vectors cannot remove");
 		assert!(index < len, "Index {} is out of bounds: {}", index, len);
-		unsafe {
-			self.get_unchecked_mut(index ..).rotate_left(1);
-			self.pop().unwrap_or_else(|| unreachable_unchecked())
-		}
+		let out = unsafe { *self.get_unchecked(index) };
+		self.copy_within(index + 1 .., index);
+		self.pop();
+		out
 	}
 
 	/// Retains only the bits that pass the predicate.
 	///
 	/// This removes all bits `b` where `f(e)` returns `false`. This method
-	/// operates in place and preserves the order of the retained bits. Because
-	/// it is in-place, it operates in `O(n²)` time.
+	/// operates in place and preserves the order of the retained bits. It
+	/// visits each bit exactly once, in order, moving each retained bit
+	/// directly to its final index, and so operates in `O(n)` time.
 	///
 	/// # API Differences
 	///
@@ -456,6 +954,8 @@ where
 	/// `(usize, &T) -> bool`. This difference is in place because `BitSlice` by
 	/// definition has only one bit of information per slice item, and including
 	/// the index allows the callback function to make more informed choices.
+	/// The index is always the bit’s position in the original vector, before
+	/// any bits were removed.
 	///
 	/// # Examples
 	///
@@ -466,14 +966,156 @@ where
 	/// assert_eq!(bv, bitvec![1, 1, 1]);
 	/// ```
 	///
-	/// [`BitSlice::for_each`]: ../slice/struct.BitSlice.html#method.for_each
+	/// [`Vec::retain`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.retain
 	pub fn retain<F>(&mut self, mut pred: F)
 	where F: FnMut(usize, bool) -> bool {
-		for n in (0 .. self.len()).rev() {
-			if !pred(n, self[n]) {
-				self.remove(n);
+		let len = self.len();
+		//  Index of the next slot to write; everything before it is kept.
+		let mut write = 0;
+		for read in 0 .. len {
+			unsafe {
+				let bit = *self.get_unchecked(read);
+				if pred(read, bit) {
+					if read != write {
+						self.set_unchecked(write, bit);
+					}
+					write += 1;
+				}
+			}
+		}
+		self.truncate(write);
+	}
+
+	/// Creates an iterator which uses a predicate to determine if a bit should
+	/// be removed.
+	///
+	/// If the predicate returns `true`, the bit is removed from the vector and
+	/// yielded by the iterator. If it returns `false`, the bit remains in the
+	/// vector and is not yielded. The predicate is called exactly once for each
+	/// bit, in order, and receives the bit’s index in the original vector.
+	///
+	/// The kept bits are moved into their final position as the iterator
+	/// advances, so the vector is filtered in a single pass, and the removed
+	/// bits are available to the caller rather than discarded as in
+	/// [`retain`].
+	///
+	/// # Notes
+	///
+	/// 1. If the iterator is dropped before it is exhausted, the bits it has
+	///    not yet visited remain in the vector, after the kept bits, and no
+	///    further bits are removed.
+	/// 2. If the `ExtractIf` value is leaked, the vector keeps its original
+	///    length, but the values of the bits it contains are unspecified.
+	///
+	/// # API Differences
+	///
+	/// The [`Vec::extract_if`] method takes a range of elements to visit and a
+	/// predicate with signature `(&mut T) -> bool`. This method always visits
+	/// the whole vector, and its predicate has the same `(usize, bool) -> bool`
+	/// signature as [`retain`].
+	///
+	/// # Examples
+	///
+	/// Splitting a vector into set and unset bits:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 0, 0];
+	/// let ones = bv.extract_if(|_, b| b).collect::<BitVec>();
+	/// assert_eq!(ones, bitvec![1; 3]);
+	/// assert_eq!(bv, bitvec![0; 4]);
+	/// ```
+	///
+	/// Stopping early keeps the unvisited bits:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![1, 0, 1, 0, 1];
+	/// let mut iter = bv.extract_if(|_, b| b);
+	/// assert_eq!(iter.next(), Some(true));
+	/// assert_eq!(iter.next(), Some(true));
+	/// drop(iter);
+	/// assert_eq!(bv, bitvec![0, 0, 1]);
+	/// ```
+	///
+	/// [`Vec::extract_if`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.extract_if
+	/// [`retain`]: #method.retain
+	pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<O, T, F>
+	where F: FnMut(usize, bool) -> bool {
+		let old_len = self.len();
+		ExtractIf {
+			bitvec: self,
+			idx: 0,
+			del: 0,
+			old_len,
+			pred,
+		}
+	}
+
+	/// Removes all but the first of consecutive bits in the vector satisfying
+	/// a given equality relation.
+	///
+	/// The `same_bucket` function is passed the values of two bits from the
+	/// vector, and must determine if the bits compare equal. The bits are
+	/// passed in opposite order from their order in the vector, so if
+	/// `same_bucket(a, b)` returns `true`, `a` is removed.
+	///
+	/// This makes a single pass over the vector, moving each retained bit
+	/// directly to its final index. The capacity of the vector is unchanged.
+	///
+	/// # API Differences
+	///
+	/// The [`Vec::dedup_by`] method passes mutable references to its
+	/// elements. Bits are passed by value, as they cannot be referenced.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 0, 1, 0, 1, 1, 1, 0];
+	/// //  Only remove set bits that follow a set bit.
+	/// bv.dedup_by(|a, b| a && b);
+	/// assert_eq!(bv, bitvec![0, 0, 1, 0, 1, 0]);
+	/// ```
+	///
+	/// [`Vec::dedup_by`]: https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup_by
+	pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+	where F: FnMut(bool, bool) -> bool {
+		let len = self.len();
+		if len < 2 {
+			return;
+		}
+		//  Index of the next slot to write; everything before it is kept.
+		let mut write = 1;
+		for read in 1 .. len {
+			unsafe {
+				let bit = *self.get_unchecked(read);
+				let prev = *self.get_unchecked(write - 1);
+				if !same_bucket(bit, prev) {
+					self.set_unchecked(write, bit);
+					write += 1;
+				}
 			}
 		}
+		self.truncate(write);
+	}
+
+	/// Removes consecutive repeated bits in the vector.
+	///
+	/// This leaves a vector in which every bit differs from its neighbors, and
+	/// so describes the boundaries between runs of equal bits. The capacity of
+	/// the vector is unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 0, 1, 1, 1, 0];
+	/// bv.dedup();
+	/// assert_eq!(bv, bitvec![0, 1, 0]);
+	/// ```
+	pub fn dedup(&mut self) {
+		self.dedup_by(|a, b| a == b)
 	}
 
 	/// Appends a bit to the back of the vector.
@@ -498,7 +1140,7 @@ where
 	pub fn push(&mut self, value: bool) {
 		let len = self.len();
 		assert!(
-			len <= BitPtr::<T>::MAX_BITS,
+			self.grown_len(1).is_some(),
 			"Capacity overflow: {} >= {}",
 			len,
 			BitPtr::<T>::MAX_BITS,
@@ -516,20 +1158,192 @@ where
 		}
 	}
 
-	/// Removes the last element from a vector and returns it, or `None` if it
-	/// is empty.
+	/// Appends the low `width` bits of an integer to the back of the vector.
+	///
+	/// The bits are appended from least significant to most significant: the
+	/// first new bit in the vector is `value & 1`, the second is
+	/// `(value >> 1) & 1`, and so on. This is the same sequence produced by
+	/// calling [`push`] in a loop, but each destination element is written
+	/// only once, rather than once per bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: An integer whose low bits will be appended.
+	/// - `width`: The number of bits of `value` to append. Bits of `value`
+	///   above this width are ignored.
+	///
+	/// # Type Parameters
+	///
+	/// - `U`: Any fundamental unsigned integer. It need not be the same type as
+	///   the vector’s storage element.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` exceeds the bit width of `U`, or if the push will
+	/// cause the vector to allocate above `BitPtr<T>::MAX_BITS` or machine
+	/// capacity.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// # use bitvec::prelude::*;
-	/// let mut bv: BitVec = BitVec::new();
-	/// assert!(bv.is_empty());
-	/// bv.push(true);
-	/// assert_eq!(bv.len(), 1);
-	/// assert!(bv[0]);
+	/// let mut bv = bitvec![Lsb0, u8; 1];
+	/// bv.push_uint(0x1ABCu16, 13);
+	/// assert_eq!(bv.len(), 14);
+	/// assert_eq!(bv.as_slice(), &[0x79, 0x35]);
+	/// ```
 	///
-	/// assert!(bv.pop().unwrap());
+	/// [`push`]: #method.push
+	pub fn push_uint<U>(&mut self, value: U, width: usize)
+	where U: BitMemory {
+		assert!(
+			width <= U::BITS as usize,
+			"Width {} exceeds the source width {}",
+			width,
+			U::BITS,
+		);
+		if width == 0 {
+			return;
+		}
+		let len = self.len();
+		self.reserve(width);
+		unsafe {
+			self.set_len(len + width);
+		}
+
+		let lsb0 = BitSlice::<O, T>::linear_order();
+		let mut value = value;
+		//  Takes the next `to - from` bits of `value`, placed for an element.
+		let mut next = |from: u8, to: u8, mask: BitMask<T::Mem>| {
+			let bits = place::<O, U, T::Mem>(value, from, *mask, lsb0);
+			let count = to - from;
+			value = if count < U::BITS {
+				value >> count
+			}
+			else {
+				U::ZERO
+			};
+			bits
+		};
+
+		match self[len ..].domain_mut() {
+			DomainMut::Enclave { head, elem, tail } => {
+				let mask = O::mask(head, tail);
+				let bits = next(*head, *tail, mask);
+				elem.clear_bits(mask);
+				elem.set_bits(mask & bits);
+			},
+			DomainMut::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					let mask = O::mask(head, None);
+					let bits = next(*head, T::Mem::BITS, mask);
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+				for elem in body {
+					let bits = next(0, T::Mem::BITS, BitMask::new(T::Mem::ALL));
+					elem.set_elem(bits.retype::<T::NoAlias>());
+				}
+				if let Some((elem, tail)) = tail {
+					let mask = O::mask(None, tail);
+					let bits = next(0, *tail, mask);
+					elem.clear_bits(mask);
+					elem.set_bits(mask & bits);
+				}
+			},
+		}
+	}
+
+	/// Appends a bit to the back of the vector, if there is sufficient spare
+	/// capacity; otherwise, the bit is returned.
+	///
+	/// Unlike [`push`], this method will never reallocate when the capacity is
+	/// insufficient. The unused bits in a partially-filled last element count
+	/// as spare capacity, and the bits before the vector’s starting index in
+	/// its first element do not.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `value`: The bit to append.
+	///
+	/// # Returns
+	///
+	/// `Ok(())` if the bit was appended, or `Err(value)` if the vector is full.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = BitVec::<Lsb0, u8>::with_capacity(8);
+	/// let cap = bv.capacity();
+	/// for _ in 0 .. cap {
+	///   assert!(bv.push_within_capacity(true).is_ok());
+	/// }
+	/// assert_eq!(bv.push_within_capacity(false), Err(false));
+	/// assert_eq!(bv.len(), cap);
+	/// assert_eq!(bv.capacity(), cap);
+	/// ```
+	///
+	/// [`push`]: #method.push
+	pub fn push_within_capacity(&mut self, value: bool) -> Result<(), bool> {
+		let len = self.len();
+		if len == BitPtr::<T>::MAX_BITS
+			|| self.pointer.head().span(len + 1).0 > self.capacity
+		{
+			return Err(value);
+		}
+		unsafe {
+			self.pointer.set_len(len + 1);
+			self.set_unchecked(len, value);
+		}
+		Ok(())
+	}
+
+	/// Appends a bit to the back of the vector, returning an error instead of
+	/// panicking if the vector cannot grow.
+	///
+	/// This is [`push`], with the fallible allocation behavior of
+	/// [`try_reserve`].
+	///
+	/// # Errors
+	///
+	/// If the vector already holds `BitPtr::<T>::MAX_BITS` bits, or the
+	/// allocator reports a failure, then an error is returned and the vector is
+	/// unchanged.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0; 3];
+	/// bv.try_push(true).expect("out of memory");
+	/// assert_eq!(bv, bitvec![0, 0, 0, 1]);
+	/// ```
+	///
+	/// [`push`]: #method.push
+	/// [`try_reserve`]: #method.try_reserve
+	pub fn try_push(&mut self, value: bool) -> Result<(), BitReserveError> {
+		self.try_reserve(1)?;
+		self.push(value);
+		Ok(())
+	}
+
+	/// Removes the last element from a vector and returns it, or `None` if it
+	/// is empty.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv: BitVec = BitVec::new();
+	/// assert!(bv.is_empty());
+	/// bv.push(true);
+	/// assert_eq!(bv.len(), 1);
+	/// assert!(bv[0]);
+	///
+	/// assert!(bv.pop().unwrap());
 	/// assert!(bv.is_empty());
 	/// assert!(bv.pop().is_none());
 	/// ```
@@ -541,13 +1355,306 @@ where
 		})
 	}
 
+	/// Prepends a bit to the front of a vector.
+	///
+	/// When the vector begins partway into its first element (for example,
+	/// after [`truncate_front`] or [`pop_front`]), this takes constant time:
+	/// the vector’s starting index moves back by one bit. When the vector
+	/// already begins at the front edge of its buffer, a new element is
+	/// inserted before the existing ones, and the vector begins at the last bit
+	/// of that element. This moves the buffer up by one element, but leaves
+	/// room for the next `T::Mem::BITS - 1` calls to take constant time.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector overflows
+	/// `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 0];
+	/// bv.push_front(true);
+	/// assert_eq!(bv, bitvec![1, 0, 0]);
+	/// ```
+	///
+	/// [`pop_front`]: #method.pop_front
+	/// [`truncate_front`]: #method.truncate_front
+	pub fn push_front(&mut self, value: bool) {
+		let len = self.len();
+		assert!(
+			self.grown_len(1).is_some(),
+			"Capacity overflow: {} >= {}",
+			len,
+			BitPtr::<T>::MAX_BITS,
+		);
+		unsafe {
+			self.grow_front(1);
+			self.set_unchecked(0, value);
+		}
+	}
+
+	/// Prepends `n` bits of unspecified value to the vector.
+	///
+	/// The starting index moves down into the unused bits of the first
+	/// element, and only when they run out are new elements inserted at the
+	/// front of the buffer.
+	///
+	/// # Safety
+	///
+	/// The caller must have checked that the new length is within
+	/// [`grown_len`], and must initialize the new bits.
+	///
+	/// [`grown_len`]: #method.grown_len
+	unsafe fn grow_front(&mut self, n: usize) {
+		let len = self.len();
+		//  An empty vector has no live element to grow into.
+		let head = if len == 0 {
+			0
+		}
+		else {
+			*self.pointer.head() as usize
+		};
+		let width = T::Mem::BITS as usize;
+		if n <= head {
+			self.pointer.set_head(((head - n) as u8).idx());
+		}
+		else {
+			let elts = (n - head + width - 1) / width;
+			self.with_vec(|v| {
+				v.splice(0 .. 0, (0 .. elts).map(|_| T::Mem::ZERO.into()));
+			});
+			self.pointer
+				.set_head(((head + elts * width - n) as u8).idx());
+		}
+		self.pointer.set_len(len + n);
+	}
+
+	/// Removes the first bit from a vector and returns it, or `None` if it is
+	/// empty.
+	///
+	/// This advances the vector’s starting index by one bit, in the same manner
+	/// as [`truncate_front`]. Once per `T::Mem::BITS` calls, the starting index
+	/// leaves its element, and the remaining elements are moved down by one.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![1, 0];
+	/// assert_eq!(bv.pop_front(), Some(true));
+	/// assert_eq!(bv.pop_front(), Some(false));
+	/// assert!(bv.pop_front().is_none());
+	/// ```
+	///
+	/// [`truncate_front`]: #method.truncate_front
+	pub fn pop_front(&mut self) -> Option<bool> {
+		let out = *self.first()?;
+		self.truncate_front(1);
+		Some(out)
+	}
+
+	/// Rotates the vector in-place such that the first `by` bits move to the
+	/// end while the last `self.len() - by` bits move to the front.
+	///
+	/// This has the same behavior as [`BitSlice::rotate_left`], but uses the
+	/// vector’s ownership of its buffer to avoid moving every bit:
+	///
+	/// - when the vector starts at the zero index, fills its last element, and
+	///   `by` is a multiple of `T::Mem::BITS`, the elements are rotated
+	///   directly;
+	/// - otherwise, the shorter side of the rotation is copied past the other
+	///   end of the vector, and the starting index is moved over it.
+	///
+	/// Either way, only the rotated bits and the elements are moved.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `by` is greater than the length of the
+	/// vector. Note that `by == self.len()` does *not* panic and is a noöp
+	/// rotation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u8; 1, 1, 0, 0, 0, 0, 1];
+	/// bv.rotate_left(2);
+	/// assert_eq!(bv, bits![0, 0, 0, 0, 1, 1, 1]);
+	/// ```
+	///
+	/// [`BitSlice::rotate_left`]:
+	/// ../slice/struct.BitSlice.html#method.rotate_left
+	pub fn rotate_left(&mut self, by: usize) {
+		let len = self.len();
+		assert!(
+			by <= len,
+			"Slices cannot be rotated by more than their length"
+		);
+		self.rotate(by);
+	}
+
+	/// Rotates the vector in-place such that the first `self.len() - by` bits
+	/// move to the end while the last `by` bits move to the front.
+	///
+	/// This has the same behavior as [`BitSlice::rotate_right`], with the same
+	/// strategies as [`rotate_left`].
+	///
+	/// # Panics
+	///
+	/// This function will panic if `by` is greater than the length of the
+	/// vector. Note that `by == self.len()` does *not* panic and is a noöp
+	/// rotation.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Lsb0, u16; 1, 1, 0, 0, 0, 0, 1];
+	/// bv.rotate_right(2);
+	/// assert_eq!(bv, bits![0, 1, 1, 1, 0, 0, 0]);
+	/// ```
+	///
+	/// [`BitSlice::rotate_right`]:
+	/// ../slice/struct.BitSlice.html#method.rotate_right
+	/// [`rotate_left`]: #method.rotate_left
+	pub fn rotate_right(&mut self, by: usize) {
+		let len = self.len();
+		assert!(
+			by <= len,
+			"Slices cannot be rotated by more than their length"
+		);
+		self.rotate(len - by);
+	}
+
+	/// Rotates the vector left by `by` bits, which must not exceed its length.
+	fn rotate(&mut self, by: usize) {
+		let len = self.len();
+		if by == 0 || by == len {
+			return;
+		}
+		let width = T::Mem::BITS as usize;
+		let head = *self.pointer.head();
+		if head == 0 && len % width == 0 && by % width == 0 {
+			self.as_mut_slice().rotate_left(by / width);
+			return;
+		}
+		let back = len - by;
+		//  The copied bits briefly extend the vector. In the unlikely event
+		//  that this is not possible, the slice can still rotate in place.
+		if self.grown_len(cmp::min(by, back)).is_none() {
+			self.as_mut_bitslice().rotate_left(by);
+		}
+		else if by <= back {
+			self.extend_from_within(.. by);
+			self.truncate_front(by);
+		}
+		else {
+			unsafe {
+				self.grow_front(back);
+			}
+			let (front, rest) = self.split_at_mut(len);
+			front[.. back].copy_from_slice(&rest[.. back]);
+			self.truncate(len);
+		}
+	}
+
+	/// Removes the last `width` bits from a vector and returns them as an
+	/// integer, or `None` if fewer than `width` bits remain.
+	///
+	/// This is the inverse of [`push_uint`]: the first removed bit (at index
+	/// `len - width`) becomes the least significant bit of the returned value,
+	/// and the last bit of the vector becomes its most significant bit.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `width`: The number of bits to remove. A `width` of zero removes
+	///   nothing and returns `Some(0)`.
+	///
+	/// # Returns
+	///
+	/// The removed bits, assembled into an integer, if the vector held at least
+	/// `width` bits. If it did not, the vector is unchanged and this returns
+	/// `None`.
+	///
+	/// # Panics
+	///
+	/// This panics if `width` exceeds 64.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv: BitVec = BitVec::new();
+	/// bv.push_uint(0x1ABCu16, 13);
+	/// bv.push_uint(5u8, 3);
+	///
+	/// assert_eq!(bv.pop_uint(3), Some(5));
+	/// assert_eq!(bv.pop_uint(20), None);
+	/// assert_eq!(bv.pop_uint(13), Some(0x1ABC));
+	/// assert_eq!(bv.pop_uint(0), Some(0));
+	/// assert!(bv.is_empty());
+	/// ```
+	///
+	/// [`push_uint`]: #method.push_uint
+	pub fn pop_uint(&mut self, width: usize) -> Option<u64> {
+		assert!(width <= 64, "Width {} exceeds the maximum 64", width);
+		let new_len = self.len().checked_sub(width)?;
+
+		let lsb0 = BitSlice::<O, T>::linear_order();
+		let mut out = 0u64;
+		let mut shift = 0u32;
+		//  Moves the bits of `elem` at indices `from .. to`, according to `O`,
+		//  into the next positions of `out`.
+		let mut take = |elem, mask: BitMask<T::Mem>, from: u8, to: u8| {
+			if shift < 64 {
+				let bits = gather::<O, T::Mem>(elem, *mask, from, to, lsb0);
+				out |= (bits as u64) << shift;
+			}
+			shift += u32::from(to - from);
+		};
+
+		match self[new_len ..].domain() {
+			Domain::Enclave { head, elem, tail } => {
+				take(elem.load(), O::mask(head, tail), *head, *tail)
+			},
+			Domain::Region { head, body, tail } => {
+				if let Some((head, elem)) = head {
+					let mask = O::mask(head, None);
+					take(elem.load(), mask, *head, T::Mem::BITS);
+				}
+				for elem in body {
+					let mask = BitMask::new(T::Mem::ALL);
+					take(elem.get_elem().retype::<T>(), mask, 0, T::Mem::BITS);
+				}
+				if let Some((elem, tail)) = tail {
+					take(elem.load(), O::mask(None, tail), 0, *tail);
+				}
+			},
+		}
+
+		self.truncate(new_len);
+		Some(out)
+	}
+
 	/// Moves all the elements of `other` into `self`, leaving `other` empty.
 	///
+	/// The bits are copied by [`extend_from_bitslice`], which moves whole
+	/// memory elements wherever the layouts of `self` and `other` allow. The
+	/// capacity of `other` is unchanged.
+	///
 	/// # Panics
 	///
 	/// Panics if the number of bits in the vector overflows
 	/// `BitPtr::<T>::MAX_ELTS`.
 	///
+	/// # API Differences
+	///
+	/// Earlier versions of this method accepted a vector of any ordering and
+	/// storage type. That behavior is now provided by [`append_any`].
+	///
 	/// # Examples
 	///
 	/// ```rust
@@ -559,8 +1666,41 @@ where
 	/// assert!(bv1[10]);
 	/// assert!(bv2.is_empty());
 	/// ```
+	///
+	/// [`append_any`]: #method.append_any
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
+	#[inline]
+	pub fn append(&mut self, other: &mut Self) {
+		self.extend_from_bitslice(other);
+		other.clear();
+	}
+
+	/// Moves all the elements of `other` into `self`, leaving `other` empty,
+	/// where `other` may have a different ordering or storage type.
+	///
+	/// As the two vectors may place bits differently in memory, this copies
+	/// bits individually. Prefer [`append`] when the types match.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector overflows
+	/// `BitPtr::<T>::MAX_ELTS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv1 = bitvec![Msb0, u8; 0; 10];
+	/// let mut bv2 = bitvec![Lsb0, u16; 1; 10];
+	/// bv1.append_any(&mut bv2);
+	/// assert_eq!(bv1.len(), 20);
+	/// assert!(bv1[10]);
+	/// assert!(bv2.is_empty());
+	/// ```
+	///
+	/// [`append`]: #method.append
 	#[inline]
-	pub fn append<D, U>(&mut self, other: &mut BitVec<D, U>)
+	pub fn append_any<D, U>(&mut self, other: &mut BitVec<D, U>)
 	where
 		D: BitOrder,
 		U: BitStore,
@@ -661,7 +1801,14 @@ where
 	/// Returns a newly allocated `Self`. `self` contains elements `[0, at)`,
 	/// and the returned `Self` contains elements `[at, len)`.
 	///
-	/// Note that the capacity of `self` does not change.
+	/// The returned vector is copied from `self` element by element, as in
+	/// [`from_bitslice`], and begins at the same position within its first
+	/// element that bit `at` had in `self`.
+	///
+	/// Note that the capacity of `self` does not change. When `at` is `0`, the
+	/// original buffer moves into the returned vector without copying, and
+	/// `self` receives a fresh allocation of the same capacity, ready for
+	/// reuse.
 	///
 	/// # Panics
 	///
@@ -676,11 +1823,16 @@ where
 	/// assert_eq!(bv1, bitvec![0, 0, 0]);
 	/// assert_eq!(bv2, bitvec![1, 1, 1]);
 	/// ```
+	///
+	/// [`from_bitslice`]: #method.from_bitslice
 	pub fn split_off(&mut self, at: usize) -> Self {
 		let len = self.len();
 		assert!(at <= len, "Index out of bounds: {} is beyond {}", at, len);
 		match at {
-			0 => mem::replace(self, Self::new()),
+			0 => {
+				let capacity = self.capacity();
+				mem::replace(self, Self::with_capacity(capacity))
+			},
 			n if n == len => Self::new(),
 			_ => {
 				let out = self[at ..].to_owned();
@@ -690,6 +1842,46 @@ where
 		}
 	}
 
+	/// Splits the collection into two at the given index.
+	///
+	/// Returns a newly allocated `Self`. `self` contains elements `[at, len)`,
+	/// and the returned `Self` contains elements `[0, at)`.
+	///
+	/// This is the counterpart to [`split_off`]. The front bits are removed
+	/// from `self` with [`truncate_front`], so the remaining bits are not
+	/// shifted within their elements. Note that the capacity of `self` does
+	/// not change.
+	///
+	/// # Panics
+	///
+	/// Panics if `at > len`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv1 = bitvec![0, 0, 0, 1, 1, 1];
+	/// let bv2 = bv1.split_to(2);
+	/// assert_eq!(bv1, bitvec![0, 1, 1, 1]);
+	/// assert_eq!(bv2, bitvec![0, 0]);
+	/// ```
+	///
+	/// [`split_off`]: #method.split_off
+	/// [`truncate_front`]: #method.truncate_front
+	pub fn split_to(&mut self, at: usize) -> Self {
+		let len = self.len();
+		assert!(at <= len, "Index out of bounds: {} is beyond {}", at, len);
+		match at {
+			0 => Self::new(),
+			n if n == len => mem::replace(self, Self::new()),
+			_ => {
+				let out = self[.. at].to_owned();
+				self.truncate_front(at);
+				out
+			},
+		}
+	}
+
 	/// Resizes the `BitVec` in-place so that `len` is equal to `new_len`.
 	///
 	/// If `new_len` is greater than `len`, the `BitVec` is extended by the
@@ -721,6 +1913,21 @@ where
 	/// assert_eq!(bv, bitvec![1, 0, 1, 0]);
 	/// ```
 	///
+	/// The closure is called exactly once for each new bit, in order, so it
+	/// can track the index it is producing:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0; 3];
+	/// let mut idx = bv.len();
+	/// bv.resize_with(8, || {
+	///   idx += 1;
+	///   idx % 3 == 0
+	/// });
+	/// assert_eq!(idx, 8);
+	/// assert_eq!(bv, bitvec![0, 0, 0, 0, 0, 1, 0, 0]);
+	/// ```
+	///
 	/// [`Clone`]: https://doc.rust-lang.org/std/clone/trait.Clone.html
 	/// [`Default`]: https://doc.rust-lang.org/std/default/trait.Default.html
 	/// [`Default::default()`]: https://doc.rust-lang.org/std/default/trait.Default.html#tymethod.default
@@ -733,8 +1940,13 @@ where
 			cmp::Ordering::Greater => {
 				let diff = new_len - len;
 				self.reserve(diff);
-				for _ in 0 .. (new_len - len) {
-					self.push(f());
+				unsafe {
+					self.set_len(new_len);
+				}
+				for idx in len .. new_len {
+					unsafe {
+						self.set_unchecked(idx, f());
+					}
 				}
 			},
 			cmp::Ordering::Equal => {},
@@ -807,6 +2019,256 @@ where
 		self[len ..].clone_from_slice(other)
 	}
 
+	/// Copies and appends all bits in a bit-slice of the same type to the
+	/// `BitVec`.
+	///
+	/// This reserves all needed memory up front. When the live tail of `self`
+	/// and the head of `other` sit at the same index within their elements,
+	/// the slice is copied as whole memory elements, with only the partial
+	/// edge elements masked; this is a `memcpy` with at most two extra
+	/// read/modify/write cycles. When the indices differ, under [`Lsb0`] and
+	/// [`Msb0`] each destination element is assembled from the two source
+	/// elements it overlaps with a pair of shifts. Only other orderings fall
+	/// back to bit-by-bit transfer as in [`extend_from_slice`]. See
+	/// [`BitSlice::copy_from_bitslice`], which performs the copy.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `other`: A bit-slice whose contents will be copied onto the end of
+	///   `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector overflows
+	/// `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u8; 1, 0, 1];
+	/// let src = 0x3Cu8;
+	/// bv.extend_from_bitslice(&src.bits::<Msb0>()[3 ..]);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 1, 1, 1, 0, 0]);
+	/// assert_eq!(bv.as_slice(), &[0xBC]);
+	/// ```
+	///
+	/// `other` may not be borrowed from `self`, as the vector may reallocate
+	/// while it is being written:
+	///
+	/// ```rust,compile_fail
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1, 0];
+	/// bv.extend_from_bitslice(&bv[.. 2]);
+	/// ```
+	///
+	/// Copy the region out first to append a vector to itself:
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1, 0];
+	/// let head = bv[.. 2].to_owned();
+	/// bv.extend_from_bitslice(&head);
+	/// assert_eq!(bv, bitvec![0, 1, 1, 0, 0, 1]);
+	/// ```
+	///
+	/// [`BitSlice::copy_from_bitslice`]:
+	/// ../slice/struct.BitSlice.html#method.copy_from_bitslice
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	/// [`extend_from_slice`]: #method.extend_from_slice
+	pub fn extend_from_bitslice(&mut self, other: &BitSlice<O, T>) {
+		let len = self.len();
+		let olen = other.len();
+		if olen == 0 {
+			return;
+		}
+		self.reserve(olen);
+		unsafe {
+			self.set_len(len + olen);
+		}
+		self[len ..].copy_from_bitslice(other);
+	}
+
+	/// Appends whole memory elements to the vector.
+	///
+	/// Each element of `elts` is appended as the `T::Mem::BITS` bits it holds
+	/// under the vector’s order, as if by
+	/// `extend_from_bitslice(elts.bits::<O>())`. This is the inverse of
+	/// [`as_slice`] for a vector that begins at the front edge of its buffer.
+	///
+	/// When the vector ends at an element boundary, the elements are copied
+	/// directly into the spare capacity. When it does not, under [`Lsb0`] and
+	/// [`Msb0`] each element is split across two elements of the vector with a
+	/// pair of shifts, leaving the live bits already in the vector’s last
+	/// element untouched. Other orderings fall back to
+	/// [`extend_from_bitslice`].
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `elts`: Memory elements whose bits will be appended to `self`.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bits in the vector overflows
+	/// `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![Msb0, u8; 1, 1, 1];
+	/// bv.extend_from_raw_slice(&[0x00, 0xA5]);
+	/// assert_eq!(bv.len(), 19);
+	/// assert_eq!(bv[3 ..], [0x00u8, 0xA5].bits::<Msb0>());
+	/// assert_eq!(bv.as_slice(), &[0xE0, 0x14, 0xA0]);
+	/// ```
+	///
+	/// [`Lsb0`]: ../order/struct.Lsb0.html
+	/// [`Msb0`]: ../order/struct.Msb0.html
+	/// [`as_slice`]: #method.as_slice
+	/// [`extend_from_bitslice`]: #method.extend_from_bitslice
+	pub fn extend_from_raw_slice(&mut self, elts: &[T]) {
+		let len = self.len();
+		let new_len = elts
+			.len()
+			.checked_mul(T::Mem::BITS as usize)
+			.and_then(|bits| self.grown_len(bits))
+			.unwrap_or_else(|| {
+				panic!(
+					"Capacity overflow: {} elements exceed {}",
+					elts.len(),
+					BitPtr::<T>::MAX_BITS,
+				)
+			});
+		if elts.is_empty() {
+			return;
+		}
+		let offset = ((*self.pointer.head() as usize + len)
+			% T::Mem::BITS as usize) as u8;
+		let lsb0 = BitSlice::<O, T>::linear_order();
+		if offset != 0 && lsb0.is_none() {
+			self.extend_from_bitslice(BitSlice::from_slice(elts));
+			return;
+		}
+
+		self.reserve(new_len - len);
+		let old = self.pointer.elements();
+		unsafe {
+			let base = self.pointer.pointer().w() as *mut T::Mem;
+			if offset == 0 {
+				for (n, elt) in elts.iter().enumerate() {
+					base.add(old + n).write(elt.get_elem());
+				}
+			}
+			else {
+				let lsb0 = lsb0.unwrap_or_else(|| unreachable_unchecked());
+				let back = T::Mem::BITS - offset;
+				//  Each element finishes the partial element before it, and
+				//  carries its remaining bits into the next one. An empty
+				//  vector starting partway into an element has nothing to
+				//  finish, and starts in a fresh element.
+				let (last, live) = match old.checked_sub(1) {
+					Some(n) => (base.add(n), base.add(n).read()),
+					None => (base, T::Mem::ZERO),
+				};
+				//  The live bits are held at the back of the first carry, as if
+				//  they were the tail of a source element.
+				let live = *(O::mask(None, offset.tail()) & live);
+				let mut carry = shift_back(live, back, lsb0);
+				for (n, elt) in elts.iter().enumerate() {
+					let elt = elt.get_elem();
+					last.add(n).write(funnel(carry, elt, back, lsb0));
+					carry = elt;
+				}
+				let rest = funnel(carry, T::Mem::ZERO, back, lsb0);
+				last.add(elts.len()).write(rest);
+			}
+			self.set_len(new_len);
+		}
+	}
+
+	/// Copies bits from the `src` range to the end of the vector.
+	///
+	/// The copy proceeds from front to back, one bit at a time in effect. The
+	/// range may therefore extend past the current end of the vector, in which
+	/// case the bits written earlier in this call are themselves copied. This
+	/// is the back-reference behavior of LZ77-style decompression: copying the
+	/// range `len - 1 .. len + 7` repeats the last bit eight times.
+	///
+	/// The copy reserves memory once, and then moves blocks of up to
+	/// `len - start` bits at a time.
+	///
+	/// # Parameters
+	///
+	/// - `&mut self`
+	/// - `src`: A range of indices to copy. An unbounded end is the current
+	///   length of the vector.
+	///
+	/// # Panics
+	///
+	/// This panics if the range start is greater than its end, or if the range
+	/// is not empty and its start is not a live index in the vector. It also
+	/// panics if the new length overflows `BitPtr::<T>::MAX_BITS`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1];
+	/// bv.extend_from_within(1 ..);
+	/// assert_eq!(bv, bitvec![0, 1, 1, 1, 1]);
+	///
+	/// //  Overlapping copies repeat the source pattern.
+	/// let mut bv = bitvec![1, 0];
+	/// bv.extend_from_within(0 .. 7);
+	/// assert_eq!(bv, bitvec![1, 0, 1, 0, 1, 0, 1, 0, 1]);
+	/// ```
+	pub fn extend_from_within<R>(&mut self, src: R)
+	where R: RangeBounds<usize> {
+		let len = self.len();
+		let Range {
+			start: from,
+			end: upto,
+		} = self.bounds(src);
+		assert!(
+			from <= upto,
+			"The source start must be below the source end"
+		);
+		if from == upto {
+			return;
+		}
+		assert!(
+			from < len,
+			"Index out of bounds: {} is beyond {}",
+			from,
+			len.saturating_sub(1),
+		);
+
+		let count = upto - from;
+		assert!(
+			count <= BitPtr::<T>::MAX_BITS - len,
+			"Capacity overflow: {} + {} exceeds {}",
+			len,
+			count,
+			BitPtr::<T>::MAX_BITS,
+		);
+		let distance = len - from;
+		self.reserve(count);
+		unsafe {
+			self.set_len(len + count);
+		}
+		let mut pos = len;
+		while pos < len + count {
+			let chunk = cmp::min(distance, len + count - pos);
+			let (front, back) = self.split_at_mut(pos);
+			back[.. chunk].copy_from_slice(&front[pos - distance ..][.. chunk]);
+			pos += chunk;
+		}
+	}
+
 	/// Creates a splicing iterator that replaces the specified range in the
 	/// vector with the given `replace_with` iterator and yields the removed
 	/// bits. `replace_with` does not need to be the same length as `range`.
@@ -861,4 +2323,153 @@ where
 			splice: replace_with.into_iter(),
 		}
 	}
+
+	/// Replaces the specified range in the vector with the contents of a
+	/// `BitSlice`, returning the removed bits.
+	///
+	/// Because the replacement length is known in advance, the bits after
+	/// `range` move at most once, directly to their final position, and the
+	/// replacement is then copied into place. When the replacement has the
+	/// same length as `range`, the range is overwritten and the rest of the
+	/// vector does not move.
+	///
+	/// # Panics
+	///
+	/// Panics if the starting point is greater than the end point or if the end
+	/// point is greater than the length of the vector.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 0, 1, 1, 0, 0];
+	/// let removed = bv.splice_bitslice(2 .. 4, bits![1, 0, 1]);
+	/// assert_eq!(removed, bitvec![1, 1]);
+	/// assert_eq!(bv, bitvec![0, 0, 1, 0, 1, 0, 0]);
+	/// ```
+	pub fn splice_bitslice<R>(
+		&mut self,
+		range: R,
+		replacement: &BitSlice<O, T>,
+	) -> Self
+	where
+		R: RangeBounds<usize>,
+	{
+		let len = self.len();
+		//  `upto` is the first index beyond the end of the splice.
+		let Range {
+			start: from,
+			end: upto,
+		} = self.bounds(range);
+		assert!(
+			from <= upto,
+			"The splice start must be below the splice end"
+		);
+		assert!(
+			upto <= len,
+			"The splice end must be within the vector bounds"
+		);
+
+		let removed = self[from .. upto].to_owned();
+		let count = replacement.len();
+		let new_upto = from + count;
+		//  Move the tail once, growing the vector before or shrinking it after.
+		if new_upto > upto {
+			let delta = new_upto - upto;
+			self.reserve(delta);
+			unsafe {
+				self.set_len(len + delta);
+			}
+			self.copy_within(upto .. len, new_upto);
+		}
+		else if new_upto < upto {
+			let delta = upto - new_upto;
+			self.copy_within(upto .. len, new_upto);
+			unsafe {
+				self.set_len(len - delta);
+			}
+		}
+		self[from .. new_upto].copy_from_bitslice(replacement);
+		removed
+	}
+}
+
+/** The error type for `try_reserve` methods.
+
+This is a counterpart to the standard library’s `TryReserveError`, which is not
+available at this crate’s minimum supported compiler version.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitReserveError {
+	/// The requested capacity exceeds the number of bits that a `BitVec` can
+	/// describe, or the number of bytes that the allocator can address.
+	CapacityOverflow,
+	/// The memory allocator returned an error.
+	AllocError,
+}
+
+impl Display for BitReserveError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			BitReserveError::CapacityOverflow => fmt.write_str(
+				"memory allocation failed because the computed capacity \
+				 exceeded the collection's maximum",
+			),
+			BitReserveError::AllocError => fmt.write_str(
+				"memory allocation failed because the memory allocator \
+				 returned an error",
+			),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitReserveError {
+}
+
+/** The error type for `try_insert`.
+
+An insertion can fail either because its index is past the end of the vector,
+or because the vector cannot grow to hold the new bit.
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitInsertError {
+	/// The insertion index was past the end of the vector.
+	OutOfBounds {
+		/// The requested index.
+		index: usize,
+		/// The length of the vector.
+		len: usize,
+	},
+	/// The vector could not reserve space for the new bit.
+	Reserve(BitReserveError),
+}
+
+impl From<BitReserveError> for BitInsertError {
+	fn from(err: BitReserveError) -> Self {
+		BitInsertError::Reserve(err)
+	}
+}
+
+impl Display for BitInsertError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self {
+			BitInsertError::OutOfBounds { index, len } => write!(
+				fmt,
+				"insertion index {} is out of bounds: {}",
+				index, len,
+			),
+			BitInsertError::Reserve(err) => Display::fmt(err, fmt),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BitInsertError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			BitInsertError::OutOfBounds { .. } => None,
+			BitInsertError::Reserve(err) => Some(err),
+		}
+	}
 }
@@ -3,6 +3,10 @@
 use super::*;
 
 use crate::{
+	access::BitAccess,
+	domain::DomainMut,
+	index::Indexable,
+	mem::BitMemory,
 	order::BitOrder,
 	store::BitStore,
 };
@@ -10,6 +14,11 @@ use crate::{
 use alloc::vec::Vec;
 
 use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
 	iter::{
 		FromIterator,
 		FusedIterator,
@@ -19,9 +28,9 @@ use core::{
 
 /** Extends a `BitVec` with the contents of another bitstream.
 
-At present, this just calls `.push()` in a loop. When specialization becomes
-available, it will be able to more intelligently perform bulk moves from the
-source into `self` when the source is `BitSlice`-compatible.
+The bits are collected into a register one memory element at a time, and each
+element is written to the vector once, after it is filled or the stream ends.
+The allocator is only called when the vector’s capacity is exhausted.
 **/
 impl<O, T> Extend<bool> for BitVec<O, T>
 where
@@ -50,17 +59,81 @@ where
 	/// assert_eq!(0x0F, bv.as_slice()[0]);
 	/// ```
 	fn extend<I: IntoIterator<Item = bool>>(&mut self, src: I) {
-		let iter = src.into_iter();
+		let mut iter = src.into_iter();
 		match iter.size_hint() {
 			(_, Some(hi)) => self.reserve(hi),
 			(lo, None) => self.reserve(lo),
 		}
-		iter.for_each(|b| self.push(b));
+		loop {
+			let len = self.len();
+			//  The index of the next bit within its element.
+			let start = ((*self.pointer.head() as usize + len)
+				% T::Mem::BITS as usize) as u8;
+
+			//  Collect bits until the element fills or the stream ends.
+			let mut bits = T::Mem::ZERO;
+			let mut end = start;
+			while end < T::Mem::BITS {
+				match iter.next() {
+					Some(bit) => {
+						bits.set::<O>(end.idx(), bit);
+						end += 1;
+					},
+					None => break,
+				}
+			}
+			let count = (end - start) as usize;
+			if count == 0 {
+				return;
+			}
+
+			if self.pointer.head().span(len + count).0 > self.capacity {
+				self.reserve(count);
+			}
+			unsafe {
+				self.set_len(len + count);
+			}
+			/* The new bits lie in a single element, so exactly one of the
+			domain’s parts is present. An element that already holds live bits
+			merges the register under a mask; a fresh element is overwritten,
+			which also clears its dead bits.
+			*/
+			match self[len ..].domain_mut() {
+				DomainMut::Enclave { head, elem, tail } => {
+					if start == 0 {
+						elem.store(bits);
+					}
+					else {
+						let mask = O::mask(head, tail);
+						elem.clear_bits(mask);
+						elem.set_bits(mask & bits);
+					}
+				},
+				DomainMut::Region { head, body, tail } => {
+					if let Some((head, elem)) = head {
+						let mask = O::mask(head, None);
+						elem.clear_bits(mask);
+						elem.set_bits(mask & bits);
+					}
+					for elem in body {
+						elem.set_elem(bits.retype::<T::NoAlias>());
+					}
+					if let Some((elem, _)) = tail {
+						elem.store(bits);
+					}
+				},
+			}
+		}
 	}
 }
 
-/// Permits the construction of a `BitVec` by using `.collect()` on an iterator
-/// of `bool`.
+/** Permits the construction of a `BitVec` by using `.collect()` on an iterator
+of `bool`.
+
+The vector is allocated up front from the iterator’s size hint, preferring the
+upper bound when one is given, so an iterator that reports its exact length is
+collected without any reallocation. The bits are then written as in `Extend`.
+**/
 impl<O, T> FromIterator<bool> for BitVec<O, T>
 where
 	O: BitOrder,
@@ -83,9 +156,7 @@ where
 		let mut bv = match iter.size_hint() {
 			(_, Some(len)) | (len, _) => Self::with_capacity(len),
 		};
-		for bit in iter {
-			bv.push(bit);
-		}
+		bv.extend(iter);
 		bv
 	}
 }
@@ -170,6 +241,70 @@ where
 	O: BitOrder,
 	T: 'a + BitStore,
 {
+	/// Views the bits that remain to be yielded as a `BitSlice`.
+	///
+	/// Bits already yielded from either end are not included.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1, 0, 1];
+	/// let mut drain = bv.drain(1 ..);
+	/// assert_eq!(drain.as_bits(), bits![1, 1, 0, 1]);
+	/// drain.next();
+	/// drain.next_back();
+	/// assert_eq!(drain.as_bits(), bits![1, 0]);
+	/// ```
+	#[inline]
+	pub fn as_bits(&self) -> &BitSlice<O, T> {
+		self.iter.as_bitslice()
+	}
+
+	/// Keeps the bits that have not yet been yielded in the vector, and stops
+	/// draining.
+	///
+	/// The unyielded bits move down to close the gap left by the yielded bits,
+	/// and the bits after the drained range follow them, as if only the
+	/// yielded bits had been removed.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut bv = bitvec![0, 1, 1, 0, 1, 0];
+	/// let mut drain = bv.drain(1 .. 5);
+	/// assert_eq!(drain.next(), Some(true));
+	/// assert_eq!(drain.next_back(), Some(true));
+	/// drain.keep_rest();
+	/// assert_eq!(bv, bitvec![0, 1, 0, 0]);
+	/// ```
+	pub fn keep_rest(self) {
+		let rest = self.iter.as_bitslice();
+		let count = rest.len();
+		if count == 0 {
+			return;
+		}
+		unsafe {
+			let bv = &mut *self.bitvec.as_ptr();
+			let start = bv.len();
+			//  Find the index in the vector of the first unyielded bit. The raw
+			//  `pointer` field is used, rather than `.bitptr()`, because the
+			//  vector is empty when the drain starts at zero, and an empty
+			//  handle does not keep its address.
+			let (vec_ptr, rest_ptr) = (bv.pointer, rest.bitptr());
+			let elts = (rest_ptr.pointer().u() - vec_ptr.pointer().u())
+				/ mem::size_of::<T>();
+			let from = elts * T::Mem::BITS as usize + *rest_ptr.head() as usize
+				- *vec_ptr.head() as usize;
+			//  Move the unyielded bits down to the front of the drained span,
+			bv.set_len(from + count);
+			bv.copy_within(from .. from + count, start);
+			//  And mark them live. `Drain::drop` then moves the tail after them.
+			bv.set_len(start + count);
+		}
+	}
+
 	/// Fills the drain span with another iterator.
 	///
 	/// If the stream exhausts before the drain is filled, then the tail
@@ -232,6 +367,16 @@ where
 	}
 }
 
+impl<'a, O, T> Debug for Drain<'a, O, T>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+{
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		f.debug_tuple("Drain").field(&self.as_bits()).finish()
+	}
+}
+
 impl<'a, O, T> DoubleEndedIterator for Drain<'a, O, T>
 where
 	O: BitOrder,
@@ -304,15 +449,110 @@ where
 			//  Inflate the vector to include the remnant span,
 			bv.set_len(full_len);
 			//  Move the remnant span down into the drained span,
-			for (from, to) in (tail .. full_len).zip(start .. end_len) {
-				bv.copy_unchecked(from, to);
-			}
+			bv.copy_within(tail .. full_len, start);
 			//  And deflate the vector to fit.
 			bv.set_len(end_len);
 		}
 	}
 }
 
+/** A filtering iterator for `BitVec`.
+
+This visits each bit in the vector in order, yielding the bits for which the
+predicate returns `true` and removing them from the vector. Bits for which the
+predicate returns `false` are moved down over the removed bits as the iteration
+proceeds, so the vector is compacted in a single pass.
+
+When this is dropped, any bits that have not yet been visited remain in the
+vector, after the bits that were kept.
+
+# Type Parameters
+
+- `O: BitOrder`: The ordering type of the underlying vector.
+- `T: 'a + BitStore`: The storage type of the underlying vector.
+- `F: FnMut(usize, bool) -> bool`: The predicate deciding which bits to remove.
+
+# Lifetimes
+
+- `'a`: The lifetime of the underlying vector.
+**/
+pub struct ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	/// The `BitVec` being filtered.
+	pub(super) bitvec: &'a mut BitVec<O, T>,
+	/// Index of the next bit to visit.
+	pub(super) idx: usize,
+	/// Number of bits removed so far.
+	pub(super) del: usize,
+	/// Length of the vector before filtering began.
+	pub(super) old_len: usize,
+	/// The predicate deciding which bits to remove.
+	pub(super) pred: F,
+}
+
+impl<'a, O, T, F> FusedIterator for ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+}
+
+impl<'a, O, T, F> Iterator for ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	type Item = bool;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.idx < self.old_len {
+			let idx = self.idx;
+			let bit = unsafe { *self.bitvec.get_unchecked(idx) };
+			self.idx += 1;
+			if (self.pred)(idx, bit) {
+				self.del += 1;
+				return Some(bit);
+			}
+			//  Move kept bits down over the removed bits.
+			if self.del > 0 {
+				unsafe {
+					self.bitvec.set_unchecked(idx - self.del, bit);
+				}
+			}
+		}
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.old_len - self.idx))
+	}
+}
+
+impl<'a, O, T, F> Drop for ExtractIf<'a, O, T, F>
+where
+	O: BitOrder,
+	T: 'a + BitStore,
+	F: FnMut(usize, bool) -> bool,
+{
+	fn drop(&mut self) {
+		let (idx, del, old_len) = (self.idx, self.del, self.old_len);
+		//  Move the unvisited bits down into the removed span,
+		if del > 0 {
+			self.bitvec.copy_within(idx .. old_len, idx - del);
+		}
+		//  And deflate the vector to fit.
+		unsafe {
+			self.bitvec.set_len(old_len - del);
+		}
+	}
+}
+
 /// A consuming iterator for `BitVec`.
 #[repr(C)]
 pub struct IntoIter<O, T>
@@ -332,6 +572,22 @@ where
 	O: BitOrder,
 	T: BitStore,
 {
+	/// Views the bits that have not yet been yielded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// # use bitvec::prelude::*;
+	/// let mut iter = bitvec![0, 1, 1, 0, 1].into_iter();
+	/// iter.next();
+	/// iter.next_back();
+	/// assert_eq!(iter.as_bits(), bits![1, 1, 0]);
+	/// ```
+	#[inline]
+	pub fn as_bits(&self) -> &BitSlice<O, T> {
+		self.region.into_bitslice()
+	}
+
 	fn iterator(&self) -> <&BitSlice<O, T> as IntoIterator>::IntoIter {
 		self.region.into_bitslice().into_iter()
 	}
@@ -441,19 +697,21 @@ where
 	///
 	/// # Returns
 	///
-	/// The number of bits in the iterator.
+	/// The number of bits remaining in the iterator.
 	///
 	/// # Examples
 	///
 	/// ```rust
 	/// use bitvec::prelude::*;
 	/// let bv = bitvec![Msb0, u8; 0, 1, 0, 1, 0];
-	/// assert_eq!(bv.into_iter().count(), 5);
+	/// let mut iter = bv.into_iter();
+	/// iter.next();
+	/// assert_eq!(iter.count(), 4);
 	/// ```
 	///
 	/// [`BitSlice`]: ../struct.BitSlice.html#method.iter
 	fn count(self) -> usize {
-		self.bitvec.len()
+		self.region.len()
 	}
 
 	/// Advances the iterator by `n` bits, starting from zero.
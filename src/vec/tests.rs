@@ -0,0 +1,2037 @@
+/*! Unit tests for the `vec` module.
+!*/
+
+use crate::{
+	order::{
+		Lsb0,
+		Msb0,
+	},
+	pointer::BitPtr,
+	slice::{
+		AsBits,
+		BitSlice,
+	},
+	testing::{
+		heads,
+		pattern,
+		xorshift,
+	},
+	vec::{
+		BitConcat,
+		BitInsertError,
+		BitReserveError,
+		BitVec,
+	},
+};
+
+use core::{
+	cmp,
+	mem::MaybeUninit,
+};
+
+#[test]
+fn extend_from_bitslice() {
+	let data = [0x5Au8, 0xC3, 0x96, 0x0F, 0xE1];
+	let bits = data.bits::<Msb0>();
+
+	for dst_head in 0 .. 8 {
+		for dst_len in 0 .. 12 {
+			for src_head in 0 .. 8 {
+				for src_len in 0 .. 28 {
+					let dst = &bits[dst_head ..][.. dst_len];
+					let src = &bits[src_head ..][.. src_len];
+
+					let mut bv = dst.to_owned();
+					bv.extend_from_bitslice(src);
+
+					assert_eq!(bv.len(), dst_len + src_len);
+					assert_eq!(&bv[.. dst_len], dst);
+					assert_eq!(&bv[dst_len ..], src);
+				}
+			}
+		}
+	}
+
+	let data = [!0u16; 3];
+	let mut bv = 0u16.bits::<Lsb0>()[5 ..].to_owned();
+	bv.extend_from_bitslice(&data.bits::<Lsb0>()[.. 46]);
+	assert_eq!(bv.len(), 57);
+	assert!(bv[.. 11].not_any());
+	assert!(bv[11 ..].all());
+	assert_eq!(bv.as_slice(), &[0, !0, !0, 0x3FFF]);
+
+	let mut bv = BitVec::<Msb0, u8>::new();
+	let src = bv.clone();
+	bv.extend_from_bitslice(&src);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn push_uint() {
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let values = [0u16, 1, 0x1ABC, 0x8001, 0xA5C3, !0];
+			let data: [$t; 2] = [0x96, 0x69];
+			for head in 0 .. 8 {
+				for len in 0 .. 10 {
+					let base = &data.bits::<$o>()[head ..][.. len];
+					for &value in values.iter() {
+						for width in 0 ..= 16 {
+							let mut bv = BitVec::from_bitslice(base);
+							bv.push_uint(value, width);
+
+							let mut expected = BitVec::from_bitslice(base);
+							for idx in 0 .. width {
+								expected.push((value >> idx) & 1 != 0);
+							}
+							assert_eq!(bv, expected);
+						}
+					}
+				}
+			}
+		)+ };
+	}
+
+	check!(Msb0, u8; Lsb0, u8; Msb0, u32; Lsb0, u16);
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	bv.push_uint(0x0123_4567u32, 28);
+	bv.push_uint(0u8, 0);
+	bv.push_uint(0xFu8, 4);
+	assert_eq!(bv.as_slice(), &[0x67, 0x45, 0x23, 0xF1]);
+}
+
+#[test]
+#[should_panic]
+fn push_uint_too_wide() {
+	BitVec::<Lsb0, u32>::new().push_uint(0u8, 9);
+}
+
+#[test]
+fn pop_uint() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5, 0x0F, 0xF0, 0x5A, 0xC3, 0x81];
+	for head in 0 .. 8 {
+		for width in 0 ..= 64 {
+			let base = &data.bits::<Msb0>()[head ..];
+			let mut bv = BitVec::from_bitslice(base);
+			let len = bv.len();
+
+			let mut expected = 0u64;
+			for (shift, bit) in base[len - width ..].iter().enumerate() {
+				expected |= (*bit as u64) << shift;
+			}
+			assert_eq!(bv.pop_uint(width), Some(expected));
+			assert_eq!(bv.len(), len - width);
+			assert_eq!(bv, base[.. len - width]);
+		}
+	}
+
+	let mut bv = BitVec::<Lsb0, u16>::new();
+	for width in 0 ..= 16 {
+		bv.push_uint(0xA5C3u16, width);
+	}
+	for width in (0 ..= 16).rev() {
+		let mask = (1u64 << width) - 1;
+		assert_eq!(bv.pop_uint(width), Some(0xA5C3 & mask));
+	}
+	assert!(bv.is_empty());
+	assert!(bv.pop_uint(1).is_none());
+}
+
+#[test]
+#[should_panic]
+fn pop_uint_too_wide() {
+	BitVec::<Lsb0, u8>::repeat(false, 80).pop_uint(65);
+}
+
+#[test]
+fn try_reserve() {
+	let max = BitPtr::<u8>::MAX_BITS;
+
+	let mut bv = bitvec![Lsb0, u8; 1; 5];
+	assert!(bv.try_reserve(20).is_ok());
+	assert!(bv.capacity() >= 25);
+	assert_eq!(bv, bitvec![1; 5]);
+	assert!(bv.try_reserve_exact(100).is_ok());
+	assert!(bv.capacity() >= 105);
+	assert_eq!(bv, bitvec![1; 5]);
+
+	let cap = bv.capacity();
+	for &additional in [max - 4, max, !0].iter() {
+		assert_eq!(
+			bv.try_reserve(additional),
+			Err(BitReserveError::CapacityOverflow)
+		);
+		assert_eq!(
+			bv.try_reserve_exact(additional),
+			Err(BitReserveError::CapacityOverflow)
+		);
+	}
+	assert_eq!(bv.capacity(), cap);
+	assert_eq!(bv, bitvec![1; 5]);
+
+	//  A head offset counts against the maximum length.
+	let mut bv = BitVec::from_bitslice(&0u8.bits::<Lsb0>()[3 ..]);
+	assert!(bv.try_reserve(max - 5).is_err());
+
+	let mut bv = BitVec::<Lsb0, u32>::new();
+	assert_eq!(
+		bv.try_reserve(BitPtr::<u32>::MAX_BITS + 1),
+		Err(BitReserveError::CapacityOverflow)
+	);
+	assert!(bv.try_reserve(64).is_ok());
+	bv.push_uint(!0u32, 32);
+	bv.push_uint(!0u32, 32);
+	assert!(bv.all());
+}
+
+#[test]
+fn shrink_to() {
+	let data = [0x96u8, 0x69, 0x3C];
+	let base = &data.bits::<Msb0>()[5 .. 19];
+
+	let mut bv = BitVec::from_bitslice(base);
+	bv.reserve(200);
+	assert!(bv.capacity() >= 214);
+
+	bv.shrink_to(100);
+	assert!(bv.capacity() >= 100 + 5);
+	assert!(bv.capacity() < 200);
+	assert_eq!(bv, base);
+
+	//  The floor is below the length; no live bits may be lost.
+	bv.shrink_to(3);
+	assert!(bv.capacity() >= 14 + 5);
+	assert_eq!(bv, base);
+	bv.push(true);
+	assert_eq!(bv[.. 14], base);
+	assert!(bv[14]);
+
+	//  Shrinking never grows the allocation.
+	let cap = bv.capacity();
+	bv.shrink_to(cap * 2);
+	assert_eq!(bv.capacity(), cap);
+
+	let mut bv = BitVec::<Lsb0, u16>::with_capacity(64);
+	bv.shrink_to(0);
+	assert_eq!(bv.capacity(), 0);
+	bv.push_uint(0x5Au8, 8);
+	assert_eq!(bv.pop_uint(8), Some(0x5A));
+}
+
+#[test]
+fn resize_with() {
+	let data = [0x96u8, 0x69];
+	let base = &data.bits::<Msb0>()[3 .. 13];
+
+	let mut bv = BitVec::from_bitslice(base);
+	let mut calls = 0;
+	bv.resize_with(50, || {
+		calls += 1;
+		calls % 2 == 1
+	});
+	assert_eq!(calls, 40);
+	assert_eq!(bv.len(), 50);
+	assert_eq!(bv[.. 10], base);
+	for (idx, bit) in bv[10 ..].iter().enumerate() {
+		assert_eq!(*bit, idx % 2 == 0);
+	}
+
+	let before = bv.clone();
+	let mut calls = 0;
+	bv.resize_with(20, || {
+		calls += 1;
+		true
+	});
+	assert_eq!(calls, 0);
+	assert_eq!(bv, before[.. 20]);
+
+	bv.resize_with(20, || unreachable!());
+	assert_eq!(bv.len(), 20);
+}
+
+#[test]
+fn truncate_front() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5, 0x0F];
+	let bits = data.bits::<Msb0>();
+
+	for head in 0 .. 8 {
+		let base = &bits[head .. 36];
+		for n in 0 ..= base.len() {
+			let mut bv = BitVec::from_bitslice(base);
+			bv.truncate_front(n);
+			assert_eq!(bv, base[n ..]);
+
+			let mut aligned = bv.clone();
+			aligned.force_align();
+			assert_eq!(aligned, base[n ..]);
+
+			bv.shrink_to_fit();
+			assert_eq!(bv, base[n ..]);
+			bv.push(true);
+			assert_eq!(bv[.. 36 - head - n], base[n ..]);
+			assert!(bv[36 - head - n]);
+		}
+	}
+
+	let mut bv = BitVec::from_bitslice(&bits[3 ..]);
+	let cap = bv.capacity();
+	bv.truncate_front(100);
+	assert!(bv.is_empty());
+	//  Clearing the head makes the bits in front of it available.
+	assert_eq!(bv.capacity(), cap + 3);
+	bv.push_uint(0xA5u8, 8);
+	assert_eq!(bv.as_slice(), &[0xA5]);
+}
+
+#[test]
+fn split_to() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Msb0>();
+
+	for head in 0 .. 8 {
+		let base = &bits[head ..];
+		for at in 0 ..= base.len() {
+			let mut bv = BitVec::from_bitslice(base);
+			let front = bv.split_to(at);
+			assert_eq!(front, base[.. at]);
+			assert_eq!(bv, base[at ..]);
+		}
+	}
+
+	let mut bv = BitVec::from_bitslice(bits);
+	let front = bv.split_to(0);
+	assert!(front.is_empty());
+	assert_eq!(front.capacity(), 0);
+	assert_eq!(bv, bits);
+
+	let ptr = bv.as_slice().as_ptr();
+	let front = bv.split_to(32);
+	assert_eq!(front.as_slice().as_ptr(), ptr);
+	assert_eq!(front, bits);
+	assert!(bv.is_empty());
+	assert_eq!(bv.capacity(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 5 is beyond 4")]
+fn split_to_out_of_bounds() {
+	BitVec::<Lsb0, u8>::repeat(true, 4).split_to(5);
+}
+
+#[test]
+fn raw_parts() {
+	//  Unallocated
+	let bv = BitVec::<Lsb0, u32>::new();
+	let (pointer, head, bits, capacity) = bv.into_raw_parts();
+	assert!(!pointer.is_null());
+	assert_eq!((head, bits, capacity), (0, 0, 0));
+	let mut bv = unsafe {
+		BitVec::<Lsb0, u32>::from_raw_parts(pointer, head, bits, capacity)
+	};
+	assert!(bv.is_empty());
+	bv.push(true);
+	assert_eq!(bv, bitvec![1]);
+
+	//  Empty, but allocated
+	let bv = BitVec::<Msb0, u8>::with_capacity(20);
+	let (pointer, head, bits, capacity) = bv.into_raw_parts();
+	assert_eq!((head, bits), (0, 0));
+	assert!(capacity >= 3);
+	let bv = unsafe {
+		BitVec::<Msb0, u8>::from_raw_parts(pointer, head, bits, capacity)
+	};
+	assert!(bv.is_empty());
+	assert_eq!(bv.capacity(), capacity * 8);
+
+	//  Unaligned
+	let data = [0x96u8, 0x69, 0x3C];
+	for start in 0 .. 8 {
+		for end in start .. 24 {
+			let base = &data.bits::<Msb0>()[start .. end];
+			let bv = BitVec::from_bitslice(base);
+			let (pointer, head, bits, capacity) = bv.into_raw_parts();
+			assert_eq!(head, start);
+			assert_eq!(bits, end - start);
+			let bv = unsafe {
+				BitVec::<Msb0, u8>::from_raw_parts(pointer, head, bits, capacity)
+			};
+			assert_eq!(bv, base);
+		}
+	}
+}
+
+#[test]
+fn spare_capacity_mut() {
+	let mut bv = BitVec::<Lsb0, u16>::with_capacity(64);
+	let cap = bv.spare_capacity_mut().len();
+	assert!(cap >= 4);
+
+	//  A partial element is not spare.
+	bv.push_uint(0x1Fu8, 5);
+	assert_eq!(bv.spare_capacity_mut().len(), cap - 1);
+
+	//  Bits that end on an element boundary continue into the spare capacity.
+	let mut bv = BitVec::from_bitslice(&0xFFu8.bits::<Msb0>()[3 ..]);
+	bv.reserve(16);
+	let spare = bv.spare_capacity_mut();
+	assert!(spare.len() >= 2);
+	spare[0] = MaybeUninit::new(0x96);
+	spare[1] = MaybeUninit::new(0x69);
+	unsafe {
+		bv.set_len(5 + 16);
+	}
+	assert_eq!(bv[.. 5], bitvec![1; 5]);
+	assert_eq!(bv.as_slice()[1 ..], [0x96, 0x69]);
+
+	let mut bv = BitVec::<Msb0, u8>::new();
+	assert!(bv.spare_capacity_mut().is_empty());
+}
+
+#[test]
+fn push_within_capacity() {
+	let mut bv = BitVec::<Msb0, u16>::with_capacity(40);
+	let (cap, ptr) = (bv.capacity(), bv.as_slice().as_ptr());
+	for idx in 0 .. cap {
+		assert!(bv.push_within_capacity(idx % 3 == 0).is_ok());
+	}
+	assert_eq!(bv.push_within_capacity(true), Err(true));
+	assert_eq!(bv.capacity(), cap);
+	assert_eq!(bv.as_slice().as_ptr(), ptr);
+	for (idx, bit) in bv.iter().enumerate() {
+		assert_eq!(*bit, idx % 3 == 0);
+	}
+
+	//  Bits in front of the head are not available.
+	let mut bv = BitVec::from_bitslice(&0u8.bits::<Lsb0>()[5 ..]);
+	bv.shrink_to_fit();
+	assert_eq!(bv.capacity(), 3);
+	assert_eq!(bv.len(), 3);
+	assert_eq!(bv.push_within_capacity(true), Err(true));
+	assert_eq!(bv.len(), 3);
+
+	//  The dead tail of the last element is available.
+	let mut bv = BitVec::from_bitslice(&0u8.bits::<Lsb0>()[2 .. 5]);
+	bv.shrink_to_fit();
+	for _ in 0 .. 3 {
+		assert!(bv.push_within_capacity(true).is_ok());
+	}
+	assert_eq!(bv.push_within_capacity(false), Err(false));
+	assert_eq!(bv, bitvec![0, 0, 0, 1, 1, 1]);
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	assert_eq!(bv.push_within_capacity(false), Err(false));
+}
+
+#[test]
+fn extend_from_within() {
+	let data = [0x96u8, 0x69, 0x3C];
+	let bits = data.bits::<Msb0>();
+
+	for head in 0 .. 8 {
+		let base = &bits[head .. 20];
+		let len = base.len();
+		for from in 0 .. len {
+			for upto in from ..= len + 12 {
+				let mut bv = BitVec::from_bitslice(base);
+				bv.extend_from_within(from .. upto);
+
+				let mut expected = BitVec::from_bitslice(base);
+				for idx in from .. upto {
+					let bit = expected[idx];
+					expected.push(bit);
+				}
+				assert_eq!(bv, expected);
+			}
+		}
+	}
+
+	//  A distance of one repeats a single bit.
+	let mut bv = bitvec![Lsb0, u8; 0, 1];
+	bv.extend_from_within(1 .. 20);
+	assert_eq!(bv.len(), 21);
+	assert!(bv[1 ..].all());
+
+	let mut bv = bitvec![Lsb0, u8; 1, 0, 0];
+	bv.extend_from_within(..);
+	bv.extend_from_within(3 ..= 5);
+	bv.extend_from_within(9 .. 9);
+	assert_eq!(bv, bitvec![1, 0, 0, 1, 0, 0, 1, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Index out of bounds: 4 is beyond 3")]
+fn extend_from_within_out_of_bounds() {
+	BitVec::<Lsb0, u8>::repeat(true, 4).extend_from_within(4 .. 6);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn extend_from_within_inclusive_max() {
+	BitVec::<Lsb0, u8>::repeat(true, 4).extend_from_within(1 ..= !0);
+}
+
+#[test]
+fn dedup() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Msb0>();
+	for head in 0 .. 8 {
+		for len in 0 .. 24 {
+			let base = &bits[head ..][.. len];
+			let mut bv = BitVec::from_bitslice(base);
+			let cap = bv.capacity();
+			bv.dedup();
+
+			let mut expected = BitVec::<Msb0, u8>::new();
+			for &bit in base.iter() {
+				if expected.last() != Some(&bit) {
+					expected.push(bit);
+				}
+			}
+			assert_eq!(bv, expected);
+			assert_eq!(bv.capacity(), cap);
+		}
+	}
+
+	let mut bv = bitvec![0, 1, 0, 1, 0];
+	bv.dedup();
+	assert_eq!(bv, bitvec![0, 1, 0, 1, 0]);
+
+	let mut bv = bitvec![1; 100];
+	bv.dedup();
+	assert_eq!(bv, bitvec![1]);
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	bv.dedup();
+	assert!(bv.is_empty());
+
+	//  Only the relation decides; the order of arguments is (current, prior).
+	let mut bv = bitvec![0, 1, 1, 0, 0, 1];
+	bv.dedup_by(|cur, prev| !cur && prev);
+	assert_eq!(bv, bitvec![0, 1, 1, 1]);
+}
+
+#[test]
+fn extract_if() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Lsb0>();
+	for head in 0 .. 8 {
+		let base = &bits[head ..];
+		let mut bv = BitVec::from_bitslice(base);
+		let cap = bv.capacity();
+		let mut indices = Vec::new();
+		let removed = bv
+			.extract_if(|n, b| {
+				indices.push(n);
+				n % 3 == 0 || b
+			})
+			.collect::<BitVec<Lsb0, u8>>();
+		assert_eq!(indices, (0 .. base.len()).collect::<Vec<_>>());
+
+		let (mut kept_exp, mut removed_exp) =
+			(BitVec::<Lsb0, u8>::new(), BitVec::<Lsb0, u8>::new());
+		for (n, &bit) in base.iter().enumerate() {
+			if n % 3 == 0 || bit {
+				removed_exp.push(bit);
+			}
+			else {
+				kept_exp.push(bit);
+			}
+		}
+		assert_eq!(bv, kept_exp);
+		assert_eq!(removed, removed_exp);
+		assert_eq!(bv.capacity(), cap);
+	}
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	assert!(bv.extract_if(|_, _| true).next().is_none());
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn extract_if_early_drop() {
+	let mut bv = bitvec![Msb0, u8; 1, 0, 1, 1, 0, 1, 0, 1, 1, 0];
+	{
+		let mut iter = bv.extract_if(|_, b| b);
+		assert_eq!(iter.size_hint(), (0, Some(10)));
+		assert_eq!(iter.next(), Some(true));
+		assert_eq!(iter.next(), Some(true));
+		assert_eq!(iter.size_hint(), (0, Some(7)));
+	}
+	//  Removed bits 0 and 2, kept bit 1, and did not visit bits 3 and beyond.
+	assert_eq!(bv, bitvec![Msb0, u8; 0, 1, 0, 1, 0, 1, 1, 0]);
+
+	//  Dropping without advancing changes nothing.
+	drop(bv.extract_if(|_, _| true));
+	assert_eq!(bv.len(), 8);
+
+	//  The unvisited bits move down across element boundaries.
+	for (_, base) in heads::<Msb0>() {
+		for taken in 0 .. 4 {
+			let mut bv = BitVec::from_bitslice(base);
+			let mut visited = 0;
+			{
+				let mut iter = bv.extract_if(|n, _| {
+					visited = n + 1;
+					n % 5 == 0
+				});
+				for _ in 0 .. taken {
+					iter.next();
+				}
+			}
+
+			let mut expected = BitVec::<Msb0, u16>::new();
+			for (n, &bit) in base.iter().enumerate() {
+				if n >= visited || n % 5 != 0 {
+					expected.push(bit);
+				}
+			}
+			assert_eq!(bv, expected);
+		}
+	}
+
+	let mut bv = bitvec![1, 0, 1];
+	assert_eq!(bv.extract_if(|_, _| false).count(), 0);
+	assert_eq!(bv, bitvec![1, 0, 1]);
+	assert_eq!(bv.extract_if(|_, _| true).count(), 3);
+	assert!(bv.is_empty());
+}
+
+#[test]
+fn insert_bitslice() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Msb0>();
+	let src = [0xF0u8, 0x0F];
+	let src = src.bits::<Msb0>();
+	for head in 0 .. 8 {
+		let base = &bits[head .. head + 20];
+		for index in 0 ..= base.len() {
+			for count in &[0, 1, 7, 8, 13] {
+				let ins = &src[head % 4 ..][.. *count];
+				let mut bv = BitVec::from_bitslice(base);
+				bv.insert_bitslice(index, ins);
+
+				let mut expected = BitVec::<Msb0, u8>::new();
+				expected.extend(base[.. index].iter().copied());
+				expected.extend(ins.iter().copied());
+				expected.extend(base[index ..].iter().copied());
+				assert_eq!(bv, expected);
+			}
+		}
+	}
+
+	//  Self-insertion goes through an owned copy.
+	let mut bv = bitvec![0, 1, 1];
+	let copy = bv[1 ..].to_vec();
+	bv.insert_bitslice(1, &copy);
+	assert_eq!(bv, bitvec![0, 1, 1, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn insert_bitslice_out_of_bounds() {
+	let mut bv = bitvec![0, 1];
+	bv.insert_bitslice(3, &bitvec![1]);
+}
+
+#[test]
+fn retain() {
+	//  The previous implementation, which removed bits one at a time.
+	fn retain_by_remove<F>(bv: &mut BitVec<Msb0, u16>, mut pred: F)
+	where F: FnMut(usize, bool) -> bool {
+		for n in (0 .. bv.len()).rev() {
+			if !pred(n, bv[n]) {
+				bv.remove(n);
+			}
+		}
+	}
+
+	let mut next = xorshift();
+
+	for _ in 0 .. 64 {
+		let head = (next() % 16) as usize;
+		let len = (next() % 300) as usize;
+		let mut base = BitVec::<Msb0, u16>::new();
+		for _ in 0 .. head + len {
+			base.push(next() & 1 == 1);
+		}
+		let base = &base[head ..];
+		let keep = next();
+		let pred = |n: usize, b: bool| (keep >> (n % 64)) & 1 == 1 || b;
+
+		let mut bv = BitVec::from_bitslice(base);
+		let mut expected = BitVec::from_bitslice(base);
+		let cap = bv.capacity();
+
+		let mut indices = Vec::new();
+		bv.retain(|n, b| {
+			indices.push(n);
+			pred(n, b)
+		});
+		retain_by_remove(&mut expected, pred);
+
+		assert_eq!(bv, expected);
+		assert_eq!(indices, (0 .. base.len()).collect::<Vec<_>>());
+		assert_eq!(bv.capacity(), cap);
+	}
+}
+
+#[test]
+fn append() {
+	let data = [0x96u8, 0x69, 0x3C, 0xA5];
+	let bits = data.bits::<Lsb0>();
+	for self_len in 0 .. 20 {
+		for head in 0 .. 8 {
+			let mut bv = BitVec::from_bitslice(&bits[3 ..][.. self_len]);
+			let mut other = BitVec::from_bitslice(&bits[head ..][.. 17]);
+			let other_cap = other.capacity();
+
+			let mut expected = bv.clone();
+			expected.extend(other.iter().copied());
+
+			bv.append(&mut other);
+			assert_eq!(bv, expected);
+			assert!(other.is_empty());
+			assert_eq!(other.capacity(), other_cap);
+		}
+	}
+
+	let mut bv = BitVec::<Lsb0, u8>::from_bitslice(&bits[.. 5]);
+	let mut other = BitVec::<Msb0, u32>::from_bitslice(
+		&0x1234_5678u32.bits::<Msb0>()[3 .. 15],
+	);
+	let mut expected = bv.clone();
+	expected.extend(other.iter().copied());
+	bv.append_any(&mut other);
+	assert_eq!(bv, expected);
+	assert!(other.is_empty());
+}
+
+#[test]
+fn from_bitslice() {
+	for (_, base) in heads::<Msb0>() {
+		for len in 0 .. base.len() {
+			let src = &base[.. len];
+			let bv = BitVec::from_bitslice(src);
+			assert_eq!(bv, src);
+			assert_eq!(bv.bitptr().head(), src.bitptr().head());
+		}
+	}
+}
+
+#[test]
+fn extend() {
+	let bits = pattern::<Lsb0>();
+	for (_, rest) in heads::<Lsb0>() {
+		for len in 0 .. 20 {
+			for count in &[0, 1, 5, 16, 21, 33] {
+				let base = &rest[.. len];
+				let src = &bits[7 ..][.. *count];
+				let mut bv = BitVec::from_bitslice(base);
+				//  `filter` hides the length, so the vector must grow mid-way.
+				bv.extend(src.iter().copied().filter(|_| true));
+
+				let mut expected = BitVec::from_bitslice(base);
+				for &bit in src {
+					expected.push(bit);
+				}
+				assert_eq!(bv, expected);
+			}
+		}
+	}
+
+	//  Fresh elements do not keep stale memory in their dead bits.
+	let mut bv = BitVec::<Lsb0, u8>::with_capacity(16);
+	bv.extend(bits![1; 16].iter().copied());
+	bv.clear();
+	bv.extend(bits![1, 0, 1].iter().copied());
+	assert_eq!(bv.as_slice(), &[0b101]);
+}
+
+#[test]
+fn splice_bitslice() {
+	let bits = pattern::<Msb0>();
+	let base = &bits[3 .. 30];
+	let src = &bits[5 ..];
+	let len = base.len();
+	for &(from, upto) in &[(0, 0), (0, 5), (4, 9), (10, 27), (27, 27), (0, 27)] {
+		//  Shrink, keep equal, and grow.
+		for &count in &[0, upto - from, upto - from + 9] {
+			let replacement = &src[.. count];
+			let mut bv = BitVec::from_bitslice(base);
+			let removed = bv.splice_bitslice(from .. upto, replacement);
+			assert_eq!(removed, &base[from .. upto]);
+
+			let mut expected = BitVec::from_bitslice(&base[.. from]);
+			expected.extend_from_bitslice(replacement);
+			expected.extend_from_bitslice(&base[upto ..]);
+			assert_eq!(bv, expected);
+			assert_eq!(bv.len(), len - (upto - from) + count);
+		}
+	}
+
+	let mut bv = bitvec![0, 1, 0];
+	assert_eq!(bv.splice_bitslice(.., bits![1, 1]), bitvec![0, 1, 0]);
+	assert_eq!(bv, bitvec![1, 1]);
+	assert_eq!(bv.splice_bitslice(1 ..= 1, bits![0]), bitvec![1]);
+	assert_eq!(bv, bitvec![1, 0]);
+}
+
+#[test]
+#[should_panic]
+fn splice_bitslice_out_of_bounds() {
+	let mut bv = bitvec![0, 1, 0];
+	bv.splice_bitslice(2 .. 4, bits![1]);
+}
+
+#[test]
+fn drain_as_bits() {
+	let mut bv = bitvec![Msb0, u8; 0, 1, 1, 0, 1, 0, 0, 1, 1];
+	{
+		let mut drain = bv.drain(2 .. 8);
+		assert_eq!(drain.as_bits(), bits![1, 0, 1, 0, 0, 1]);
+		assert_eq!(drain.next(), Some(true));
+		assert_eq!(drain.next_back(), Some(true));
+		assert_eq!(drain.as_bits(), bits![0, 1, 0, 0]);
+		assert_eq!(
+			format!("{:?}", drain),
+			format!("Drain({:?})", drain.as_bits()),
+		);
+		drain.by_ref().for_each(drop);
+		assert!(drain.as_bits().is_empty());
+	}
+	assert_eq!(bv, bitvec![Msb0, u8; 0, 1, 1]);
+}
+
+#[test]
+fn drain_double_ended() {
+	let bits = pattern::<Lsb0>();
+	for &(from, upto) in &[(0, 48), (3, 45), (5, 6), (16, 16)] {
+		//  Alternate ends in every pattern of four steps, then drop the drain.
+		for pattern in 0 .. 16u8 {
+			let mut bv = BitVec::from_bitslice(bits);
+			let mut front = from;
+			let mut back = upto;
+			{
+				let mut drain = bv.drain(from .. upto);
+				for step in 0 .. 4 {
+					assert_eq!(drain.len(), back - front);
+					let live = front < back;
+					if pattern & (1 << step) == 0 {
+						let bit = drain.next();
+						assert_eq!(
+							bit,
+							if live { Some(bits[front]) } else { None }
+						);
+						front += live as usize;
+					}
+					else {
+						let bit = drain.next_back();
+						assert_eq!(
+							bit,
+							if live { Some(bits[back - 1]) } else { None }
+						);
+						back -= live as usize;
+					}
+				}
+				assert_eq!(drain.len(), back - front);
+				assert_eq!(drain.as_bits(), &bits[front .. back]);
+			}
+
+			let mut expected = BitVec::from_bitslice(&bits[.. from]);
+			expected.extend_from_bitslice(&bits[upto ..]);
+			assert_eq!(bv, expected);
+		}
+	}
+
+	//  Once exhausted from the back, the front stays exhausted.
+	let mut bv = bitvec![1, 0, 1];
+	let mut drain = bv.drain(..);
+	assert_eq!(drain.by_ref().rev().collect::<BitVec>(), bitvec![1, 0, 1]);
+	assert_eq!(drain.next(), None);
+	assert_eq!(drain.next_back(), None);
+	assert_eq!(drain.len(), 0);
+}
+
+#[test]
+fn drain_keep_rest() {
+	for (_, base) in heads::<Msb0>() {
+		//  Drain 10 bits, consume 3, and keep the other 7.
+		for front in 0 ..= 3 {
+			let mut bv = BitVec::from_bitslice(base);
+			{
+				let mut drain = bv.drain(2 .. 12);
+				for _ in 0 .. front {
+					drain.next();
+				}
+				for _ in front .. 3 {
+					drain.next_back();
+				}
+				drain.keep_rest();
+			}
+
+			let mut expected = BitVec::from_bitslice(&base[.. 2]);
+			expected.extend_from_bitslice(&base[2 + front .. 9 + front]);
+			expected.extend_from_bitslice(&base[12 ..]);
+			assert_eq!(bv, expected);
+			assert_eq!(bv.len(), base.len() - 3);
+		}
+	}
+
+	//  Drains that start at the front of the vector.
+	let mut bv = bitvec![0, 1];
+	bv.drain(..).keep_rest();
+	assert_eq!(bv, bitvec![0, 1]);
+
+	let mut bv = bitvec![0, 1, 1, 0, 1];
+	let mut drain = bv.drain(..);
+	assert_eq!(drain.next(), Some(false));
+	drain.keep_rest();
+	assert_eq!(bv, bitvec![1, 1, 0, 1]);
+
+	for (_, base) in heads::<Msb0>() {
+		for upto in 1 ..= 16 {
+			let mut bv = BitVec::from_bitslice(base);
+			{
+				let mut drain = bv.drain(0 .. upto);
+				drain.next();
+				drain.keep_rest();
+			}
+			assert_eq!(bv, BitVec::from_bitslice(&base[1 ..]));
+		}
+	}
+
+	//  Keeping an exhausted drain removes the whole range.
+	let mut bv = bitvec![0, 1, 1, 0];
+	let mut drain = bv.drain(1 .. 3);
+	drain.by_ref().for_each(drop);
+	drain.keep_rest();
+	assert_eq!(bv, bitvec![0, 0]);
+}
+
+#[test]
+fn resize() {
+	for (head, bits) in heads::<Lsb0>() {
+		for len in 0 .. 17 {
+			let base = &bits[.. len];
+			//  Growth within the tail element, to its edge, and beyond it.
+			for grow in &[0, 1, 16 - (head + len) % 16, 40] {
+				for &value in &[false, true] {
+					let mut bv = BitVec::from_bitslice(base);
+					bv.resize(len + grow, value);
+
+					let mut expected = BitVec::from_bitslice(base);
+					for _ in 0 .. *grow {
+						expected.push(value);
+					}
+					assert_eq!(bv, expected);
+				}
+			}
+		}
+	}
+
+	//  Growth from an empty, unallocated vector.
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	assert_eq!(bv.capacity(), 0);
+	bv.resize(21, true);
+	assert_eq!(bv.len(), 21);
+	assert!(bv.all());
+	assert_eq!(bitvec![Lsb0, u8; 1; 21], bv);
+}
+
+#[test]
+fn set_uninitialized() {
+	use std::{
+		collections::hash_map::DefaultHasher,
+		hash::{
+			Hash,
+			Hasher,
+		},
+	};
+	fn hash(elts: &[u16]) -> u64 {
+		let mut hasher = DefaultHasher::new();
+		elts.hash(&mut hasher);
+		hasher.finish()
+	}
+
+	for (_, bits) in heads::<Msb0>() {
+		for len in 0 .. 30 {
+			let live = &bits[.. len];
+			//  Two vectors with the same live bits, and different dead bits.
+			let mut a = BitVec::<Msb0, u16>::from_bitslice(live);
+			let mut b = BitVec::<Msb0, u16>::from_bitslice(live);
+			a.reserve(40);
+			b.reserve(40);
+			b.set_elements(!0);
+			b[..].clone_from_slice(live);
+			b.spare_capacity_mut()
+				.iter_mut()
+				.for_each(|elt| *elt = MaybeUninit::new(!0));
+
+			for &value in &[false, true] {
+				a.set_uninitialized(value);
+				b.set_uninitialized(value);
+				assert_eq!(a, live);
+				assert_eq!(b, live);
+				assert_eq!(a.as_slice(), b.as_slice());
+				assert_eq!(hash(a.as_slice()), hash(b.as_slice()));
+
+				let fill = if value { !0 } else { 0 };
+				for elt in a.spare_capacity_mut() {
+					assert_eq!(unsafe { elt.assume_init() }, fill);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn truncate_zeroed() {
+	for (head, base) in heads::<Lsb0>() {
+		for new_len in 0 .. 33 {
+			let mut bv = BitVec::<Lsb0, u16>::from_bitslice(base);
+			let len = bv.len();
+			bv.set_elements(!0);
+			bv[..].clone_from_slice(base);
+			let elts = bv.as_slice().len();
+			let ptr = bv.as_ptr();
+
+			bv.truncate_zeroed(new_len);
+			assert_eq!(bv, &base[.. new_len]);
+
+			//  Read back the whole of the old live region.
+			let raw = unsafe { core::slice::from_raw_parts(ptr, elts) };
+			let old = raw.bits::<Lsb0>();
+			assert_eq!(&old[head ..][.. new_len], &base[.. new_len]);
+			assert!(old[head + new_len .. head + len].not_any());
+			//  The dead bits before the head are untouched.
+			assert!(old[.. head].all());
+		}
+	}
+
+	let mut bv = bitvec![Lsb0, u8; 1; 20];
+	bv.truncate_zeroed(30);
+	assert_eq!(bv.as_slice(), &[!0, !0, !0]);
+	//  Only the removed bit is cleared; the dead bits after it are not.
+	assert_eq!(bv.pop_zeroed(), Some(true));
+	assert_eq!(bv.as_slice(), &[!0, !0, 0xF7]);
+	bv.clear_zeroed();
+	assert!(bv.is_empty());
+	assert_eq!(bv.pop_zeroed(), None);
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn zeroize() {
+	use zeroize::Zeroize;
+
+	let mut bv = BitVec::from_bitslice(&pattern::<Msb0>()[5 ..]);
+	bv.reserve(100);
+	bv.spare_capacity_mut()
+		.iter_mut()
+		.for_each(|elt| *elt = MaybeUninit::new(!0));
+	let (ptr, cap) = (bv.as_ptr(), bv.capacity);
+
+	bv.zeroize();
+	assert!(bv.is_empty());
+	assert_eq!(*bv.pointer.head(), 0);
+	let raw = unsafe { core::slice::from_raw_parts(ptr, cap) };
+	assert!(raw.iter().all(|&elt| elt == 0));
+}
+
+#[test]
+fn capacity_head() {
+	fn check<T: crate::store::BitStore>(bits: &crate::slice::BitSlice<Msb0, T>) {
+		let width = core::mem::size_of::<T>() * 8;
+		for head in 0 .. width {
+			let mut bv = BitVec::from_bitslice(&bits[head ..]);
+			assert_eq!(bv.capacity(), bv.capacity * width - head);
+
+			//  Filling to the reported capacity does not reallocate.
+			let ptr = bv.as_ptr();
+			while bv.len() < bv.capacity() {
+				bv.push(true);
+			}
+			assert_eq!(bv.as_ptr(), ptr);
+
+			//  Both reservations count from the head.
+			bv.reserve_exact(1);
+			assert!(bv.capacity() > bv.len());
+			let len = bv.len();
+			bv.reserve(3 * width);
+			assert!(bv.capacity() >= len + 3 * width);
+			bv.reserve_exact(0);
+			bv.reserve(0);
+			assert!(bv.capacity >= (head + bv.capacity()) / width);
+		}
+	}
+
+	check([0u8; 3].bits::<Msb0>());
+	check([0u16; 3].bits::<Msb0>());
+	check([0u32; 3].bits::<Msb0>());
+	check([0u64; 3].bits::<Msb0>());
+}
+
+/// Builds a vector at the maximum length, without any backing memory.
+///
+/// The vector must not be dropped, and its bits must not be accessed.
+fn full() -> core::mem::ManuallyDrop<BitVec<Lsb0, u8>> {
+	let bits = BitPtr::<u8>::MAX_BITS;
+	let elts = crate::mem::elts::<u8>(bits);
+	core::mem::ManuallyDrop::new(unsafe {
+		BitVec::from_raw_parts(
+			core::ptr::NonNull::dangling().as_ptr(),
+			0,
+			bits,
+			elts,
+		)
+	})
+}
+
+#[test]
+fn max_bits() {
+	let mut bv = full();
+	assert_eq!(bv.len(), BitPtr::<u8>::MAX_BITS);
+	assert_eq!(bv.try_push(true), Err(BitReserveError::CapacityOverflow));
+	assert_eq!(bv.push_within_capacity(true), Err(true));
+	assert_eq!(
+		bv.try_insert(0, true),
+		Err(BitInsertError::Reserve(BitReserveError::CapacityOverflow)),
+	);
+	let (index, len) = (bv.len() + 1, bv.len());
+	assert_eq!(
+		bv.try_insert(index, true),
+		Err(BitInsertError::OutOfBounds { index, len }),
+	);
+	assert_eq!(bv.len(), BitPtr::<u8>::MAX_BITS);
+	//  Shrinking works up to the boundary.
+	bv.truncate(BitPtr::<u8>::MAX_BITS - 1);
+	assert_eq!(bv.len(), BitPtr::<u8>::MAX_BITS - 1);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn max_bits_push() {
+	full().push(true);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn max_bits_insert() {
+	full().insert(0, true);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn max_bits_push_front() {
+	full().push_front(true);
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn max_bits_extend() {
+	full().extend(Some(true));
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn max_bits_resize() {
+	let len = BitPtr::<u8>::MAX_BITS;
+	full().resize(len + 1, true);
+}
+
+#[test]
+fn into_boxed_bitslice() {
+	for (_, bits) in heads::<Msb0>() {
+		for len in 0 .. 32 {
+			let src = &bits[.. len];
+			//  Large spare capacity forces the conversion to shrink the buffer.
+			let mut bv = BitVec::from_bitslice(src);
+			bv.reserve(1 << 16);
+
+			let bb = bv.into_boxed_bitslice();
+			assert_eq!(bb.len(), len);
+			assert!(bb.iter().zip(src.iter()).all(|(a, b)| a == b));
+			assert_eq!(bb.as_bitslice(), src);
+			if len > 0 {
+				assert_eq!(
+					bb.as_bitslice().bitptr().head(),
+					src.bitptr().head()
+				);
+			}
+		}
+	}
+}
+
+#[test]
+fn split_off() {
+	for (_, base) in heads::<Lsb0>() {
+		for at in 0 ..= base.len() {
+			let mut bv = BitVec::from_bitslice(base);
+			bv.reserve(40);
+			let cap = bv.capacity();
+			let tail = bv.split_off(at);
+			assert_eq!(bv, &base[.. at]);
+			assert_eq!(tail, &base[at ..]);
+			assert!(bv.capacity() >= cap);
+		}
+	}
+
+	//  Splitting at the front moves the buffer, and reallocates for `self`.
+	let mut bv = bitvec![Lsb0, u8; 1; 20];
+	let (ptr, cap) = (bv.as_ptr(), bv.capacity());
+	let all = bv.split_off(0);
+	assert_eq!(all.as_ptr(), ptr);
+	assert!(bv.is_empty());
+	assert!(bv.capacity() >= cap);
+	assert_eq!(all, bitvec![1; 20]);
+}
+
+#[test]
+fn push_pop_front() {
+	let bits = pattern::<Lsb0>();
+
+	//  Pushing into slack before the head neither moves nor grows the buffer,
+	//  and returns the slack to the capacity.
+	let mut bv = BitVec::from_bitslice(&bits[3 ..]);
+	let (ptr, cap) = (bv.as_ptr(), bv.capacity());
+	for n in (0 .. 3).rev() {
+		bv.push_front(bits[n]);
+		assert_eq!(bv.as_ptr(), ptr);
+		assert_eq!(*bv.pointer.head() as usize, n);
+	}
+	assert_eq!(bv, bits);
+	assert_eq!(bv.capacity(), cap + 3);
+
+	//  Pushing at the zero index inserts a new element, and leaves the head at
+	//  its far end.
+	bv.push_front(true);
+	assert_eq!(*bv.pointer.head(), 15);
+	assert_eq!(bv.as_slice().len(), 4);
+	assert!(bv[0]);
+	assert_eq!(bv[1 ..], bits);
+	assert!(bv.capacity() >= bv.len());
+
+	bv.force_align();
+	assert_eq!(*bv.pointer.head(), 0);
+	assert!(bv[0]);
+	assert_eq!(bv[1 ..], bits);
+
+	//  Popping advances the head, and discards elements it leaves.
+	let mut bv = BitVec::from_bitslice(bits);
+	for n in 0 .. 20 {
+		assert_eq!(bv.pop_front(), Some(bits[n]));
+		assert_eq!(bv, bits[n + 1 ..]);
+		assert_eq!(*bv.pointer.head() as usize, (n + 1) % 16);
+	}
+	bv.reserve(100);
+	assert!(bv.capacity() >= bv.len() + 100);
+	assert_eq!(bv, bits[20 ..]);
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	assert!(bv.pop_front().is_none());
+	bv.push_front(true);
+	assert_eq!(bv, bits![1]);
+	assert_eq!(bv.pop_front(), Some(true));
+	assert!(bv.pop_front().is_none());
+}
+
+#[test]
+fn push_pop_front_back() {
+	let mut next = xorshift();
+
+	let mut bv = BitVec::<Msb0, u8>::new();
+	let mut expected = Vec::<bool>::new();
+	for _ in 0 .. 4000 {
+		let roll = next();
+		let bit = roll & 1 == 1;
+		match (roll >> 1) % 5 {
+			0 | 1 => {
+				bv.push_front(bit);
+				expected.insert(0, bit);
+			},
+			2 => {
+				bv.push(bit);
+				expected.push(bit);
+			},
+			3 => {
+				let exp = if expected.is_empty() {
+					None
+				}
+				else {
+					Some(expected.remove(0))
+				};
+				assert_eq!(bv.pop_front(), exp);
+			},
+			_ => assert_eq!(bv.pop(), expected.pop()),
+		}
+		assert_eq!(bv.len(), expected.len());
+		assert!(bv.capacity() >= bv.len());
+		assert!(bv.iter().zip(expected.iter()).all(|(a, b)| a == b));
+	}
+
+	let mut aligned = bv.clone();
+	aligned.force_align();
+	assert_eq!(aligned, bv);
+}
+
+#[test]
+fn force_align() {
+	use crate::{
+		index::{
+			BitIdx,
+			BitPos,
+			Indexable,
+		},
+		mem::BitMemory,
+		order::BitOrder,
+	};
+
+	/// An ordering that is neither `Lsb0` nor `Msb0`, which must take the
+	/// bit-by-bit path.
+	struct PairSwap;
+
+	impl BitOrder for PairSwap {
+		const TYPENAME: &'static str = "PairSwap";
+
+		fn at<M>(place: BitIdx<M>) -> BitPos<M>
+		where M: BitMemory {
+			(*place ^ 1).pos()
+		}
+	}
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let data: [$t; 5] = [!0 / 3, !0 / 5, !0 / 7, !0 / 13, !0 / 17];
+			let bits = data.bits::<$o>();
+			let width = <$t as BitMemory>::BITS as usize;
+			for head in 0 .. width {
+				for len in &[0, 1, width - head, 2 * width + 3, 4 * width] {
+					let base = &bits[head .. head + len];
+					let mut bv = BitVec::from_bitslice(base);
+					let (ptr, cap) = (bv.pointer.pointer().r(), bv.capacity());
+					let before = bv.as_slice().to_vec();
+					bv.force_align();
+					assert_eq!(bv, base);
+					assert_eq!(*bv.pointer.head(), 0);
+					assert_eq!(bv.pointer.pointer().r(), ptr);
+					assert!(bv.capacity() >= cap);
+					assert_eq!(bv.as_slice().len(), (len + width - 1) / width);
+					//  Dead bits after the live region are left untouched.
+					let (last, live) = (len / width, len % width);
+					if live != 0 {
+						for idx in live .. width {
+							let idx = (idx as u8).idx();
+							assert_eq!(
+								bv.as_slice()[last].get::<$o>(idx),
+								before[last].get::<$o>(idx),
+							);
+						}
+					}
+				}
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8; PairSwap, u8;
+		Lsb0, u16; Msb0, u16; PairSwap, u16;
+		Lsb0, u32; Msb0, u32; PairSwap, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64; PairSwap, u64);
+}
+
+#[test]
+fn change_order_reordered() {
+	let data = [0x96A5_3C69u32, 0xF00F_5AC3, 0x1234_5678];
+	for head in 0 .. 32 {
+		for &len in &[0, 1, 31 - head, 40, 96 - head] {
+			let src = &data.bits::<Msb0>()[head .. head + len];
+			let bv = BitVec::from_bitslice(src);
+			let le = bv.clone().change_order_reordered::<Lsb0>();
+			assert_eq!(le, bv);
+			assert_eq!(le, src);
+
+			let be = le.change_order_reordered::<Msb0>();
+			assert_eq!(be, src);
+			if len > 0 {
+				assert_eq!(*be.pointer.head() as usize, head);
+				assert_eq!(be.as_slice(), bv.as_slice());
+			}
+		}
+	}
+}
+
+#[test]
+fn as_raw_bytes() {
+	macro_rules! check {
+		($($t:ty),+ $(,)?) => { $(
+			let data: [$t; 3] = [!0 / 3, !0 / 5, !0 / 7];
+			let width = core::mem::size_of::<$t>();
+			let mut bytes = Vec::new();
+			for elt in &data {
+				bytes.extend_from_slice(&elt.to_ne_bytes());
+			}
+
+			//  The partial edge elements are owned, and so are visible.
+			let src = &data.bits::<Msb0>()[3 .. 3 * width * 8 - 3];
+			let mut bv = BitVec::from_bitslice(src);
+			assert_eq!(bv.as_raw_bytes(), &bytes[..]);
+			assert_eq!(bv.as_raw_bytes().len(), 3 * width);
+
+			bv.truncate(width * 8 - 3);
+			assert_eq!(bv.as_raw_bytes(), &bytes[.. width]);
+			bv.clear();
+			assert!(bv.as_raw_bytes().is_empty());
+
+			let mut bv = BitVec::<Lsb0, $t>::repeat(false, width * 8 + 1);
+			for byte in bv.as_raw_bytes_mut() {
+				*byte = !0;
+			}
+			assert!(bv.all());
+			assert_eq!(bv.as_slice(), &[!0, !0]);
+		)+ };
+	}
+
+	check!(u8, u16, u32);
+	#[cfg(target_pointer_width = "64")]
+	check!(u64);
+}
+
+#[test]
+fn bytes_round_trip() {
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($bits:expr, $bytes:expr; $($o:ty, $t:ty);+ $(;)?) => { $(
+			let bv = BitVec::<$o, $t>::from_bytes(&$bytes, $bits.len()).unwrap();
+			assert_eq!(bv, $bits);
+			assert_eq!(bv.to_bytes(), $bytes);
+			assert_eq!(bv[..].to_bytes(), $bytes);
+			if !$bits.is_empty() {
+				assert_eq!(bv[1 ..].to_bytes(), $bits[1 ..].to_bytes());
+			}
+		)+ };
+	}
+
+	for _ in 0 .. 64 {
+		let len = (next() % 300) as usize;
+		let mut bits = BitVec::<Msb0, u8>::new();
+		for _ in 0 .. len {
+			bits.push(next() & 1 == 1);
+		}
+		bits.set_uninitialized(false);
+		let bytes = bits.as_slice().to_vec();
+		assert_eq!(bytes.len(), (len + 7) / 8);
+
+		check!(bits, bytes;
+			Lsb0, u8; Msb0, u8;
+			Lsb0, u16; Msb0, u16;
+			Lsb0, u32; Msb0, u32;
+		);
+		#[cfg(target_pointer_width = "64")]
+		check!(bits, bytes; Lsb0, u64; Msb0, u64);
+
+		let whole = bytes.len() * 8;
+		assert!(BitVec::<Lsb0, u32>::from_bytes(&bytes, whole).is_some());
+		assert!(BitVec::<Lsb0, u32>::from_bytes(&bytes, whole + 1).is_none());
+	}
+}
+
+#[test]
+fn try_insert() {
+	let mut bv = bitvec![Msb0, u8; 0, 1, 0, 1, 1, 0, 0, 1, 0];
+	let before = bv.clone();
+	let cap = bv.capacity();
+	for &index in &[10, 11, !0] {
+		assert_eq!(
+			bv.try_insert(index, true),
+			Err(BitInsertError::OutOfBounds { index, len: 9 }),
+		);
+		assert_eq!(bv, before);
+		assert_eq!(bv.capacity(), cap);
+	}
+
+	for index in 0 ..= 9 {
+		let mut bv = before.clone();
+		let mut expected = before.clone();
+		bv.try_insert(index, true).unwrap();
+		expected.insert(index, true);
+		assert_eq!(bv, expected);
+	}
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_write() {
+	use std::io::{
+		self,
+		Cursor,
+		Write,
+	};
+
+	let data = [0x96u8, 0xA5, 0x3C, 0x0F, 0xF0];
+
+	let mut bv = BitVec::<Msb0, u8>::new();
+	assert_eq!(io::copy(&mut Cursor::new(&data[..]), &mut bv).unwrap(), 5);
+	assert_eq!(bv, data.bits::<Msb0>());
+	assert_eq!(bv.as_slice(), &data[..]);
+
+	let mut bv = BitVec::<Lsb0, u8>::new();
+	io::copy(&mut Cursor::new(&data[..]), &mut bv).unwrap();
+	assert_eq!(bv, data.bits::<Lsb0>());
+	assert_eq!(bv.as_slice(), &data[..]);
+	bv.flush().unwrap();
+
+	//  An unaligned vector takes every byte, split across elements.
+	for &shift in &[false, true] {
+		let mut bv = BitVec::<Msb0, u8>::from_bitslice(&0xC0u8.bits()[.. 3]);
+		if shift {
+			bv.truncate_front(1);
+		}
+		let prefix = bv.clone();
+		bv.write_all(&data).unwrap();
+		assert_eq!(bv[.. prefix.len()], prefix);
+		assert_eq!(bv[prefix.len() ..], data.bits::<Msb0>());
+	}
+
+	let mut bv = full();
+	let err = bv.write(&data).unwrap_err();
+	assert_eq!(err.kind(), io::ErrorKind::Other);
+	assert_eq!(bv.len(), BitPtr::<u8>::MAX_BITS);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn io_read() {
+	use std::io::{
+		self,
+		Read,
+	};
+
+	/// Produces three bytes per read, and is interrupted before every fourth
+	/// read. It fails once its data runs out, if `fail` is set.
+	struct Chunked<'a> {
+		data: &'a [u8],
+		calls: usize,
+		fail: bool,
+	}
+
+	impl Read for Chunked<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+			self.calls += 1;
+			if self.calls % 4 == 0 {
+				return Err(io::ErrorKind::Interrupted.into());
+			}
+			if self.data.is_empty() && self.fail {
+				return Err(io::ErrorKind::UnexpectedEof.into());
+			}
+			let n = self.data.len().min(buf.len()).min(3);
+			buf[.. n].copy_from_slice(&self.data[.. n]);
+			self.data = &self.data[n ..];
+			Ok(n)
+		}
+	}
+
+	let data = (0 .. 1000u32)
+		.map(|n| (n * 37 % 251) as u8)
+		.collect::<Vec<_>>();
+	let reader = |data| Chunked {
+		data,
+		calls: 0,
+		fail: false,
+	};
+
+	let bv = BitVec::<Msb0, u8>::from_reader(reader(&data)).unwrap();
+	let mut bytes = Vec::new();
+	reader(&data).read_to_end(&mut bytes).unwrap();
+	assert_eq!(bv, BitVec::<Msb0, u8>::from_vec(bytes));
+	assert_eq!(bv.as_slice(), &data[..]);
+
+	let bv = BitVec::<Lsb0, u8>::from_reader(&[][..]).unwrap();
+	assert!(bv.is_empty());
+
+	//  Appending to an unaligned vector goes through the stack buffer.
+	for &head in &[0, 3] {
+		let src = &0xB5u8.bits::<Lsb0>()[head .. 5];
+		let mut bv = BitVec::from_bitslice(src);
+		assert_eq!(bv.read_from(reader(&data)).unwrap(), data.len());
+		assert_eq!(bv[.. src.len()], src);
+		assert_eq!(bv[src.len() ..], data.bits::<Lsb0>());
+	}
+
+	//  An error keeps the bytes read before it.
+	let mut bv = bitvec![Msb0, u8; 1; 8];
+	let err = bv
+		.read_from(Chunked {
+			data: &data[.. 10],
+			calls: 0,
+			fail: true,
+		})
+		.unwrap_err();
+	assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+	assert_eq!(bv[8 ..], data[.. 10].bits::<Msb0>());
+}
+
+#[test]
+fn extend_from_raw_slice() {
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let data: [$t; 4] = [!0 / 3, !0 / 5, !0 / 7, !0 / 13];
+			let bits = data.bits::<$o>();
+			let width = <$t as crate::mem::BitMemory>::BITS as usize;
+			for head in 0 .. width {
+				for len in 0 .. 2 * width {
+					let prefix = &bits[head .. head + len];
+					let mut bv = BitVec::from_bitslice(prefix);
+					//  Dead bits must not leak into the appended region.
+					bv.set_uninitialized(true);
+					bv.extend_from_raw_slice(&data[1 ..]);
+					assert_eq!(bv[.. len], prefix);
+					assert_eq!(bv[len ..], data[1 ..].bits::<$o>());
+
+					bv.extend_from_raw_slice(&[]);
+					assert_eq!(bv.len(), len + 3 * width);
+				}
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+
+	//  An aligned vector takes the elements unchanged.
+	let mut bv = BitVec::<Msb0, u16>::new();
+	bv.extend_from_raw_slice(&[0x1234, 0x5678]);
+	assert_eq!(bv.as_slice(), &[0x1234, 0x5678]);
+}
+
+#[test]
+fn into_iter() {
+	let bits = &pattern::<Msb0>()[3 ..];
+	let bv = BitVec::from_bitslice(bits);
+
+	let mut iter = bv.clone().into_iter();
+	let (mut front, mut back) = (0, bits.len());
+	for step in 0 .. 12 {
+		assert_eq!(iter.len(), back - front);
+		assert_eq!(iter.size_hint(), (back - front, Some(back - front)));
+		assert_eq!(iter.as_bits(), &bits[front .. back]);
+		match step % 3 {
+			0 => {
+				assert_eq!(iter.nth(4), Some(bits[front + 4]));
+				front += 5;
+			},
+			1 => {
+				assert_eq!(iter.next_back(), Some(bits[back - 1]));
+				back -= 1;
+			},
+			_ => {
+				assert_eq!(iter.next(), Some(bits[front]));
+				front += 1;
+			},
+		}
+	}
+	assert_eq!(iter.count(), back - front);
+
+	//  Overshooting empties the iterator.
+	let mut iter = bv.clone().into_iter();
+	assert!(iter.nth(bits.len()).is_none());
+	assert_eq!(iter.len(), 0);
+	assert!(iter.as_bits().is_empty());
+	assert!(iter.next().is_none());
+	assert!(iter.next_back().is_none());
+
+	let mut iter = bv.clone().into_iter();
+	iter.next_back();
+	assert_eq!(iter.last(), Some(bits[bits.len() - 2]));
+
+	let skipped = bv.into_iter().skip(20).take(8).collect::<BitVec>();
+	assert_eq!(skipped, bits[20 .. 28]);
+}
+
+#[test]
+fn bool_vec() {
+	for len in &[0, 1, 7, 8, 9, 15, 16, 17, 31, 32, 33, 63, 64, 65, 200] {
+		let bools = (0 .. *len).map(|n| n % 3 == 1).collect::<Vec<_>>();
+
+		let a = BitVec::<Msb0, u8>::from(bools.clone());
+		let b = BitVec::<Lsb0, u32>::from(&bools[..]);
+		assert_eq!(a.len(), *len);
+		assert_eq!(a, b);
+		assert!(a.iter().zip(bools.iter()).all(|(a, b)| a == b));
+
+		assert_eq!(a.to_bool_vec(), bools);
+		assert_eq!(b[..].to_bool_vec(), bools);
+		assert_eq!(Vec::<bool>::from(b), bools);
+	}
+}
+
+#[test]
+fn from_uint() {
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let value = 0x96A5_3C69_F00F_5AC3u64;
+			for width in 0 ..= 64 {
+				let bv = BitVec::<$o, $t>::from_uint(value, width);
+				assert_eq!(bv.len(), width);
+				let mask = if width == 64 { !0 } else { (1 << width) - 1 };
+				assert_eq!(bv.to_uint::<u64>(), Some(value & mask));
+				for (n, bit) in bv.iter().enumerate() {
+					assert_eq!(*bit, value >> (width - 1 - n) & 1 == 1);
+				}
+			}
+
+			//  Wider than the source, the value is zero-extended.
+			let bv = BitVec::<$o, $t>::from_uint(0xA5u8, 20);
+			assert!(bv[.. 12].not_any());
+			assert_eq!(bv[12 ..].to_uint::<u8>(), Some(0xA5));
+			assert_eq!(bv.to_uint::<u32>(), Some(0xA5));
+
+			//  Writing into a slice leaves its neighbors alone.
+			let mut bv = BitVec::<$o, $t>::repeat(true, 80);
+			bv[5 .. 75].set_uint(0u64);
+			assert!(bv[.. 5].all() && bv[75 ..].all());
+			assert!(bv[5 .. 75].not_any());
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+}
+
+#[test]
+fn rotate() {
+	let mut next = xorshift();
+
+	macro_rules! check {
+		($($o:ty, $t:ty);+ $(;)?) => { $(
+			let width = <$t>::max_value().count_ones() as usize;
+			for _ in 0 .. 64 {
+				let data = [next(), next(), next(), next()];
+				let bits = data.bits::<$o>();
+				//  Every other vector starts and ends on element boundaries.
+				let (head, len) = if next() % 2 == 0 {
+					(0, (next() % 5) as usize * width)
+				}
+				else {
+					((next() % 16) as usize, (next() % 200) as usize)
+				};
+				let mut base = bits[.. head + len]
+					.iter()
+					.copied()
+					.collect::<BitVec<$o, $t>>();
+				base.truncate_front(head);
+				let by = match next() % 3 {
+					0 => (next() % 5) as usize * width,
+					_ => (next() as usize) % (len + 1),
+				};
+				let by = cmp::min(by, len);
+
+				let mut expected = base.clone();
+				expected.as_mut_bitslice().rotate_left(by);
+				let mut bv = base.clone();
+				bv.rotate_left(by);
+				assert_eq!(bv, expected);
+
+				let mut expected = base.clone();
+				expected.as_mut_bitslice().rotate_right(by);
+				let mut bv = base.clone();
+				bv.rotate_right(by);
+				assert_eq!(bv, expected);
+			}
+		)+ };
+	}
+
+	check!(
+		Lsb0, u8; Msb0, u8;
+		Lsb0, u16; Msb0, u16;
+		Lsb0, u32; Msb0, u32;
+	);
+	#[cfg(target_pointer_width = "64")]
+	check!(Lsb0, u64; Msb0, u64);
+}
+
+#[test]
+#[should_panic(expected = "Slices cannot be rotated by more than their length")]
+fn rotate_past_len() {
+	let mut bv = BitVec::<Msb0, u8>::repeat(true, 10);
+	bv.rotate_right(11);
+}
+
+#[test]
+fn length_policies() {
+	let lhs_data = [0x5Au8, 0xC3, 0x96, 0x0F, 0xE1];
+	let rhs_data = [0x1234u16, 0xABCD, 0x0FF0];
+	let lhs_all = lhs_data.bits::<Msb0>();
+	let rhs_all = rhs_data.bits::<Lsb0>();
+	for &lhs_head in &[0, 3] {
+		for &lhs_len in &[0, 5, 8, 13, 30] {
+			let lhs = &lhs_all[lhs_head ..][.. lhs_len];
+			for &rhs_len in &[0, 5, 8, 13, 30] {
+				let rhs = &rhs_all[7 ..][.. rhs_len];
+				let short = cmp::min(lhs_len, rhs_len);
+
+				//  AND drops every bit of `self` past the end of `other`.
+				let mut bv = BitVec::<Msb0, u8>::from_bitslice(lhs);
+				bv.and_assign_truncate(rhs);
+				assert_eq!(bv.len(), short);
+				for idx in 0 .. short {
+					assert_eq!(bv[idx], lhs[idx] & rhs[idx]);
+				}
+
+				//  OR keeps the excess bits of `self`, and copies those of
+				//  `other`.
+				let mut bv = BitVec::<Msb0, u8>::from_bitslice(lhs);
+				bv.or_assign_extend(rhs);
+				assert_eq!(bv.len(), cmp::max(lhs_len, rhs_len));
+				for idx in 0 .. bv.len() {
+					let l = lhs.get(idx).copied().unwrap_or(false);
+					let r = rhs.get(idx).copied().unwrap_or(false);
+					assert_eq!(bv[idx], l | r);
+				}
+
+				//  XOR requires the lengths to match.
+				if lhs_len == rhs_len {
+					let mut bv = BitVec::<Msb0, u8>::from_bitslice(lhs);
+					bv.xor_assign_strict(rhs);
+					for idx in 0 .. short {
+						assert_eq!(bv[idx], lhs[idx] ^ rhs[idx]);
+					}
+				}
+			}
+		}
+	}
+}
+
+#[test]
+#[should_panic(expected = "Strict XOR requires equal lengths")]
+fn xor_assign_strict_mismatch() {
+	let mut bv = BitVec::<Msb0, u8>::repeat(true, 10);
+	bv.xor_assign_strict(&BitVec::<Lsb0, u16>::repeat(true, 9));
+}
+
+#[test]
+fn set_algebra() {
+	let a_data = [0x5Au8, 0xC3, 0x96, 0x0F];
+	let b_data = [0x1234u16, 0xABCD];
+	for &a_len in &[0, 3, 8, 17, 29] {
+		for &b_len in &[0, 5, 8, 21, 30] {
+			let a = &a_data.bits::<Msb0>()[2 ..][.. a_len];
+			let b = &b_data.bits::<Lsb0>()[1 ..][.. b_len];
+			let len = cmp::max(a_len, b_len);
+
+			let sets = [
+				a.union(b),
+				a.intersection(b),
+				a.difference(b),
+				a.symmetric_difference(b),
+			];
+			let mut with =
+				[a.to_owned(), a.to_owned(), a.to_owned(), a.to_owned()];
+			with[0].union_with(b);
+			with[1].intersection_with(b);
+			with[2].difference_with(b);
+			with[3].symmetric_difference_with(b);
+			for (set, with) in sets.iter().zip(with.iter()) {
+				assert_eq!(set.len(), len);
+				assert_eq!(set, with);
+			}
+			for idx in 0 .. len {
+				let x = a.get(idx).copied().unwrap_or(false);
+				let y = b.get(idx).copied().unwrap_or(false);
+				assert_eq!(sets[0][idx], x | y);
+				assert_eq!(sets[1][idx], x & y);
+				assert_eq!(sets[2][idx], x & !y);
+				assert_eq!(sets[3][idx], x ^ y);
+			}
+		}
+	}
+
+	//  Self-union and self-intersection are the identity, and self-difference
+	//  is empty.
+	let a = &a_data.bits::<Msb0>()[3 .. 27];
+	assert_eq!(a.union(a), a);
+	assert_eq!(a.intersection(a), a);
+	assert!(a.difference(a).not_any());
+	assert!(a.symmetric_difference(a).not_any());
+
+	//  Disjoint sets.
+	let evens = BitVec::<Lsb0, u8>::from_element(0x55);
+	let odds = &0x5555u16.bits::<Msb0>()[.. 8];
+	assert!(evens.intersection(odds).not_any());
+	assert!(evens.union(odds).all());
+	assert_eq!(evens.difference(odds), evens);
+	assert_eq!(evens.symmetric_difference(odds), evens.union(odds));
+}
+
+#[test]
+fn replace() {
+	let mut next = xorshift();
+
+	//  HDLC bit-stuffing inserts a 0 after every five consecutive 1s.
+	let five = bits![Lsb0, u8; 1, 1, 1, 1, 1];
+	let stuffed = bits![Lsb0, u8; 1, 1, 1, 1, 1, 0];
+	for _ in 0 .. 64 {
+		let data = [next() | next(), next() | next(), next(), !0];
+		let head = (next() % 32) as usize;
+		let len = (next() % 200) as usize;
+		let bits = &data.bits::<Msb0>()[head .. head + len];
+
+		let out = bits.replace(five, stuffed);
+		assert!(out.find(bits![1; 6]).is_none());
+		assert_eq!(out.len(), len + bits.split_pattern(five).count() - 1);
+		assert_eq!(out.replace(stuffed, five), bits);
+	}
+
+	//  Matches do not overlap, and replacements are not rescanned.
+	let bits = bits![Msb0, u16; 1, 1, 1, 0, 1, 1];
+	assert_eq!(bits.replace(bits![1, 1], bits![0]), bits![0, 1, 0, 0]);
+	let bits = bits![1, 0, 1];
+	let out = bits.replace(bits![1], bits![1, 1]);
+	assert_eq!(out, bits![1, 1, 0, 1, 1]);
+	assert_eq!(bits.replace(bits![1, 0], bits![0; 0]), bits![1]);
+	assert_eq!(bits.replace(bits![0, 0], bits![1]), bits);
+
+	//  Limited replacement.
+	let bits = bits![1, 1, 1, 1, 1];
+	assert_eq!(bits.replacen(bits![1], bits![0], 2), bits![0, 0, 1, 1, 1]);
+	assert_eq!(bits.replacen(bits![1], bits![0], 0), bits);
+	assert_eq!(bits.replacen(bits![1, 1], bits![0], 9), bits![0, 0, 1]);
+}
+
+#[test]
+fn runs_round_trip() {
+	let mut next = xorshift();
+
+	let data = [next(), 0, next() & next(), !0, !0, next() | next(), next()];
+	for _ in 0 .. 128 {
+		let head = (next() % 64) as usize;
+		let len = (next() % 384) as usize;
+		let bits = &data.bits::<Lsb0>()[head .. head + len];
+		let runs = bits.to_runs();
+		assert_eq!(runs.iter().map(|&(_, n)| n).sum::<usize>(), len);
+		let bv = BitVec::<Msb0, u16>::from_runs(runs.iter().copied());
+		assert_eq!(bv, bits);
+		assert_eq!(bv.to_runs(), runs);
+	}
+
+	//  A single enormous run, between short ones.
+	let runs = [(false, 3), (true, 1 << 22), (false, 5)];
+	let bv = BitVec::<Lsb0, usize>::from_runs(runs.iter().copied());
+	assert_eq!(bv.len(), (1 << 22) + 8);
+	assert_eq!(bv.count_ones(), 1 << 22);
+	assert_eq!(bv.to_runs(), runs);
+
+	//  Empty runs, and repeated values, are merged.
+	let runs = vec![(true, 2), (false, 0), (true, 3)];
+	let bv = BitVec::<Lsb0, u8>::from_runs(runs);
+	assert_eq!(bv.to_runs(), [(true, 5)]);
+	assert!(BitVec::<Lsb0, u8>::from_runs(None).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn from_runs_overflow() {
+	let max = BitPtr::<u8>::MAX_BITS;
+	BitVec::<Lsb0, u8>::from_runs(vec![(true, 1), (false, max)]);
+}
+
+#[test]
+fn split_at_mut() {
+	let mut bv = BitVec::<Msb0, u8>::repeat(false, 12);
+	let (left, right) = bv.split_at_mut(5);
+	left.set(4, true);
+	right.set(0, true);
+	right[3 ..].set_all(true);
+	assert_eq!(bv.as_slice(), &[0x0C, 0xF0]);
+}
+
+#[test]
+fn fill() {
+	let mut bv = BitVec::<Msb0, u8>::repeat(false, 20);
+	bv.fill(true);
+	assert!(bv.all());
+	assert_eq!(bv.as_slice(), &[0xFF, 0xFF, 0xF0]);
+
+	bv[3 .. 13].fill(false);
+	assert_eq!(bv.as_slice(), &[0xE0, 0x07, 0xF0]);
+}
+
+#[test]
+fn concat() {
+	let data = [0x5Au8, 0xC3, 0x96, 0x0F];
+	let bits = data.bits::<Msb0>();
+
+	//  Pieces with every combination of head and tail alignment, and an empty
+	//  piece in the middle.
+	let pieces = [
+		&bits[3 .. 6],
+		&bits[8 .. 16],
+		&bits[16 .. 16],
+		&bits[5 .. 21],
+		&bits[20 .. 32],
+	];
+	let mut model = BitVec::<Msb0, u8>::new();
+	for piece in pieces.iter() {
+		model.extend(piece.iter().copied());
+	}
+
+	let bv = pieces.concat();
+	assert_eq!(bv, model);
+	assert!(bv.capacity() >= bv.len());
+	assert_eq!(pieces.to_vec().concat(), model);
+
+	let owned = pieces
+		.iter()
+		.map(|piece| piece.to_vec())
+		.collect::<Vec<_>>();
+	assert_eq!(owned.concat(), model);
+
+	let empty: [&BitSlice<Msb0, u8>; 0] = [];
+	assert!(empty.concat().is_empty());
+}
+
+#[test]
+fn join() {
+	let data = [0x5Au8, 0xC3, 0x96, 0x0F];
+	let bits = data.bits::<Msb0>();
+	let sep = &bits[6 .. 9];
+
+	let pieces = [&bits[3 .. 6], &bits[16 .. 16], &bits[5 .. 21]];
+	let mut model = BitVec::<Msb0, u8>::new();
+	for (idx, piece) in pieces.iter().enumerate() {
+		if idx > 0 {
+			model.extend(sep.iter().copied());
+		}
+		model.extend(piece.iter().copied());
+	}
+	assert_eq!(pieces.join(sep), model);
+
+	//  A single piece has no separators.
+	assert_eq!(pieces[.. 1].join(sep), pieces[0]);
+	let empty: [&BitSlice<Msb0, u8>; 0] = [];
+	assert!(empty.join(sep).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Capacity overflow")]
+fn concat_overflow() {
+	let max = BitPtr::<u8>::MAX_BITS;
+	let data = 0u8;
+	//  Only the lengths of the pieces are inspected before the panic, so the
+	//  memory behind them is never read.
+	let huge = unsafe { BitSlice::<Lsb0, u8>::from_raw_parts(&data, 0, max) };
+	[&data.bits::<Lsb0>()[.. 1], huge].concat();
+}
@@ -40,6 +40,24 @@ use core::{
 	mem,
 };
 
+#[cfg(feature = "zeroize")]
+use crate::index::BitIdx;
+
+#[cfg(feature = "zeroize")]
+use core::{
+	ptr,
+	sync::atomic,
+};
+
+#[cfg(feature = "std")]
+use std::io::{
+	self,
+	Write,
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 /// Signifies that `BitSlice` is the borrowed form of `BitVec`.
 impl<O, T> Borrow<BitSlice<O, T>> for BitVec<O, T>
 where
@@ -415,8 +433,8 @@ where
 
 /** Builds a `BitVec` out of a slice of `bool`.
 
-This is primarily for the `bitvec!` macro; it is not recommended for general
-use.
+The vector is allocated once, and the `bool`s are packed one memory element at
+a time, as by `FromIterator`.
 **/
 impl<O, T> From<&[bool]> for BitVec<O, T>
 where
@@ -428,6 +446,37 @@ where
 	}
 }
 
+/** Builds a `BitVec` out of a `Vec` of `bool`.
+
+This packs the `bool`s in the same way as `From<&[bool]>`, and then releases the
+source buffer.
+**/
+impl<O, T> From<Vec<bool>> for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(src: Vec<bool>) -> Self {
+		Self::from(&src[..])
+	}
+}
+
+/** Unpacks a `BitVec` into a `Vec` of `bool`.
+
+This is the same as [`BitSlice::to_bool_vec`].
+
+[`BitSlice::to_bool_vec`]: ../slice/struct.BitSlice.html#method.to_bool_vec
+**/
+impl<O, T> From<BitVec<O, T>> for Vec<bool>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn from(src: BitVec<O, T>) -> Self {
+		src.to_bool_vec()
+	}
+}
+
 impl<O, T> From<BitBox<O, T>> for BitVec<O, T>
 where
 	O: BitOrder,
@@ -633,6 +682,108 @@ where
 	}
 }
 
+/** Appends bytes to the vector, eight bits at a time.
+
+Each byte is appended as the eight bits it holds under the vector’s order, as
+if by `extend_from_bitslice(buf.bits::<O>())`. When the vector ends at an
+element boundary, each byte is copied whole into a new element. When it does
+not, every byte straddles two elements. Under [`Lsb0`] and [`Msb0`], it is
+shifted into place with the element-wise copy of
+[`BitSlice::copy_from_bitslice`]; under other orderings, it is copied bit by
+bit.
+
+# Examples
+
+```rust
+# use bitvec::prelude::*;
+# #[cfg(feature = "std")] {
+use std::io::Write;
+
+let mut bv = bitvec![Msb0, u8; 1, 0];
+bv.write_all(&[0xA5]).unwrap();
+assert_eq!(bv, bits![1, 0, 1, 0, 1, 0, 0, 1, 0, 1]);
+# }
+```
+
+[`BitSlice::copy_from_bitslice`]: ../slice/struct.BitSlice.html#method.copy_from_bitslice
+[`Lsb0`]: ../order/struct.Lsb0.html
+[`Msb0`]: ../order/struct.Msb0.html
+**/
+#[cfg(feature = "std")]
+impl<O> Write for BitVec<O, u8>
+where O: BitOrder
+{
+	/// Appends all of `buf` to the vector.
+	///
+	/// # Errors
+	///
+	/// If the vector cannot grow to hold `buf`, then an error of kind
+	/// `io::ErrorKind::Other` is returned and the vector is unchanged.
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		buf.len()
+			.checked_mul(8)
+			.ok_or(BitReserveError::CapacityOverflow)
+			.and_then(|bits| self.try_reserve(bits))
+			.map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+		self.extend_from_bitslice(buf.bits::<O>());
+		Ok(buf.len())
+	}
+
+	/// Does nothing, as writes are never buffered.
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+/** Overwrites the entire allocation with zero, and empties the vector.
+
+Every element of the buffer, up to its full capacity, is cleared with volatile
+writes, so live bits, dead bits, and spare capacity are all scrubbed. The head
+index is reset, so the vector refills from the start of its buffer.
+
+To scrub the allocation automatically when the vector is dropped, wrap it in
+[`Zeroizing`].
+
+# Examples
+
+```rust
+# #[cfg(feature = "zeroize")] {
+use bitvec::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+let mut bv = bitvec![Msb0, u8; 1; 20];
+bv.zeroize();
+assert!(bv.is_empty());
+
+let secret = Zeroizing::new(bitvec![Msb0, u8; 1; 20]);
+assert!(secret.all());
+# }
+```
+
+[`Zeroizing`]: https://docs.rs/zeroize/1/zeroize/struct.Zeroizing.html
+**/
+#[cfg(feature = "zeroize")]
+impl<O, T> Zeroize for BitVec<O, T>
+where
+	O: BitOrder,
+	T: BitStore,
+{
+	fn zeroize(&mut self) {
+		let base = self.pointer.pointer().w() as *mut T::Mem;
+		for n in 0 .. self.capacity {
+			unsafe {
+				ptr::write_volatile(base.add(n), T::Mem::ZERO);
+			}
+		}
+		//  Keep the stores from being reördered past the length change.
+		atomic::compiler_fence(atomic::Ordering::SeqCst);
+		self.clear();
+		unsafe {
+			self.pointer.set_head(BitIdx::ZERO);
+		}
+	}
+}
+
 /// `BitVec` is safe to move across thread boundaries, as is `&mut BitVec`.
 unsafe impl<O, T> Send for BitVec<O, T>
 where
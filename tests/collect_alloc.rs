@@ -0,0 +1,92 @@
+/*! Prove that collecting into a `BitVec` allocates only once.
+
+`FromIterator<bool> for BitVec` sizes its buffer from the iterator’s size hint.
+An iterator that reports its exact length must be collected with a single
+allocation and no reallocation.
+
+This file installs a counting global allocator, so it must remain the only test
+in its crate. Only allocations made by the test’s own thread are counted, as the
+test harness allocates on its main thread while the test runs.
+!*/
+
+#[cfg(feature = "std")]
+extern crate bitvec;
+
+#[cfg(feature = "std")]
+use bitvec::prelude::*;
+
+#[cfg(feature = "std")]
+use std::{
+	alloc::{
+		GlobalAlloc,
+		Layout,
+		System,
+	},
+	cell::Cell,
+	sync::atomic::{
+		AtomicUsize,
+		Ordering,
+	},
+};
+
+/// Counts every allocation and reallocation made through it by a thread that
+/// has set `COUNTING`.
+#[cfg(feature = "std")]
+struct Counter;
+
+#[cfg(feature = "std")]
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(feature = "std")]
+thread_local! {
+	static COUNTING: Cell<bool> = Cell::new(false);
+}
+
+#[cfg(feature = "std")]
+fn count() {
+	if COUNTING.try_with(Cell::get).unwrap_or(false) {
+		ALLOCS.fetch_add(1, Ordering::SeqCst);
+	}
+}
+
+#[cfg(feature = "std")]
+unsafe impl GlobalAlloc for Counter {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		count();
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+
+	unsafe fn realloc(
+		&self,
+		ptr: *mut u8,
+		layout: Layout,
+		new_size: usize,
+	) -> *mut u8
+	{
+		count();
+		System.realloc(ptr, layout, new_size)
+	}
+}
+
+#[cfg(feature = "std")]
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+#[cfg(feature = "std")]
+#[test]
+fn collect_alloc() {
+	COUNTING.with(|c| c.set(true));
+	for &len in &[1usize, 7, 64, 1000, 1 << 20] {
+		let before = ALLOCS.load(Ordering::SeqCst);
+		let bv = (0 .. len).map(|n| n % 3 == 0).collect::<BitVec<Msb0, u8>>();
+		let after = ALLOCS.load(Ordering::SeqCst);
+
+		assert_eq!(after - before, 1, "collecting {} bits", len);
+		assert_eq!(bv.len(), len);
+		assert!(bv.iter().enumerate().all(|(n, &bit)| bit == (n % 3 == 0)));
+	}
+}